@@ -0,0 +1,38 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, AccessibilityHeadingLevel, Button, HStack, Image, Text, VStack};
+
+#[derive(Bind, Default)]
+struct RatingView {
+    rating: State<u8>,
+}
+
+impl View for RatingView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let rating = self.rating.clone();
+        VStack::from((
+            Text::new("Rate this app").accessibility_heading(AccessibilityHeadingLevel::H1),
+            HStack::from((
+                Button::new(Image::symbol("minus"), clone!(rating => move || {
+                    rating.set(rating.get().saturating_sub(1));
+                }))
+                .accessibility_label("Fewer stars"),
+                Text::new("★".repeat(rating.get().into()))
+                    .accessibility_label("Rating")
+                    .accessibility_value(format!("{} of 5 stars", rating.get()))
+                    .accessibility_action("Reset", clone!(rating => move || rating.set(0))),
+                Button::new(Image::symbol("plus"), clone!(rating => move || {
+                    rating.set((rating.get() + 1).min(5));
+                }))
+                .accessibility_label("More stars")
+                .accessibility_hint("Adds a star to the rating"),
+            )),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(RatingView::default());
+}
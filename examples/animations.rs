@@ -14,7 +14,7 @@ impl<const COUNT: usize> AnimationsView<COUNT> {
     pub fn new(animations: [Animation; COUNT]) -> Self {
         Self {
             animations,
-            flips: State::new(animations.map(|_| false)),
+            flips: State::new([false; COUNT]),
         }
     }
 }
@@ -35,7 +35,7 @@ impl<const COUNT: usize> View for AnimationsView<COUNT> {
                     let factor = if flips.get()[i] { 1.0 } else { -1.0 };
                     HStack::from((
                         Text::new(format!("{animation}"))
-                            .frame_with(Alignment::TRAILING, Frame::with_width(100)),
+                            .frame_with(Alignment::TRAILING, Frame::with_width(250)),
                         ZStack::from((
                             Rectangle::new()
                                 .frame(Frame::exact(inner_width, 2)),
@@ -63,5 +63,8 @@ fn main() {
         Animation::ease_in(duration),
         Animation::ease_out(duration),
         Animation::ease_in_out(duration),
+        Animation::SPRING,
+        Animation::spring(1.0, 0.3),
+        Animation::linear(duration / 4).repeat_count(3, true),
     ]));
 }
@@ -0,0 +1,43 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, CalendarDate, DatePicker, HStack, If, ProgressView, Stepper, Text, Toggle, VStack};
+
+#[derive(Bind)]
+struct ControlsView {
+    is_loading: State<bool>,
+    completed: State<i64>,
+    due_date: State<CalendarDate>,
+}
+
+impl Default for ControlsView {
+    fn default() -> Self {
+        Self {
+            is_loading: State::new(false),
+            completed: State::new(3),
+            due_date: State::new(CalendarDate::new(2025, 1, 1).unwrap()),
+        }
+    }
+}
+
+impl View for ControlsView {
+    type Body = impl View;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn body(&self) -> Self::Body {
+        let completed = self.completed.get();
+        VStack::from((
+            Stepper::new("Completed", self.completed.binding(), 0..=10, 1),
+            ProgressView::new(completed as f64 / 10.0),
+            DatePicker::new("Due", self.due_date.binding()),
+            Text::new(format!("{completed} of 10 tasks done by {}", self.due_date.get())),
+            HStack::from((
+                Toggle::new("Loading", self.is_loading.binding()),
+                If::new(self.is_loading.get(), ProgressView::indeterminate),
+            )),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(ControlsView::default());
+}
@@ -0,0 +1,35 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, Font, Grid, GridItem, GridRow, HorizontalAlignment, LazyVGrid, Text, VStack};
+
+const PHOTO_COUNT: usize = 1_000;
+
+#[derive(Bind)]
+struct DashboardView;
+
+impl View for DashboardView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        VStack::from((
+            Grid::new((
+                GridRow::new((
+                    Text::new("Metric").font(Font::HEADLINE),
+                    Text::new("Value").font(Font::HEADLINE).grid_column_alignment(HorizontalAlignment::Trailing),
+                )),
+                GridRow::new((Text::new("CPU"), Text::new("42%"))),
+                GridRow::new((Text::new("Memory"), Text::new("3.1 GB"))),
+                GridRow::new((Text::new("Last updated just now").grid_cell_columns(2),)),
+            )),
+            // Only the visible cells are rendered
+            LazyVGrid::new([GridItem::adaptive(100)], PHOTO_COUNT, |i| {
+                Text::new(format!("Photo {i}"))
+                    .frame((100, 80))
+            }),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(DashboardView);
+}
@@ -0,0 +1,36 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use std::env;
+
+use nuit::{prelude::*, ContentMode, Frame, HStack, If, Image, Text, VStack};
+
+#[derive(Bind)]
+struct ImagesView {
+    path: Option<String>,
+}
+
+impl View for ImagesView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        // Symbol names differ between the platforms
+        let symbol = |apple: &str, freedesktop: &str| Image::symbol(if cfg!(target_vendor = "apple") { apple } else { freedesktop });
+        VStack::from((
+            HStack::from((
+                symbol("star.fill", "starred-symbolic"),
+                symbol("folder", "folder-symbolic"),
+                symbol("trash", "user-trash-symbolic"),
+            )),
+            If::new_or_else(self.path.is_some(), || {
+                Image::from_file(self.path.clone().unwrap_or_default())
+                    .resizable()
+                    .aspect_ratio(ContentMode::Fit)
+                    .frame(Frame::with_width(300))
+            }, || Text::new("Pass an image path to show it")),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(ImagesView { path: env::args().nth(1) });
+}
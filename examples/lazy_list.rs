@@ -0,0 +1,44 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use std::rc::Rc;
+
+use nuit::{prelude::*, Button, HStack, List, Text, VStack};
+
+const LINE_COUNT: usize = 50_000;
+
+#[derive(Bind)]
+struct LogView {
+    lines: Rc<Vec<String>>,
+    selected: State<Option<usize>>,
+}
+
+impl View for LogView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let lines = self.lines.clone();
+        let selected = self.selected.clone();
+        VStack::from((
+            Text::new(match selected.get() {
+                Some(i) => format!("Selected: {}", lines[i]),
+                None => "Tap a line to select it".to_owned(),
+            }),
+            // Only the visible rows are rendered, even though there are many
+            List::lazy(lines.len(), move |i| {
+                HStack::from((
+                    Text::new(lines[i].clone()),
+                    Button::with_text("Select", clone!(selected => move || {
+                        selected.set(Some(i));
+                    })),
+                ))
+            }),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(LogView {
+        lines: Rc::new((0..LINE_COUNT).map(|i| format!("Log line {i}")).collect()),
+        selected: State::default(),
+    });
+}
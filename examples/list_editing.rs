@@ -0,0 +1,64 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use std::collections::HashSet;
+
+use nuit::{prelude::*, Button, ButtonRole, ForEach, Id, List, Section, Text, VStack};
+
+#[derive(Bind)]
+struct TodoView {
+    todos: State<Vec<String>>,
+    selection: State<HashSet<Id>>,
+}
+
+impl View for TodoView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let todos = self.todos.clone();
+        let selection = self.selection.clone();
+        VStack::from((
+            Text::new(format!("{} selected", selection.get().len())),
+            List::new(
+                Section::new(
+                    ForEach::with_index(todos.get(), |i, todo| {
+                        Text::new(todo.clone())
+                            .swipe_actions(Button::with_text("Done", clone!(todos => move || {
+                                let mut new_todos = todos.get();
+                                new_todos.remove(i);
+                                todos.set(new_todos);
+                            })).role(ButtonRole::Destructive))
+                            .context_menu(Button::with_text("Duplicate", clone!(todos => move || {
+                                let mut new_todos = todos.get();
+                                new_todos.insert(i + 1, format!("{todo} (copy)"));
+                                todos.set(new_todos);
+                            })))
+                    })
+                    .on_delete(clone!(todos => move |indices| {
+                        let mut new_todos = todos.get();
+                        for i in indices.into_iter().rev() {
+                            new_todos.remove(i);
+                        }
+                        todos.set(new_todos);
+                    }))
+                    .on_move(clone!(todos => move |indices, destination| {
+                        let mut new_todos = todos.get();
+                        let moved: Vec<_> = indices.iter().rev().map(|&i| new_todos.remove(i)).collect();
+                        let destination = destination - indices.iter().filter(|&&i| i < destination).count();
+                        new_todos.splice(destination..destination, moved.into_iter().rev());
+                        todos.set(new_todos);
+                    }))
+                )
+                .header(Text::new("Todos"))
+                .footer(Text::new(format!("{} left", todos.get().len())))
+            )
+            .multi_selection(selection.binding()),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(TodoView {
+        todos: State::new(["Buy milk", "Water plants", "Call mom", "Write tests"].map(str::to_owned).to_vec()),
+        selection: State::default(),
+    });
+}
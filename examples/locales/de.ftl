@@ -0,0 +1,8 @@
+title = Posteingang
+unread = { $count ->
+    [0] Keine ungelesenen Nachrichten
+    [one] Eine ungelesene Nachricht
+   *[other] { $count } ungelesene Nachrichten
+}
+add-message = Nachricht hinzufügen
+switch-language = Switch to English
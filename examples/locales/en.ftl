@@ -0,0 +1,8 @@
+title = Inbox
+unread = { $count ->
+    [0] No unread messages
+    [one] One unread message
+   *[other] { $count } unread messages
+}
+add-message = Add message
+switch-language = Auf Deutsch wechseln
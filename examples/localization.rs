@@ -0,0 +1,46 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, App, Button, Locale, LocaleKey, Localization, LocalizationKey, LocalizedText, VStack};
+
+#[derive(Bind)]
+struct InboxView {
+    locale: State<Locale>,
+    unread: State<usize>,
+}
+
+impl View for InboxView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let locale = self.locale.clone();
+        let unread = self.unread.clone();
+
+        VStack::from((
+            LocalizedText::new("title"),
+            LocalizedText::new("unread").arg("count", unread.get()),
+            Button::new(LocalizedText::new("add-message"), clone!(unread => move || {
+                unread.set(unread.get() + 1);
+            })),
+            Button::new(LocalizedText::new("switch-language"), move || {
+                let is_german = locale.get().language() == "de";
+                locale.set(Locale::new(if is_german { "en" } else { "de" }).unwrap());
+            }),
+        ))
+        .environment(LocaleKey, self.locale.get())
+    }
+}
+
+fn main() {
+    let english = Locale::new("en").unwrap();
+    let localization = Localization::new(english.clone())
+        .with_resource(english, include_str!("locales/en.ftl"))
+        .with_resource(Locale::new("de").unwrap(), include_str!("locales/de.ftl"));
+
+    nuit::run_app(
+        App::from(InboxView {
+            locale: State::new(Locale::current()),
+            unread: State::default(),
+        })
+        .environment(LocalizationKey, localization)
+    );
+}
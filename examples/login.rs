@@ -0,0 +1,60 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, FocusState, Frame, Insets, KeyboardType, SecureField, Text, TextEditor, TextField, VStack};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Email,
+    Password,
+}
+
+#[derive(Bind, Default)]
+struct LoginView {
+    email: State<String>,
+    password: State<String>,
+    notes: State<String>,
+    status: State<String>,
+    focused_field: FocusState<Field>,
+}
+
+impl View for LoginView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let email = self.email.clone();
+        let password = self.password.clone();
+        let status = self.status.clone();
+        let focused_field = self.focused_field.clone();
+        VStack::from((
+            TextField::new(email.binding())
+                .prompt("Email")
+                .keyboard_type(KeyboardType::EmailAddress)
+                .autocorrection_disabled(true)
+                .on_focus_change(|is_focused| println!("Email field focused: {is_focused}"))
+                .focused(focused_field.binding(), Field::Email),
+            SecureField::new(password.binding())
+                .prompt("Password")
+                .on_submit(clone!(email, password, status, focused_field => move || {
+                    // Focus the first field that is still empty
+                    if email.get().is_empty() {
+                        focused_field.set(Field::Email);
+                    } else if password.get().is_empty() {
+                        focused_field.set(Field::Password);
+                    } else {
+                        focused_field.set(None);
+                        status.set(format!("Logged in as {}", email.get()));
+                    }
+                }))
+                .focused(focused_field.binding(), Field::Password),
+            TextEditor::new(self.notes.binding())
+                .frame(Frame::with_height(100)),
+            Text::new(status.get()),
+        ))
+        .padding(Insets::default())
+        .frame(Frame::with_width(400))
+    }
+}
+
+fn main() {
+    nuit::run_app(LoginView::default());
+}
@@ -0,0 +1,65 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, Button, ButtonRole, Text, VStack};
+
+#[derive(Bind, Default)]
+struct PresentationView {
+    is_sheet_presented: State<bool>,
+    is_popover_presented: State<bool>,
+    is_alert_presented: State<bool>,
+    is_dialog_presented: State<bool>,
+    count: State<i32>,
+}
+
+impl View for PresentationView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let is_sheet_presented = self.is_sheet_presented.clone();
+        let is_popover_presented = self.is_popover_presented.clone();
+        let is_alert_presented = self.is_alert_presented.clone();
+        let is_dialog_presented = self.is_dialog_presented.clone();
+        let count = self.count.clone();
+        VStack::from((
+            Text::new(format!("Count: {}", count.get())),
+            Button::with_text("Show Sheet", clone!(is_sheet_presented => move || {
+                is_sheet_presented.set(true);
+            }))
+            .sheet(is_sheet_presented.binding(), VStack::from((
+                Text::new("This is a sheet"),
+                Button::with_text("Done", clone!(is_sheet_presented => move || {
+                    is_sheet_presented.set(false);
+                })),
+            )).padding(20.0)),
+            Button::with_text("Show Popover", clone!(is_popover_presented => move || {
+                is_popover_presented.set(true);
+            }))
+            .popover(is_popover_presented.binding(), Text::new("This is a popover").padding(10.0)),
+            Button::with_text("Reset", clone!(is_alert_presented => move || {
+                is_alert_presented.set(true);
+            }))
+            .alert_with_message("Reset the count?", "The count will be set back to zero.", is_alert_presented.binding(), (
+                Button::with_text("Cancel", || {}).role(ButtonRole::Cancel),
+                Button::with_text("Reset", clone!(count => move || {
+                    count.set(0);
+                })).role(ButtonRole::Destructive),
+            )),
+            Button::with_text("Change", clone!(is_dialog_presented => move || {
+                is_dialog_presented.set(true);
+            }))
+            .confirmation_dialog("Change the count", is_dialog_presented.binding(), (
+                Button::with_text("Increment", clone!(count => move || {
+                    count.set(count.get() + 1);
+                })),
+                Button::with_text("Decrement", move || {
+                    count.set(count.get() - 1);
+                }),
+                Button::with_text("Cancel", || {}).role(ButtonRole::Cancel),
+            )),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(PresentationView::default());
+}
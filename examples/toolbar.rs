@@ -0,0 +1,39 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, App, Button, CommandItem, CommandMenu, Commands, EventModifiers, Key, Scene, Text, ToolbarItem, ToolbarItemPlacement};
+
+#[derive(Bind, Default)]
+struct ToolbarView {
+    count: State<i32>,
+}
+
+impl View for ToolbarView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let count = self.count.clone();
+        Text::new(format!("Count: {}", count.get()))
+            .toolbar((
+                ToolbarItem::new(ToolbarItemPlacement::Navigation, Button::with_text("Reset", clone!(count => move || {
+                    count.set(0);
+                })).keyboard_shortcut(Key::Escape {}, EventModifiers::EMPTY)),
+                ToolbarItem::new(ToolbarItemPlacement::PrimaryAction, Button::with_text("Increment", move || {
+                    count.set(count.get() + 1);
+                }).keyboard_shortcut('i', EventModifiers::COMMAND)),
+            ))
+    }
+}
+
+fn main() {
+    nuit::run_app(
+        App::new("com.example.NuitToolbar")
+            .scene(Scene::window("main", ToolbarView::default())
+                .title("Toolbar"))
+            .with_commands(Commands::new()
+                .menu(CommandMenu::new("Greetings")
+                    .item(CommandItem::button("Say Hello", || println!("Hello!"))
+                        .keyboard_shortcut('h', EventModifiers::COMMAND | EventModifiers::SHIFT))
+                    .divider()
+                    .button("Say Goodbye", || println!("Goodbye!"))))
+    );
+}
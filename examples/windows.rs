@@ -0,0 +1,67 @@
+#![feature(type_alias_impl_trait, impl_trait_in_assoc_type)]
+
+use nuit::{prelude::*, App, Button, Environment, OpenWindowKey, Scene, Text, TextField, Vec2, VStack};
+
+#[derive(Bind, Default)]
+struct LauncherView {
+    name: State<String>,
+    open_window: Environment<OpenWindowKey>,
+}
+
+impl View for LauncherView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let name = self.name.clone();
+        let open_window = self.open_window.get();
+        VStack::from((
+            TextField::new(name.binding()),
+            Button::with_text("Open Document", clone!(open_window => move || {
+                open_window.open_with("document", name.get());
+            })),
+            Button::with_text("Settings", move || {
+                open_window.open("settings");
+            }),
+        ))
+    }
+}
+
+#[derive(Bind)]
+struct DocumentView {
+    name: String,
+    edits: State<i32>,
+}
+
+impl DocumentView {
+    fn new(name: String) -> Self {
+        Self { name, edits: State::new(0) }
+    }
+}
+
+impl View for DocumentView {
+    type Body = impl View;
+
+    fn body(&self) -> Self::Body {
+        let edits = self.edits.clone();
+        VStack::from((
+            Text::new(format!("Document '{}' with {} edits", self.name, edits.get())),
+            Button::with_text("Edit", move || {
+                edits.set(edits.get() + 1);
+            }),
+        ))
+    }
+}
+
+fn main() {
+    nuit::run_app(
+        App::new("com.example.NuitWindows")
+            .scene(Scene::window("launcher", LauncherView::default())
+                .title("Launcher")
+                .default_size(Vec2::new(320.0, 200.0)))
+            .scene(Scene::window_group("document", DocumentView::new)
+                .title("Document")
+                .min_size(Vec2::new(300.0, 150.0)))
+            .scene(Scene::settings(Text::new("No settings yet"))
+                .title("Settings"))
+    );
+}
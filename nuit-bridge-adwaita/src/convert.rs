@@ -1,5 +1,5 @@
-use adw::gtk::Align;
-use nuit_core::{HorizontalAlignment, VerticalAlignment};
+use adw::{gdk::{self, ModifierType}, glib::translate::FromGlib, gtk::{self, AccessibleRole, Align, ContentFit}};
+use nuit_core::{AccessibilityRole, Alignment, ContentMode, EventModifiers, FontLevel, HorizontalAlignment, Key, KeyboardShortcut, VerticalAlignment};
 
 pub trait ToGtk {
     type GtkValue;
@@ -32,3 +32,122 @@ impl ToGtk for VerticalAlignment {
         }
     }
 }
+
+impl ToGtk for Alignment {
+    type GtkValue = (Align, Align);
+
+    fn to_gtk(self) -> Self::GtkValue {
+        (self.horizontal().to_gtk(), self.vertical().to_gtk())
+    }
+}
+
+impl ToGtk for AccessibilityRole {
+    type GtkValue = AccessibleRole;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        match self {
+            Self::Button => AccessibleRole::Button,
+            Self::Link => AccessibleRole::Link,
+            Self::Heading => AccessibleRole::Heading,
+            Self::Image => AccessibleRole::Img,
+            Self::StaticText => AccessibleRole::Label,
+            Self::TextField => AccessibleRole::TextBox,
+            Self::SearchField => AccessibleRole::SearchBox,
+            Self::Toggle => AccessibleRole::Switch,
+            Self::Slider => AccessibleRole::Slider,
+            Self::Stepper => AccessibleRole::SpinButton,
+            Self::ProgressIndicator => AccessibleRole::ProgressBar,
+            Self::Picker => AccessibleRole::ComboBox,
+            Self::List => AccessibleRole::List,
+            Self::Group => AccessibleRole::Group,
+        }
+    }
+}
+
+impl ToGtk for ContentMode {
+    type GtkValue = ContentFit;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        match self {
+            Self::Fit => ContentFit::Contain,
+            Self::Fill => ContentFit::Cover,
+        }
+    }
+}
+
+impl ToGtk for FontLevel {
+    /// The Adwaita style class for the font level.
+    type GtkValue = &'static str;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        match self {
+            Self::ExtraLargeTitle2
+            | Self::ExtraLargeTitle
+            | Self::LargeTitle => "title-1",
+            Self::Title => "title-2",
+            Self::Title2 => "title-3",
+            Self::Title3 => "title-4",
+            Self::Headline => "heading",
+            Self::Subheadline
+            | Self::Body
+            | Self::Callout => "body",
+            Self::Caption
+            | Self::Caption2
+            | Self::Footnote => "caption",
+        }
+    }
+}
+
+impl ToGtk for Key {
+    type GtkValue = gdk::Key;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        match self {
+            // SAFETY: Keyvals are plain integers, any value is valid
+            Self::Character { character } => unsafe { gdk::Key::from_glib(gdk::unicode_to_keyval(u32::from(character))) },
+            Self::Return {} => gdk::Key::Return,
+            Self::Escape {} => gdk::Key::Escape,
+            Self::Delete {} => gdk::Key::BackSpace,
+            Self::DeleteForward {} => gdk::Key::Delete,
+            Self::Tab {} => gdk::Key::Tab,
+            Self::Space {} => gdk::Key::space,
+            Self::UpArrow {} => gdk::Key::Up,
+            Self::DownArrow {} => gdk::Key::Down,
+            Self::LeftArrow {} => gdk::Key::Left,
+            Self::RightArrow {} => gdk::Key::Right,
+            Self::Home {} => gdk::Key::Home,
+            Self::End {} => gdk::Key::End,
+            Self::PageUp {} => gdk::Key::Page_Up,
+            Self::PageDown {} => gdk::Key::Page_Down,
+        }
+    }
+}
+
+impl ToGtk for EventModifiers {
+    type GtkValue = ModifierType;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        // There is no command key outside of Apple platforms, so we map it to
+        // control, which is used for the same shortcuts there.
+        let mut modifiers = ModifierType::empty();
+        if self.contains(Self::COMMAND) || self.contains(Self::CONTROL) {
+            modifiers |= ModifierType::CONTROL_MASK;
+        }
+        if self.contains(Self::SHIFT) {
+            modifiers |= ModifierType::SHIFT_MASK;
+        }
+        if self.contains(Self::OPTION) {
+            modifiers |= ModifierType::ALT_MASK;
+        }
+        modifiers
+    }
+}
+
+impl ToGtk for KeyboardShortcut {
+    /// The GTK accelerator string for the shortcut, e.g. `<Control>s`.
+    type GtkValue = String;
+
+    fn to_gtk(self) -> Self::GtkValue {
+        gtk::accelerator_name(self.key().to_gtk(), self.modifiers().to_gtk()).into()
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Write;
+
+use nuit_core::{Color, FontDesign, FontWeight, Material, SemanticStyle, Style};
+
+/// A value that can be expressed in GTK's dialect of CSS.
+pub trait ToCss {
+    fn to_css(&self) -> String;
+}
+
+impl ToCss for Color {
+    fn to_css(&self) -> String {
+        format!("rgba({}, {}, {}, {})", self.red_u8(), self.green_u8(), self.blue_u8(), self.alpha())
+    }
+}
+
+impl ToCss for Style {
+    fn to_css(&self) -> String {
+        match self {
+            Self::Color { color } => color.to_css(),
+            // Approximate the hierarchy levels with Adwaita's dimming opacity
+            Self::Hierarchical { level } => match level {
+                0 => "currentColor".to_owned(),
+                1 => "alpha(currentColor, 0.55)".to_owned(),
+                2 => "alpha(currentColor, 0.3)".to_owned(),
+                _ => "alpha(currentColor, 0.15)".to_owned(),
+            },
+            Self::Material { material } => material.to_css(),
+            Self::Semantic { style } => style.to_css(),
+            // GTK has no notion of blending or shadows in colors, so we only
+            // render the wrapped style.
+            Self::BlendMode { wrapped, .. }
+            | Self::Shadow { wrapped, .. } => wrapped.to_css(),
+            Self::Opacity { wrapped, opacity } => format!("alpha({}, {opacity})", wrapped.to_css()),
+        }
+    }
+}
+
+impl ToCss for SemanticStyle {
+    fn to_css(&self) -> String {
+        match self {
+            Self::Foreground => "@window_fg_color",
+            Self::Background | Self::WindowBackground => "@window_bg_color",
+            Self::Selection => "alpha(@accent_bg_color, 0.3)",
+            Self::Separator => "@borders",
+            Self::Tint => "@accent_bg_color",
+            Self::Placeholder => "alpha(currentColor, 0.55)",
+            Self::Link => "@accent_color",
+            Self::Fill => "alpha(currentColor, 0.1)",
+        }.to_owned()
+    }
+}
+
+impl ToCss for Material {
+    fn to_css(&self) -> String {
+        // GTK cannot blur what is behind a widget, so materials are
+        // approximated with translucent window backgrounds.
+        match self {
+            Self::UltraThin => "alpha(@window_bg_color, 0.3)",
+            Self::Thin => "alpha(@window_bg_color, 0.5)",
+            Self::Regular => "alpha(@window_bg_color, 0.7)",
+            Self::Thick => "alpha(@window_bg_color, 0.85)",
+            Self::UltraThick => "alpha(@window_bg_color, 0.95)",
+            Self::Bar => "@headerbar_bg_color",
+        }.to_owned()
+    }
+}
+
+impl ToCss for FontDesign {
+    fn to_css(&self) -> String {
+        match self {
+            // There is no widely available rounded system font on Linux
+            Self::Default | Self::Rounded => "inherit",
+            Self::Monospaced => "monospace",
+            Self::Serif => "serif",
+        }.to_owned()
+    }
+}
+
+impl ToCss for FontWeight {
+    fn to_css(&self) -> String {
+        match self {
+            Self::UltraLight => "200",
+            Self::Thin => "100",
+            Self::Light => "300",
+            Self::Regular => "400",
+            Self::Medium => "500",
+            Self::Semibold => "600",
+            Self::Bold => "700",
+            Self::Heavy => "800",
+            Self::Black => "900",
+        }.to_owned()
+    }
+}
+
+/// Quotes the given string as a CSS string literal, escaping quotes,
+/// backslashes and control characters so it cannot end the declaration.
+pub fn css_string(string: &str) -> String {
+    let mut css = String::with_capacity(string.len() + 2);
+    css.push('"');
+    for c in string.chars() {
+        match c {
+            '"' | '\\' => {
+                css.push('\\');
+                css.push(c);
+            },
+            // Control characters (including newlines) are written as
+            // hexadecimal escapes, which are terminated by a space
+            c if c.is_control() => { let _ = write!(css, "\\{:x} ", u32::from(c)); },
+            c => css.push(c),
+        }
+    }
+    css.push('"');
+    css
+}
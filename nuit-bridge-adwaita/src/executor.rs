@@ -0,0 +1,14 @@
+use std::{future::Future, pin::Pin};
+
+use adw::glib::MainContext;
+use nuit_core::Executor;
+
+/// An executor that runs tasks on the default `GLib` main context, i.e. as part
+/// of GTK's main loop.
+pub struct GlibExecutor;
+
+impl Executor for GlibExecutor {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()>>>) {
+        MainContext::default().spawn_local(future);
+    }
+}
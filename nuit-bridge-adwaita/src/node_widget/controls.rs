@@ -0,0 +1,168 @@
+use adw::{glib, gtk::{self, Align, Calendar, Label, MenuButton, Orientation, Popover, ProgressBar, Scale, SpinButton, Spinner, Switch}, prelude::*};
+use nuit_core::{CalendarDate, Event, DEFAULT_SPACING};
+
+use super::event_sink::EventSink;
+
+/// Lays out the given control next to a label with the given title. The label
+/// is hidden if the title is empty.
+#[allow(clippy::cast_possible_truncation)]
+pub fn titled(title: &str, control: &impl IsA<gtk::Widget>) -> gtk::Box {
+    let title_label = Label::new(Some(title));
+    title_label.set_visible(!title.is_empty());
+
+    let gtk_box = gtk::Box::new(Orientation::Horizontal, DEFAULT_SPACING as i32);
+    gtk_box.append(&title_label);
+    gtk_box.append(control);
+    gtk_box
+}
+
+/// Updates the title of a control laid out by [`titled`] and returns the
+/// control, or `None` if the given widget isn't such a layout.
+pub fn patch_titled<T>(widget: &gtk::Widget, title: &str) -> Option<T> where T: IsA<gtk::Widget> {
+    let gtk_box = widget.downcast_ref::<gtk::Box>()?;
+    let title_label = gtk_box.first_child().and_downcast::<Label>()?;
+    let control = gtk_box.last_child().and_downcast::<T>()?;
+
+    title_label.set_label(title);
+    title_label.set_visible(!title.is_empty());
+    Some(control)
+}
+
+/// Creates a horizontal scale for a slider.
+pub fn build_slider(value: f64, lower_bound: f64, upper_bound: f64, step: Option<f64>, event_sink: EventSink) -> gtk::Widget {
+    let scale = Scale::with_range(Orientation::Horizontal, lower_bound, upper_bound, step.unwrap_or(1e-32));
+    scale.set_value(value);
+    scale.set_width_request(150);
+    scale.connect_value_changed(move |scale| {
+        event_sink.fire(&Event::UpdateSliderValue { value: scale.value() });
+    });
+    scale.upcast()
+}
+
+pub fn patch_slider(widget: &gtk::Widget, value: f64, lower_bound: f64, upper_bound: f64, step: Option<f64>) -> bool {
+    let Some(scale) = widget.downcast_ref::<Scale>() else { return false };
+    scale.set_range(lower_bound, upper_bound);
+    scale.adjustment().set_step_increment(step.unwrap_or(1e-32));
+    // Setting the value would fire a change event, so we only do so if the
+    // scale doesn't already show it
+    if (scale.value() - value).abs() > f64::EPSILON {
+        scale.set_value(value);
+    }
+    true
+}
+
+/// Creates a switch for a toggle.
+pub fn build_toggle(title: &str, is_on: bool, event_sink: EventSink) -> gtk::Widget {
+    let switch = Switch::new();
+    switch.set_active(is_on);
+    switch.set_valign(Align::Center);
+    switch.connect_active_notify(move |switch| {
+        event_sink.fire(&Event::UpdateToggle { is_on: switch.is_active() });
+    });
+    titled(title, &switch).upcast()
+}
+
+pub fn patch_toggle(widget: &gtk::Widget, title: &str, is_on: bool) -> bool {
+    let Some(switch) = patch_titled::<Switch>(widget, title) else { return false };
+    if switch.is_active() != is_on {
+        switch.set_active(is_on);
+    }
+    true
+}
+
+/// Creates a spin button for a stepper.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn build_stepper(title: &str, value: i64, lower_bound: i64, upper_bound: i64, step: i64, event_sink: EventSink) -> gtk::Widget {
+    let spin_button = SpinButton::with_range(lower_bound as f64, upper_bound as f64, step as f64);
+    spin_button.set_digits(0);
+    spin_button.set_value(value as f64);
+    spin_button.connect_value_changed(move |spin_button| {
+        event_sink.fire(&Event::UpdateStepperValue { value: spin_button.value().round() as i64 });
+    });
+    titled(title, &spin_button).upcast()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn patch_stepper(widget: &gtk::Widget, title: &str, value: i64, lower_bound: i64, upper_bound: i64, step: i64) -> bool {
+    let Some(spin_button) = patch_titled::<SpinButton>(widget, title) else { return false };
+    spin_button.set_range(lower_bound as f64, upper_bound as f64);
+    spin_button.set_increments(step as f64, step as f64 * 10.0);
+    // Compare the integral values, like the ones reported when the value changes
+    if spin_button.value().round() as i64 != value {
+        spin_button.set_value(value as f64);
+    }
+    true
+}
+
+/// Creates a progress bar for a determinate progress view and a spinner for
+/// an indeterminate one.
+pub fn build_progress_view(value: Option<f64>) -> gtk::Widget {
+    if let Some(value) = value {
+        let progress_bar = ProgressBar::new();
+        progress_bar.set_fraction(value);
+        progress_bar.set_valign(Align::Center);
+        progress_bar.upcast()
+    } else {
+        let spinner = Spinner::new();
+        spinner.start();
+        spinner.upcast()
+    }
+}
+
+pub fn patch_progress_view(widget: &gtk::Widget, value: Option<f64>) -> bool {
+    match (widget.downcast_ref::<ProgressBar>(), value) {
+        (Some(progress_bar), Some(value)) => progress_bar.set_fraction(value),
+        (None, None) if widget.is::<Spinner>() => {},
+        _ => return false,
+    }
+    true
+}
+
+/// Creates a menu button for a date picker, which shows the date and opens a
+/// calendar.
+pub fn build_date_picker(title: &str, date: CalendarDate, event_sink: EventSink) -> gtk::Widget {
+    let calendar = Calendar::new();
+    if let Some(date_time) = to_date_time(date) {
+        calendar.select_day(&date_time);
+    }
+    calendar.connect_day_selected(move |calendar| {
+        if let Some(date) = from_date_time(&calendar.date()) {
+            event_sink.fire(&Event::UpdateDate { date });
+        }
+    });
+
+    let popover = Popover::new();
+    popover.set_child(Some(&calendar));
+
+    let menu_button = MenuButton::new();
+    menu_button.set_label(&date.to_string());
+    menu_button.set_popover(Some(&popover));
+    titled(title, &menu_button).upcast()
+}
+
+pub fn patch_date_picker(widget: &gtk::Widget, title: &str, date: CalendarDate) -> bool {
+    let Some(menu_button) = patch_titled::<MenuButton>(widget, title) else { return false };
+    let Some(calendar) = menu_button.popover().and_then(|popover| popover.child()).and_downcast::<Calendar>() else { return false };
+
+    menu_button.set_label(&date.to_string());
+    if from_date_time(&calendar.date()) != Some(date) {
+        if let Some(date_time) = to_date_time(date) {
+            calendar.select_day(&date_time);
+        }
+    }
+    true
+}
+
+/// The start of the given day in the local time zone.
+fn to_date_time(date: CalendarDate) -> Option<glib::DateTime> {
+    glib::DateTime::from_local(date.year(), i32::from(date.month()), i32::from(date.day()), 0, 0, 0.0).ok()
+}
+
+/// The day of the given date and time.
+fn from_date_time(date_time: &glib::DateTime) -> Option<CalendarDate> {
+    CalendarDate::new(
+        date_time.year(),
+        u8::try_from(date_time.month()).ok()?,
+        u8::try_from(date_time.day_of_month()).ok()?,
+    )
+}
@@ -0,0 +1,34 @@
+use std::{cell::Cell, rc::Rc};
+
+use nuit_core::{Event, EventResponse, IdPath, IdPathBuf};
+
+/// A handle for firing events at a node widget's id path from signal handlers.
+#[derive(Clone)]
+pub struct EventSink {
+    id_path: IdPathBuf,
+    #[allow(clippy::type_complexity)]
+    fire_event: Option<Rc<dyn Fn(&IdPath, &Event) -> EventResponse>>,
+    is_updating: Rc<Cell<bool>>,
+}
+
+impl EventSink {
+    #[allow(clippy::type_complexity)]
+    pub fn new(
+        id_path: IdPathBuf,
+        fire_event: Option<Rc<dyn Fn(&IdPath, &Event) -> EventResponse>>,
+        is_updating: Rc<Cell<bool>>,
+    ) -> Self {
+        Self { id_path, fire_event, is_updating }
+    }
+
+    /// Fires the given event, unless the widget is currently being updated
+    /// programmatically (in which case the event would only echo the update).
+    pub fn fire(&self, event: &Event) {
+        if self.is_updating.get() {
+            return;
+        }
+        if let Some(ref fire_event) = self.fire_event {
+            fire_event(&self.id_path, event);
+        }
+    }
+}
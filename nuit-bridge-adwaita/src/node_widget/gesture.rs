@@ -0,0 +1,56 @@
+use std::{cell::Cell, rc::Rc};
+
+use adw::{gtk::{self, GestureClick, GestureDrag}, prelude::*};
+use nuit_core::{DragEvent, DragEventKind, Event, GestureEvent, GestureNode, Vec2};
+
+use super::event_sink::EventSink;
+
+/// Attaches an event controller recognizing the given gesture to the given
+/// widget, firing gesture events at the given event sink.
+pub fn attach_gesture(widget: &impl IsA<gtk::Widget>, gesture: &GestureNode, event_sink: EventSink) {
+    match *gesture {
+        GestureNode::Tap { count } => {
+            let click = GestureClick::new();
+            click.connect_released(move |_click, n_press, _x, _y| {
+                if usize::try_from(n_press) == Ok(count) {
+                    event_sink.fire(&Event::Gesture { gesture: GestureEvent::Tap {} });
+                }
+            });
+            widget.add_controller(click);
+        },
+        GestureNode::Drag { minimum_distance } => {
+            let drag = GestureDrag::new();
+            // Whether the minimum distance has been exceeded
+            let is_dragging = Rc::new(Cell::new(false));
+
+            let fire = move |drag: &GestureDrag, kind: DragEventKind, dx: f64, dy: f64| {
+                let Some((x, y)) = drag.start_point() else { return };
+                let start_location = Vec2::new(x, y);
+                let location = Vec2::new(x + dx, y + dy);
+                event_sink.fire(&Event::Gesture {
+                    gesture: GestureEvent::Drag { drag: DragEvent::new(kind, start_location, location) },
+                });
+            };
+
+            drag.connect_drag_update({
+                let is_dragging = is_dragging.clone();
+                let fire = fire.clone();
+                move |drag, dx, dy| {
+                    if !is_dragging.get() && dx.hypot(dy) < minimum_distance {
+                        return;
+                    }
+                    is_dragging.set(true);
+                    fire(drag, DragEventKind::Updated, dx, dy);
+                }
+            });
+
+            drag.connect_drag_end(move |drag, dx, dy| {
+                if is_dragging.replace(false) {
+                    fire(drag, DragEventKind::Ended, dx, dy);
+                }
+            });
+
+            widget.add_controller(drag);
+        },
+    }
+}
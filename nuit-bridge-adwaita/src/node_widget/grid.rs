@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use adw::{gtk::{self, Align}, prelude::*};
+use nuit_core::{Alignment, Difference, IdPathBuf, Identified, ModifierNode, Node};
+
+use crate::convert::ToGtk;
+
+use super::{flattened_children, NodeWidget};
+
+/// A cell of a grid, keyed by its id path relative to the grid.
+struct GridCell<'a> {
+    path: IdPathBuf,
+    node: &'a Node,
+    row: i32,
+    column: i32,
+    /// The number of columns spanned, or none if the cell spans the entire row.
+    span: Option<i32>,
+    valign: Option<Align>,
+}
+
+impl NodeWidget {
+    /// Updates the node widgets attached to the given grid to match the rows
+    /// of the given grid node.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(super) fn update_grid(&self, grid: &gtk::Grid, alignment: Alignment, horizontal_spacing: f64, vertical_spacing: f64, wrapped: &Identified<Node>, difference: Option<&Difference<&Node>>) {
+        // Negative spacings are treated as no spacing
+        grid.set_column_spacing(horizontal_spacing.max(0.0) as u32);
+        grid.set_row_spacing(vertical_spacing.max(0.0) as u32);
+
+        let cells = grid_cells(wrapped);
+        let column_count = cells.iter().map(|cell| cell.column + cell.span.unwrap_or(1)).max().unwrap_or(1);
+
+        // Like in SwiftUI, a column alignment applies to all cells in the column
+        let mut column_alignments = HashMap::new();
+        for cell in &cells {
+            if let Some(halign) = column_alignment(cell.node) {
+                column_alignments.entry(cell.column).or_insert(halign);
+            }
+        }
+
+        let (horizontal_default, vertical_default) = alignment.to_gtk();
+        let layouts: Vec<_> = cells.iter().map(|cell| (cell.row, cell.column, cell.span.unwrap_or(column_count), cell.valign)).collect();
+        let (widgets, removed) = self.reconcile_children(cells.into_iter().map(|cell| (cell.path, cell.node)).collect(), difference);
+
+        for widget in removed {
+            grid.remove(&widget);
+        }
+
+        for ((widget, is_new), (row, column, span, valign)) in widgets.into_iter().zip(layouts) {
+            let is_placed = !is_new && grid.query_child(&widget) == (column, row, span, 1);
+            if !is_placed {
+                if !is_new {
+                    grid.remove(&widget);
+                }
+                grid.attach(&widget, column, row, span, 1);
+            }
+            widget.set_halign(column_alignments.get(&column).copied().unwrap_or(horizontal_default));
+            widget.set_valign(valign.unwrap_or(vertical_default));
+        }
+    }
+}
+
+/// The cells of the grid with the given wrapped node, along with their
+/// positions. Children that aren't grid rows span an entire row.
+fn grid_cells(wrapped: &Identified<Node>) -> Vec<GridCell<'_>> {
+    let mut cells = Vec::new();
+    for (row, (path, child)) in flattened_children(wrapped).into_iter().enumerate() {
+        let row = i32::try_from(row).unwrap_or(i32::MAX);
+        match child {
+            Node::GridRow { alignment, wrapped: row_wrapped } => {
+                let mut column = 0;
+                for (cell_path, cell) in row_wrapped.value().children_from(&path.child(row_wrapped.id().clone())) {
+                    let span = column_span(cell);
+                    cells.push(GridCell { path: cell_path, node: cell, row, column, span: Some(span), valign: alignment.map(ToGtk::to_gtk) });
+                    column += span;
+                }
+            },
+            _ => cells.push(GridCell { path, node: child, row, column: 0, span: None, valign: None }),
+        }
+    }
+    cells
+}
+
+/// The number of columns spanned by the given cell.
+fn column_span(cell: &Node) -> i32 {
+    grid_modifiers(cell)
+        .find_map(|modifier| match modifier {
+            ModifierNode::GridCellColumns { count } => i32::try_from(*count).ok(),
+            _ => None,
+        })
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// The alignment the given cell specifies for its column, if any.
+fn column_alignment(cell: &Node) -> Option<Align> {
+    grid_modifiers(cell).find_map(|modifier| match modifier {
+        ModifierNode::GridColumnAlignment { alignment } => Some(alignment.to_gtk()),
+        _ => None,
+    })
+}
+
+/// The modifiers applied to the given cell, from the outermost inwards.
+fn grid_modifiers(cell: &Node) -> impl Iterator<Item = &ModifierNode> {
+    let mut node = cell;
+    std::iter::from_fn(move || match node {
+        Node::Modified { wrapped, modifier } => {
+            node = wrapped.value();
+            Some(modifier)
+        },
+        _ => None,
+    })
+}
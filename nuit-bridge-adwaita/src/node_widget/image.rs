@@ -0,0 +1,37 @@
+use std::iter;
+
+use adw::{gdk::{self, gdk_pixbuf::Pixbuf}, gio::{self, MemoryInputStream}, glib, gtk::{self, Picture}, prelude::*};
+use nuit_core::ImageSource;
+
+use super::NodeWidget;
+
+/// Creates the GTK widget for an image from the given source. Files and
+/// encoded bytes are shown as pictures, symbols as icons from the icon theme.
+/// Like in `SwiftUI`, images are shown at their natural size unless resizable.
+pub fn build_image(source: &ImageSource) -> gtk::Widget {
+    let picture = match source {
+        ImageSource::File { path } => Picture::for_filename(path),
+        ImageSource::Bytes { data } => {
+            let stream = MemoryInputStream::from_bytes(&glib::Bytes::from(data));
+            match Pixbuf::from_stream(&stream, None::<&gio::Cancellable>) {
+                Ok(pixbuf) => Picture::for_paintable(&gdk::Texture::for_pixbuf(&pixbuf)),
+                Err(error) => {
+                    eprintln!("Warning: Could not decode image: {error}");
+                    Picture::new()
+                },
+            }
+        },
+        ImageSource::Symbol { name } => return gtk::Image::from_icon_name(name).upcast(),
+    };
+    picture.set_can_shrink(false);
+    picture.upcast()
+}
+
+/// The picture rendered by the given node widget, looking through the node
+/// widgets of modifiers wrapping it.
+pub fn wrapped_picture(widget: &NodeWidget) -> Option<Picture> {
+    iter::successors(Some(widget.clone().upcast::<gtk::Widget>()), |widget| {
+        if widget.is::<NodeWidget>() { widget.first_child() } else { None }
+    })
+    .find_map(|widget| widget.downcast::<Picture>().ok())
+}
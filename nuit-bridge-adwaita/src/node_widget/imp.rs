@@ -1,21 +1,58 @@
-use std::{cell::{Cell, RefCell}, rc::Rc};
+use std::{cell::{Cell, RefCell}, collections::HashMap, rc::Rc};
 
-use adw::{glib, gtk, subclass::prelude::*};
-use nuit_core::{Event, IdPath, IdPathBuf, Node};
+use adw::{glib, gtk::{self, Align, CssProvider}, subclass::prelude::*, HeaderBar, NavigationPage};
+use nuit_core::{Event, EventResponse, IdPath, IdPathBuf, Node, ToolbarItemPlacement};
+use serde_json::Value;
 
 // See https://gtk-rs.org/gtk4-rs/stable/latest/book/g_object_subclassing.html
 
 // Object holding the state
 #[derive(Default)]
 pub struct NodeWidget {
-    pub node: Cell<Node>,
+    pub node: RefCell<Node>,
     pub id_path: RefCell<IdPathBuf>,
 
     // Yeah, this is a complex type. We need interior mutability to access it
     // via `&self` (RefCell), optionality to allow default-constructing it
     // (Option) and cloneability (Rc).
     #[allow(clippy::type_complexity)]
-    pub fire_event: RefCell<Option<Rc<dyn Fn(&IdPath, &Event)>>>,
+    pub fire_event: RefCell<Option<Rc<dyn Fn(&IdPath, &Event) -> EventResponse>>>,
+
+    /// Whether the node contains views that are resolved through events and
+    /// may thus change without showing up in a difference. Cached since
+    /// computing it requires scanning all descendants.
+    pub has_dynamic_content: Cell<bool>,
+
+    /// The GTK widget rendering the node itself (if any).
+    pub widget: RefCell<Option<gtk::Widget>>,
+    /// The node widgets rendering the node's children, keyed by their id paths
+    /// relative to this widget.
+    pub children: RefCell<Vec<(IdPathBuf, super::NodeWidget)>>,
+    /// Whether the widget is currently being updated programmatically. Signals
+    /// emitted during that time are not forwarded as events, since they don't
+    /// originate from the user.
+    pub is_updating: Rc<Cell<bool>>,
+    /// The alignment of the GTK widget within this widget, if overridden (e.g.
+    /// by a frame modifier).
+    pub content_alignment: Cell<Option<(Align, Align)>>,
+    /// The CSS provider used to style the child if the node is modified.
+    pub css_provider: RefCell<Option<CssProvider>>,
+    /// The navigation pages pushed on top of the root page (for navigation
+    /// stacks) or shown as detail (for navigation split views), along with
+    /// the values they were resolved from.
+    pub pages: RefCell<Vec<(Value, NavigationPage)>>,
+    /// The header bar of the window, which toolbar items are added to.
+    pub header_bar: RefCell<Option<HeaderBar>>,
+    /// The node widgets rendering the toolbar items (if the node is a
+    /// toolbar), which are shown in the header bar while this widget is mapped.
+    pub toolbar_items: RefCell<Vec<(ToolbarItemPlacement, super::NodeWidget)>>,
+    /// The dialog or popover presenting the content (if the node is a sheet,
+    /// popover, alert or confirmation dialog). Dialogs live outside of this
+    /// widget's hierarchy.
+    pub presentation: RefCell<Option<gtk::Widget>>,
+    /// The node widgets rendering the rows that are currently bound (if the
+    /// node is a lazy stack or list), keyed by their positions.
+    pub lazy_rows: RefCell<HashMap<u32, super::NodeWidget>>,
 }
 
 #[glib::object_subclass]
@@ -25,8 +62,22 @@ impl ObjectSubclass for NodeWidget {
     type ParentType = gtk::Box;
 }
 
-impl ObjectImpl for NodeWidget {}
+impl ObjectImpl for NodeWidget {
+    fn dispose(&self) {
+        self.obj().remove_presentation();
+    }
+}
 
-impl WidgetImpl for NodeWidget {}
+impl WidgetImpl for NodeWidget {
+    fn map(&self) {
+        self.parent_map();
+        self.obj().attach_toolbar_items();
+    }
+
+    fn unmap(&self) {
+        self.obj().detach_toolbar_items();
+        self.parent_unmap();
+    }
+}
 
 impl BoxImpl for NodeWidget {}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use adw::{gtk::{self, GridView, ListItem, ListView, NoSelection, Orientation, PolicyType, ScrolledWindow, SignalListItemFactory, StringList}, prelude::*, subclass::prelude::*};
+use nuit_core::{Event, EventResponse, GridItem, GridItemSize, Id, IdPath, IdPathBuf, Identified, Node};
+
+use crate::convert::ToGtk;
+
+use super::{imp, NodeWidget};
+
+/// The maximum number of columns of a grid view with adaptive columns.
+const MAX_ADAPTIVE_COLUMNS: u32 = 32;
+
+impl NodeWidget {
+    /// Creates a scrollable list view for a lazy stack or list (or a grid view
+    /// for a lazy grid). Rows are only requested from the view once the list
+    /// view binds them, i.e. when they are scrolled into view.
+    pub(super) fn build_lazy_list(&self, node: &Node) -> ScrolledWindow {
+        let (orientation, count) = lazy_orientation_and_count(node);
+        let factory = SignalListItemFactory::new();
+        let weak_self = self.downgrade();
+        factory.connect_bind(move |_factory, object| {
+            let (Some(widget), Some(list_item)) = (weak_self.upgrade(), object.downcast_ref::<ListItem>()) else { return };
+            widget.bind_lazy_row(list_item);
+        });
+        let weak_self = self.downgrade();
+        factory.connect_unbind(move |_factory, object| {
+            let (Some(widget), Some(list_item)) = (weak_self.upgrade(), object.downcast_ref::<ListItem>()) else { return };
+            widget.unbind_lazy_row(list_item);
+        });
+
+        let model = StringList::new(&[]);
+        resize_model(&model, count);
+        let selection = NoSelection::new(Some(model));
+        let view: gtk::Widget = if let Some(items) = grid_items(node) {
+            let grid_view = GridView::new(Some(selection), Some(factory));
+            grid_view.set_orientation(orientation);
+            set_grid_columns(&grid_view, items);
+            grid_view.upcast()
+        } else {
+            let list_view = ListView::new(Some(selection), Some(factory));
+            list_view.set_orientation(orientation);
+            if let Node::LazyList { .. } = node {
+                list_view.set_show_separators(true);
+                list_view.add_css_class("rich-list");
+            }
+            list_view.upcast()
+        };
+
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_hexpand(true);
+        scrolled_window.set_vexpand(true);
+        if orientation == Orientation::Horizontal {
+            scrolled_window.set_policy(PolicyType::Automatic, PolicyType::Never);
+        } else {
+            scrolled_window.set_policy(PolicyType::Never, PolicyType::Automatic);
+        }
+        scrolled_window.set_child(Some(&view));
+        scrolled_window
+    }
+
+    /// Updates the number of rows of a lazy stack, list or grid and re-requests
+    /// the rows that are currently bound. Returns false if the widget has to be
+    /// rebuilt instead.
+    pub(super) fn patch_lazy_list(&self, widget: &gtk::Widget, node: &Node) -> bool {
+        let (orientation, count) = lazy_orientation_and_count(node);
+        let Some(view) = widget.downcast_ref::<ScrolledWindow>().and_then(ScrolledWindow::child) else { return false };
+        let (selection, view_orientation) = match (view.downcast_ref::<GridView>(), view.downcast_ref::<ListView>(), grid_items(node)) {
+            (Some(grid_view), _, Some(items)) => {
+                set_grid_columns(grid_view, items);
+                (grid_view.model(), grid_view.orientation())
+            },
+            (_, Some(list_view), None) => (list_view.model(), list_view.orientation()),
+            _ => return false,
+        };
+        let Some(model) = selection.and_downcast::<NoSelection>().and_then(|selection| selection.model()).and_downcast::<StringList>() else { return false };
+        if view_orientation != orientation {
+            return false;
+        }
+
+        resize_model(&model, count);
+        self.refresh_lazy_rows(node);
+        true
+    }
+
+    /// Requests the row at the list item's position, shows it in the list
+    /// item and notifies it that it appeared.
+    fn bind_lazy_row(&self, list_item: &ListItem) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let position = list_item.position();
+        let Some(row) = self.fetch_lazy_rows(position, position + 1).remove(&position) else {
+            list_item.set_child(None::<&gtk::Widget>);
+            return;
+        };
+
+        let row_widget = self.create_child_with_path(row.value().clone(), &IdPathBuf::from(row.id().clone()));
+        layout_lazy_row(&imp.node.borrow(), &row_widget);
+        list_item.set_child(Some(&row_widget));
+        imp.lazy_rows.borrow_mut().insert(position, row_widget.clone());
+
+        // Rows are not part of the node tree, so we notify them ourselves
+        row_widget.fire(IdPath::root(), &Event::Appear {});
+    }
+
+    /// Removes the row from the list item and notifies it that it disappeared.
+    fn unbind_lazy_row(&self, list_item: &ListItem) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(row_widget) = list_item.child().and_downcast::<Self>() else { return };
+        imp.lazy_rows.borrow_mut().retain(|_, widget| *widget != row_widget);
+        list_item.set_child(None::<&gtk::Widget>);
+        row_widget.fire(IdPath::root(), &Event::Disappear {});
+    }
+
+    /// Re-requests the bound rows of the given lazy stack or list, since they
+    /// may depend on state that changed, and updates their node widgets.
+    fn refresh_lazy_rows(&self, node: &Node) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let bound_rows: Vec<(u32, Self)> = imp.lazy_rows.borrow().iter().map(|(&position, widget)| (position, widget.clone())).collect();
+        let (Some(start), Some(end)) = (bound_rows.iter().map(|(position, _)| *position).min(), bound_rows.iter().map(|(position, _)| *position).max()) else { return };
+
+        // Bound rows are usually contiguous, so we request them in one go
+        let mut rows = self.fetch_lazy_rows(start, end + 1);
+        for (position, row_widget) in bound_rows {
+            if let Some(row) = rows.remove(&position) {
+                layout_lazy_row(node, &row_widget);
+                row_widget.update(row.value().clone());
+            }
+        }
+    }
+
+    /// Requests the rows within the given range from the view, keyed by their
+    /// positions.
+    fn fetch_lazy_rows(&self, start: u32, end: u32) -> HashMap<u32, Identified<Node>> {
+        let event = Event::GetRows { start: start as usize, end: end as usize };
+        let nodes = match self.fire(IdPath::root(), &event) {
+            EventResponse::Nodes { nodes } => nodes,
+            EventResponse::Empty {} | EventResponse::Node { .. } | EventResponse::Error { .. } => Vec::new(),
+        };
+        nodes.into_iter()
+            .filter_map(|node| {
+                let position = match *node.id() {
+                    Id::Index(i) => u32::try_from(i).ok()?,
+                    Id::String(_) => return None,
+                };
+                Some((position, node))
+            })
+            .collect()
+    }
+}
+
+/// The orientation and number of rows of the given lazy stack, list or grid.
+const fn lazy_orientation_and_count(node: &Node) -> (Orientation, usize) {
+    match *node {
+        Node::LazyHStack { count, .. } | Node::LazyHGrid { count, .. } => (Orientation::Horizontal, count),
+        Node::LazyVStack { count, .. } | Node::LazyList { count } | Node::LazyVGrid { count, .. } => (Orientation::Vertical, count),
+        _ => (Orientation::Vertical, 0),
+    }
+}
+
+/// The columns (or rows) of the given lazy grid, if it is one.
+fn grid_items(node: &Node) -> Option<&[GridItem]> {
+    match node {
+        Node::LazyVGrid { columns: items, .. } | Node::LazyHGrid { rows: items, .. } => Some(items),
+        _ => None,
+    }
+}
+
+/// Sets the number of columns of a grid view. Grid views only support cells
+/// of equal size, so fixed and flexible items each become a column, while
+/// adaptive items let the grid view fit as many columns as possible.
+fn set_grid_columns(grid_view: &GridView, items: &[GridItem]) {
+    let is_adaptive = items.iter().any(|item| matches!(item.size, GridItemSize::Adaptive { .. }));
+    let (min_columns, max_columns) = if is_adaptive {
+        (1, MAX_ADAPTIVE_COLUMNS)
+    } else {
+        let count = u32::try_from(items.len()).unwrap_or(u32::MAX).max(1);
+        (count, count)
+    };
+    // The minimum may not exceed the maximum at any point
+    grid_view.set_min_columns(1);
+    grid_view.set_max_columns(max_columns);
+    grid_view.set_min_columns(min_columns);
+}
+
+/// The smallest size of the given grid items, which every cell is given.
+fn min_grid_item_size(items: &[GridItem]) -> Option<f64> {
+    items.iter()
+        .map(|item| match item.size {
+            GridItemSize::Fixed { size } => size,
+            GridItemSize::Flexible { minimum, .. } | GridItemSize::Adaptive { minimum, .. } => minimum,
+        })
+        .reduce(f64::min)
+}
+
+/// Applies the alignment, spacing and (for grids) cell size of the given lazy
+/// stack or grid to a row.
+#[allow(clippy::cast_possible_truncation)]
+fn layout_lazy_row(node: &Node, row_widget: &NodeWidget) {
+    match *node {
+        Node::LazyVGrid { ref columns, alignment, spacing, .. } => {
+            row_widget.set_halign(alignment.to_gtk());
+            row_widget.set_margin_bottom(spacing as i32);
+            row_widget.set_width_request(min_grid_item_size(columns).unwrap_or_default() as i32);
+        },
+        Node::LazyHGrid { ref rows, alignment, spacing, .. } => {
+            row_widget.set_valign(alignment.to_gtk());
+            row_widget.set_margin_end(spacing as i32);
+            row_widget.set_height_request(min_grid_item_size(rows).unwrap_or_default() as i32);
+        },
+        Node::LazyVStack { alignment, spacing, .. } => {
+            row_widget.set_halign(alignment.to_gtk());
+            row_widget.set_margin_bottom(spacing as i32);
+        },
+        Node::LazyHStack { alignment, spacing, .. } => {
+            row_widget.set_valign(alignment.to_gtk());
+            row_widget.set_margin_end(spacing as i32);
+        },
+        _ => {},
+    }
+}
+
+/// Adds or removes items at the end of the given model until it has the given
+/// number of items. The items are placeholders, since the list view only
+/// needs their positions.
+fn resize_model(model: &StringList, count: usize) {
+    let count = u32::try_from(count).unwrap_or(u32::MAX);
+    let n_items = model.n_items();
+    if count > n_items {
+        let additions = vec![""; (count - n_items) as usize];
+        model.splice(n_items, 0, &additions);
+    } else if count < n_items {
+        model.splice(count, n_items - count, &[]);
+    }
+}
@@ -0,0 +1,250 @@
+use adw::{gtk::{self, gdk, Align, Button, EventSequenceState, GestureClick, ListBox, ListBoxRow, Orientation, Popover, PropagationPhase}, prelude::*, subclass::prelude::*};
+use nuit_core::{ButtonRole, Difference, Event, Id, IdPath, IdPathBuf, Node, SelectionMode};
+
+use super::{event_sink::EventSink, imp, presentation::action_buttons, NodeWidget};
+
+/// An entry of a context menu, which fires the given event when chosen.
+type MenuEntry = (String, Option<ButtonRole>, EventSink, Event);
+
+impl NodeWidget {
+    /// Creates a list box for a list or a section. Rows are selected as
+    /// specified by the selection mode and report their ids back to the view.
+    pub(super) fn build_list(&self, node: &Node) -> ListBox {
+        let selection_mode = match node {
+            Node::List { selection_mode, .. } => *selection_mode,
+            _ => SelectionMode::None,
+        };
+        let list_box = ListBox::new();
+        list_box.set_selection_mode(match selection_mode {
+            SelectionMode::None => gtk::SelectionMode::None,
+            SelectionMode::Single => gtk::SelectionMode::Single,
+            SelectionMode::Multiple => gtk::SelectionMode::Multiple,
+        });
+        list_box.add_css_class("boxed-list");
+
+        let event_sink = self.event_sink();
+        list_box.connect_selected_rows_changed(move |list_box| {
+            let selection = list_box.selected_rows()
+                .into_iter()
+                .filter_map(|row| row_id(&row))
+                .collect();
+            event_sink.fire(&Event::UpdateSelection { selection });
+        });
+
+        self.patch_list(&list_box, node, None);
+        list_box
+    }
+
+    /// Updates the rows of a list or section and the selected rows.
+    pub(super) fn patch_list(&self, list_box: &ListBox, node: &Node, difference: Option<&Difference<&Node>>) {
+        self.update_list_children(list_box, list_rows(node), difference);
+
+        let selection = match node {
+            Node::List { selection, .. } => selection.as_slice(),
+            _ => &[],
+        };
+        for row in iter_rows(list_box) {
+            let is_selected = row.is_selectable() && row_id(&row).is_some_and(|id| selection.contains(&id));
+            if is_selected && !row.is_selected() {
+                list_box.select_row(Some(&row));
+            } else if !is_selected && row.is_selected() {
+                list_box.unselect_row(&row);
+            }
+        }
+    }
+
+    /// Updates the node widgets in the given list box to match the given rows,
+    /// each of which gets its own list box row.
+    fn update_list_children(&self, list_box: &ListBox, rows: Vec<(IdPathBuf, &Node, bool)>, difference: Option<&Difference<&Node>>) {
+        let is_content: Vec<bool> = rows.iter().map(|&(_, _, is_content)| is_content).collect();
+        let children = rows.into_iter().map(|(path, node, _)| (path, node)).collect();
+        let (widgets, removed) = self.reconcile_children(children, difference);
+
+        for widget in removed {
+            if let Some(row) = widget.parent() {
+                list_box.remove(&row);
+            }
+        }
+
+        for (i, ((widget, is_new), is_content)) in widgets.into_iter().zip(is_content).enumerate() {
+            let position = i32::try_from(i).unwrap_or(i32::MAX);
+            if is_new {
+                widget.set_halign(Align::Start);
+                let row = ListBoxRow::new();
+                row.set_activatable(false);
+                row.set_selectable(is_content);
+                if is_content {
+                    self.attach_row_menu(&row, &widget);
+                } else {
+                    row.add_css_class("dim-label");
+                }
+                row.set_child(Some(&widget));
+                list_box.insert(&row, position);
+            } else if let Some(row) = widget.parent().and_downcast::<ListBoxRow>() {
+                if row.index() != position {
+                    list_box.remove(&row);
+                    list_box.insert(&row, position);
+                }
+            }
+        }
+    }
+
+    /// Shows a menu with the given row's context or swipe actions and, if the
+    /// row is editable, entries for deleting and moving it on secondary
+    /// clicks.
+    fn attach_row_menu(&self, row: &ListBoxRow, row_widget: &Self) {
+        let gesture = GestureClick::new();
+        gesture.set_button(gdk::BUTTON_SECONDARY);
+        // We handle the click before the row's content, which would otherwise
+        // show a context menu without the editing entries
+        gesture.set_propagation_phase(PropagationPhase::Capture);
+        let weak_self = self.downgrade();
+        let weak_row_widget = row_widget.downgrade();
+        gesture.connect_pressed(move |gesture, _n_press, x, y| {
+            let (Some(widget), Some(row_widget)) = (weak_self.upgrade(), weak_row_widget.upgrade()) else { return };
+            let mut entries = widget.editing_entries(&row_widget);
+            entries.extend(action_entries(&row_widget));
+            if let Some(row) = gesture.widget() {
+                if show_menu(&row, x, y, entries) {
+                    gesture.set_state(EventSequenceState::Claimed);
+                }
+            }
+        });
+        row.add_controller(gesture);
+    }
+
+    /// Shows a menu with the actions of a context menu on secondary clicks on
+    /// the given widget.
+    pub(super) fn attach_context_menu(&self, child: &Self) {
+        let gesture = GestureClick::new();
+        gesture.set_button(gdk::BUTTON_SECONDARY);
+        let weak_self = self.downgrade();
+        gesture.connect_pressed(move |gesture, _n_press, x, y| {
+            let Some(widget) = weak_self.upgrade() else { return };
+            if let Some(child) = gesture.widget() {
+                if show_menu(&child, x, y, action_entries(&widget)) {
+                    gesture.set_state(EventSequenceState::Claimed);
+                }
+            }
+        });
+        child.add_controller(gesture);
+    }
+
+    /// The entries for deleting and moving the given row, if it belongs to an
+    /// editable group within the list rendered by this widget.
+    fn editing_entries(&self, row_widget: &Self) -> Vec<MenuEntry> {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(row_path) = imp.children.borrow().iter().find(|(_, widget)| widget == row_widget).map(|(path, _)| path.clone()) else { return Vec::new() };
+        let node = imp.node.borrow();
+
+        let mut entries = Vec::new();
+        for (path, descendant) in node.descendants() {
+            let Node::Editable { wrapped, can_delete, can_move } = descendant else { continue };
+            let siblings = wrapped.value().children_from(&path.child(wrapped.id().clone()));
+            let Some(index) = siblings.iter().position(|(sibling_path, _)| *sibling_path == row_path) else { continue };
+
+            let event_sink = self.event_sink_at(&path);
+            if *can_move && index > 0 {
+                entries.push(("Move Up".to_owned(), None, event_sink.clone(), Event::MoveRows { indices: vec![index], destination: index - 1 }));
+            }
+            if *can_move && index + 1 < siblings.len() {
+                entries.push(("Move Down".to_owned(), None, event_sink.clone(), Event::MoveRows { indices: vec![index], destination: index + 2 }));
+            }
+            if *can_delete {
+                entries.push(("Delete".to_owned(), Some(ButtonRole::Destructive), event_sink, Event::DeleteRows { indices: vec![index] }));
+            }
+        }
+        entries
+    }
+}
+
+/// The rows of the given list or section, keyed by their id paths relative to
+/// the given node, along with whether they are content rows (as opposed to
+/// headers and footers of sections).
+fn list_rows(node: &Node) -> Vec<(IdPathBuf, &Node, bool)> {
+    let children = match node {
+        Node::List { wrapped, .. } => wrapped.value().children_from(&IdPathBuf::from(wrapped.id().clone())),
+        _ => node.children_from(IdPath::root()),
+    };
+
+    let mut rows = Vec::new();
+    for (path, child) in children {
+        match child {
+            Node::Section { header, content, footer } => {
+                if !matches!(header.value(), Node::Empty {}) {
+                    rows.push((path.child(header.id().clone()), header.value(), false));
+                }
+                for (content_path, row) in content.value().children_from(&path.child(content.id().clone())) {
+                    rows.push((content_path, row, true));
+                }
+                if !matches!(footer.value(), Node::Empty {}) {
+                    rows.push((path.child(footer.id().clone()), footer.value(), false));
+                }
+            },
+            _ => rows.push((path, child, true)),
+        }
+    }
+    rows
+}
+
+/// The entries for the buttons of the context or swipe actions rendered by
+/// the given node widget, if any.
+fn action_entries(widget: &NodeWidget) -> Vec<MenuEntry> {
+    let imp = imp::NodeWidget::from_obj(widget);
+    let node = imp.node.borrow();
+    let (Node::ContextMenu { actions, .. } | Node::SwipeActions { actions, .. }) = &*node else { return Vec::new() };
+    action_buttons(actions)
+        .into_iter()
+        .map(|(path, label, role)| (label, role, widget.event_sink_at(&path), Event::ButtonTap {}))
+        .collect()
+}
+
+/// Pops up a menu with the given entries at the given position within the
+/// given widget. Returns false if there are no entries.
+fn show_menu(parent: &gtk::Widget, x: f64, y: f64, entries: Vec<MenuEntry>) -> bool {
+    if entries.is_empty() {
+        return false;
+    }
+
+    let popover = Popover::new();
+    let menu_box = gtk::Box::new(Orientation::Vertical, 0);
+    for (label, role, event_sink, event) in entries {
+        let button = Button::with_label(&label);
+        button.add_css_class("flat");
+        if let Some(ButtonRole::Destructive) = role {
+            button.add_css_class("destructive-action");
+        }
+        button.connect_clicked(move |button| {
+            if let Some(popover) = button.ancestor(Popover::static_type()).and_downcast::<Popover>() {
+                popover.popdown();
+            }
+            event_sink.fire(&event);
+        });
+        menu_box.append(&button);
+    }
+    popover.set_child(Some(&menu_box));
+    popover.set_has_arrow(false);
+    #[allow(clippy::cast_possible_truncation)]
+    popover.set_pointing_to(Some(&gdk::Rectangle::new(x as i32, y as i32, 1, 1)));
+    popover.set_parent(parent);
+    popover.connect_closed(|popover| {
+        // Unparenting during the closed signal is not allowed
+        let popover = popover.clone();
+        adw::glib::idle_add_local_once(move || popover.unparent());
+    });
+    popover.popup();
+    true
+}
+
+/// The id of the row rendered in the given list box row, i.e. the last
+/// component of its node widget's id path.
+fn row_id(row: &ListBoxRow) -> Option<Id> {
+    row.child()
+        .and_downcast::<NodeWidget>()
+        .and_then(|widget| imp::NodeWidget::from_obj(&widget).id_path.borrow().last())
+}
+
+/// The rows of the given list box, in order.
+fn iter_rows(list_box: &ListBox) -> impl Iterator<Item = ListBoxRow> + '_ {
+    (0..).map_while(move |i| list_box.row_at_index(i))
+}
@@ -1,12 +1,34 @@
+mod controls;
+mod event_sink;
+mod gesture;
+mod grid;
+mod image;
 mod imp;
+mod lazy;
+mod list;
+mod modifier;
+mod navigation;
+mod presentation;
+mod shape;
+mod text_field;
+mod toolbar;
 
-use std::rc::Rc;
+use std::{collections::HashMap, iter, mem, rc::Rc};
 
-use adw::{glib::{self, Object}, gtk::{self, Align, Button, Label, Orientation, Scale, Text}, prelude::{BoxExt, ButtonExt, EditableExt, RangeExt, WidgetExt}, subclass::prelude::*};
-use nuit_core::{clone, Event, Id, IdPath, IdPathBuf, Identified, Node};
+use adw::{glib::{self, Object}, gtk::{self, Align, Button, DrawingArea, DropDown, Label, ListBox, Orientation, Overlay, StringList}, prelude::*, subclass::prelude::*, HeaderBar};
+use nuit_core::{Alignment, ButtonRole, Diff, Difference, Event, EventResponse, Geometry, Id, IdPath, IdPathBuf, Identified, Node, Vec2, DEFAULT_SPACING};
 
 use crate::convert::ToGtk;
 
+use controls::{build_date_picker, build_progress_view, build_slider, build_stepper, build_toggle, patch_date_picker, patch_progress_view, patch_slider, patch_stepper, patch_titled, patch_toggle, titled};
+use event_sink::EventSink;
+use gesture::attach_gesture;
+use image::build_image;
+use modifier::{apply_modifier, attach_css_provider};
+use presentation::action_buttons;
+use shape::draw_shape;
+use text_field::{build_text_field, patch_text_field};
+
 // See https://gtk-rs.org/gtk4-rs/stable/latest/book/g_object_subclassing.html
 
 glib::wrapper! {
@@ -19,13 +41,15 @@ impl NodeWidget {
     fn new(
         node: Node,
         id_path: IdPathBuf,
-        fire_event: Option<Rc<dyn Fn(&IdPath, &Event)>>,
+        fire_event: Option<Rc<dyn Fn(&IdPath, &Event) -> EventResponse>>,
+        header_bar: Option<HeaderBar>,
     ) -> Self {
         let widget: Self = Object::builder().build();
 
         let imp = imp::NodeWidget::from_obj(&widget);
         imp.id_path.replace(id_path);
         imp.fire_event.replace(fire_event);
+        imp.header_bar.replace(header_bar);
 
         widget.set_halign(Align::Center);
         widget.set_valign(Align::Center);
@@ -36,112 +60,772 @@ impl NodeWidget {
         widget
     }
 
-    pub fn root(node: Node, fire_event: impl Fn(&IdPath, &Event) + 'static) -> Self {
-        Self::new(node, IdPathBuf::root(), Some(Rc::new(fire_event)))
+    /// Creates the widget for the root node of a window. Toolbar items are
+    /// added to the given header bar.
+    pub fn root(node: Node, header_bar: &HeaderBar, fire_event: impl Fn(&IdPath, &Event) -> EventResponse + 'static) -> Self {
+        Self::new(node, IdPathBuf::root(), Some(Rc::new(fire_event)), Some(header_bar.clone()))
     }
 
-    fn create_child_with_id(&self, node: Node, id: &Id) -> Self {
+    fn create_child_with_path(&self, node: Node, child_path: &IdPath) -> Self {
         let imp = imp::NodeWidget::from_obj(self);
-        let id_path = imp.id_path.borrow().child(id.clone());
+        let id_path = imp.id_path.borrow().join(child_path);
         let fire_event = imp.fire_event.borrow().clone();
+        let header_bar = imp.header_bar.borrow().clone();
 
-        Self::new(node, id_path, fire_event)
+        Self::new(node, id_path, fire_event, header_bar)
     }
 
-    fn create_child_from_identified(&self, node: &Identified<Node>) -> Self {
-        self.create_child_with_id(node.value().clone(), node.id())
+    /// Overrides the alignment of the rendered GTK widget within this widget.
+    fn set_content_alignment(&self, alignment: Option<(Align, Align)>) {
+        let imp = imp::NodeWidget::from_obj(self);
+        imp.content_alignment.set(alignment);
+        if let (Some(widget), Some(alignment)) = (&*imp.widget.borrow(), alignment) {
+            align_content(widget, alignment);
+        }
     }
 
-    fn create_child_with_path(&self, node: Node, child_path: &IdPath) -> Self {
+    /// Fires the given event at the given path relative to this widget,
+    /// returning the response. Unlike the event sinks, this is intended for
+    /// events that don't originate from the user, e.g. for resolving views.
+    fn fire(&self, child_path: &IdPath, event: &Event) -> EventResponse {
         let imp = imp::NodeWidget::from_obj(self);
         let id_path = imp.id_path.borrow().join(child_path);
         let fire_event = imp.fire_event.borrow().clone();
+        fire_event.map_or_else(EventResponse::default, |fire_event| fire_event(&id_path, event))
+    }
 
-        Self::new(node, id_path, fire_event)
+    fn event_sink(&self) -> EventSink {
+        self.event_sink_at(IdPath::root())
     }
 
-    // TODO: Address the pass-by-value lint once we figure out a proper solution
-    // to the diffing problem
+    fn event_sink_at(&self, child_path: &IdPath) -> EventSink {
+        let imp = imp::NodeWidget::from_obj(self);
+        EventSink::new(
+            imp.id_path.borrow().join(child_path),
+            imp.fire_event.borrow().clone(),
+            imp.is_updating.clone(),
+        )
+    }
 
-    #[allow(clippy::cast_possible_truncation, clippy::needless_pass_by_value)]
+    /// Updates the widget to render the given node. Only the GTK widgets
+    /// affected by the difference to the previously rendered node are patched,
+    /// everything else (including e.g. focus and cursor positions) is kept.
     pub fn update(&self, node: Node) {
         let imp = imp::NodeWidget::from_obj(self);
-        imp.node.replace(node.clone());
+        let old_node = imp.node.take();
+        let difference = node.diff(&old_node);
 
-        let id_path = imp.id_path.borrow();
-        let fire_event = imp.fire_event.borrow();
+        if !difference.is_empty() {
+            imp.has_dynamic_content.set(has_dynamic_content(&node));
+        }
 
-        // TODO: Diff nodes instead of replacing the entire tree
+        if !difference.is_empty() || imp.has_dynamic_content.get() {
+            imp.is_updating.set(true);
+            let patched = mem::discriminant(&node) == mem::discriminant(&old_node)
+                && self.patch(&old_node, &node, &difference);
+            if !patched {
+                self.rebuild(&node);
+            }
+            imp.is_updating.set(false);
+        }
+
+        imp.node.replace(node);
+    }
+
+    /// Discards all GTK widgets and creates new ones for the given node.
+    fn rebuild(&self, node: &Node) {
+        let imp = imp::NodeWidget::from_obj(self);
 
         while let Some(child) = self.first_child() {
             self.remove(&child);
         }
+        imp.children.replace(Vec::new());
+        imp.css_provider.replace(None);
+        imp.pages.replace(Vec::new());
+        self.detach_toolbar_items();
+        imp.toolbar_items.replace(Vec::new());
+        self.remove_presentation();
+
+        let widget = self.build(node);
+        if let Some(ref widget) = widget {
+            if let Some(alignment) = imp.content_alignment.get() {
+                align_content(widget, alignment);
+            }
+            self.append(widget);
+        }
+        imp.widget.replace(widget);
+    }
 
-        match &node {
-            Node::Empty {} => {},
+    /// Creates the GTK widget for the given node.
+    fn build(&self, node: &Node) -> Option<gtk::Widget> {
+        match node {
+            Node::Empty {} => None,
             Node::Text { content } => {
                 let label = Label::new(Some(content));
-                self.append(&label);
+                Some(label.upcast())
             },
-            Node::TextField { content } => {
-                let text = Text::builder().text(content).build();
-                if let Some(ref fire_event) = *fire_event {
-                    text.connect_changed(clone!(fire_event, id_path => move |text| {
-                        fire_event(&id_path, &Event::UpdateText { content: text.text().into() });
-                    }));
-                }
-                self.append(&text);
-            },
-            Node::Button { label } => {
-                let button = Button::new();
-                match label.value() {
-                    Node::Text { content } => button.set_label(content),
-                    _ => button.set_child(Some(&self.create_child_from_identified(label))),
-                }
-                if let Some(ref fire_event) = *fire_event {
-                    button.connect_clicked(clone!(fire_event, id_path => move |_button| {
-                        fire_event(&id_path, &Event::ButtonTap {});
-                    }));
-                }
-                self.append(&button);
+            Node::TextField { content, prompt, kind, keyboard_type, is_autocorrection_disabled } => {
+                Some(build_text_field(content, prompt.as_deref(), *kind, *keyboard_type, *is_autocorrection_disabled, self.event_sink()))
             },
+            Node::Button { label, role } => Some(self.build_action_button(label, *role).upcast()),
+            Node::Picker { title, selection, content } => Some(self.build_picker(title, selection, content)),
             Node::Slider { value, lower_bound, upper_bound, step } => {
-                let scale = Scale::with_range(Orientation::Horizontal, *lower_bound, *upper_bound, step.unwrap_or(1e-32));
-                scale.set_value(*value);
-                scale.set_width_request(150);
-                if let Some(ref fire_event) = *fire_event {
-                    scale.connect_value_changed(clone!(fire_event, id_path => move |scale| {
-                        let value = scale.value();
-                        fire_event(&id_path, &Event::UpdateSliderValue { value });
-                    }));
-                }
-                self.append(&scale);
+                Some(build_slider(*value, *lower_bound, *upper_bound, *step, self.event_sink()))
+            },
+            Node::Image { source } => Some(build_image(source)),
+            Node::Toggle { title, is_on } => Some(build_toggle(title, *is_on, self.event_sink())),
+            Node::Stepper { title, value, lower_bound, upper_bound, step } => {
+                Some(build_stepper(title, *value, *lower_bound, *upper_bound, *step, self.event_sink()))
+            },
+            Node::ProgressView { value } => Some(build_progress_view(*value)),
+            Node::DatePicker { title, date } => Some(build_date_picker(title, *date, self.event_sink())),
+            Node::Child { wrapped }
+            | Node::Editable { wrapped, .. }
+            | Node::SwipeActions { wrapped, .. }
+            | Node::NavigationDestination { wrapped }
+            | Node::ToolbarItem { placement: _, wrapped } => Some(self.update_child(wrapped, None).upcast()),
+            Node::Group { .. }
+            | Node::GeometryReader {}
+            | Node::HStack { .. }
+            | Node::VStack { .. }
+            | Node::ZStack { .. }
+            | Node::Grid { .. }
+            | Node::GridRow { .. }
+            | Node::Overlay { .. } => self.build_layout(node),
+            Node::List { .. } | Node::Section { .. } => Some(self.build_list(node).upcast()),
+            Node::LazyVStack { .. } | Node::LazyHStack { .. } | Node::LazyList { .. } | Node::LazyVGrid { .. } | Node::LazyHGrid { .. } => {
+                Some(self.build_lazy_list(node).upcast())
+            },
+            Node::NavigationStack { path, wrapped } => {
+                Some(self.build_navigation_stack(path.as_deref(), wrapped, node).upcast())
+            },
+            Node::NavigationSplitView { sidebar, content, detail } => {
+                Some(self.build_navigation_split_view(sidebar, content, detail).upcast())
+            },
+            Node::NavigationLink { label, value: _ } => Some(self.build_navigation_link(label).upcast()),
+            Node::Toolbar { wrapped, items } => Some(self.update_toolbar(wrapped, items, None).upcast()),
+            Node::Sheet { wrapped, is_presented, content } => Some(self.update_sheet(wrapped, *is_presented, content, None).upcast()),
+            Node::Popover { wrapped, is_presented, content } => Some(self.update_popover(wrapped, *is_presented, content, None).upcast()),
+            Node::Alert { wrapped, title, message, is_presented, actions }
+            | Node::ConfirmationDialog { wrapped, title, message, is_presented, actions } => {
+                Some(self.update_alert(wrapped, title, message.as_deref(), *is_presented, actions, None).upcast())
+            },
+            Node::ContextMenu { wrapped, .. } => {
+                // The actions are looked up when the menu is shown, so that
+                // they don't have to be rendered as widgets
+                let child = self.update_child(wrapped, None);
+                self.attach_context_menu(&child);
+                Some(child.upcast())
+            },
+            Node::Shape { shape: _ } => Some(self.build_shape().upcast()),
+            Node::Gestured { wrapped, gesture } => {
+                let child = self.update_child(wrapped, None);
+                attach_gesture(&child, gesture.value(), self.event_sink_at(&IdPathBuf::from(gesture.id().clone())));
+                Some(child.upcast())
+            },
+            Node::Modified { wrapped, modifier } => {
+                let child = self.update_child(wrapped, None);
+                let css_provider = attach_css_provider(&child);
+                apply_modifier(&child, None, modifier, &css_provider);
+                imp::NodeWidget::from_obj(self).css_provider.replace(Some(css_provider));
+                Some(child.upcast())
             },
+        }
+    }
+
+    /// Creates the GTK widget for a node laying out its children, i.e. a
+    /// group, geometry reader, stack, grid (row) or overlay.
+    #[allow(clippy::cast_possible_truncation)]
+    fn build_layout(&self, node: &Node) -> Option<gtk::Widget> {
+        match node {
+            Node::Group { .. } => {
+                // Outside of stacks, groups are laid out vertically
+                let gtk_box = gtk::Box::new(Orientation::Vertical, DEFAULT_SPACING as i32);
+                self.update_box_children(&gtk_box, node.children(), None);
+                Some(gtk_box.upcast())
+            },
+            Node::GeometryReader {} => Some(self.build_geometry_reader().upcast()),
             Node::HStack { spacing, alignment, wrapped } => {
                 let gtk_box = gtk::Box::new(Orientation::Horizontal, *spacing as i32);
                 gtk_box.set_valign(alignment.to_gtk());
-                for (child_path, child) in wrapped.value().children_from(&IdPathBuf::from(wrapped.id().clone())) {
-                    gtk_box.append(&self.create_child_with_path(child.clone(), &child_path));
-                }
-                self.append(&gtk_box);
+                self.update_box_children(&gtk_box, flattened_children(wrapped), None);
+                Some(gtk_box.upcast())
             },
             Node::VStack { spacing, alignment, wrapped } => {
                 let gtk_box = gtk::Box::new(Orientation::Vertical, *spacing as i32);
                 gtk_box.set_halign(alignment.to_gtk());
-                for (child_path, child) in wrapped.value().children_from(&IdPathBuf::from(wrapped.id().clone())) {
-                    gtk_box.append(&self.create_child_with_path(child.clone(), &child_path));
+                self.update_box_children(&gtk_box, flattened_children(wrapped), None);
+                Some(gtk_box.upcast())
+            },
+            Node::ZStack { alignment, spacing: _, wrapped } => {
+                let overlay = Overlay::new();
+                self.update_overlay_children(&overlay, flattened_children(wrapped), *alignment, None);
+                Some(overlay.upcast())
+            },
+            Node::Grid { alignment, horizontal_spacing, vertical_spacing, wrapped } => {
+                let grid = gtk::Grid::new();
+                self.update_grid(&grid, *alignment, *horizontal_spacing, *vertical_spacing, wrapped, None);
+                Some(grid.upcast())
+            },
+            Node::GridRow { wrapped, .. } => {
+                // Outside of grids, rows are laid out horizontally
+                let gtk_box = gtk::Box::new(Orientation::Horizontal, DEFAULT_SPACING as i32);
+                self.update_box_children(&gtk_box, flattened_children(wrapped), None);
+                Some(gtk_box.upcast())
+            },
+            Node::Overlay { wrapped, alignment, overlayed } => {
+                let (widgets, _) = self.reconcile_children(vec![
+                    (IdPathBuf::from(wrapped.id().clone()), wrapped.value()),
+                    (IdPathBuf::from(overlayed.id().clone()), overlayed.value()),
+                ], None);
+                let [(wrapped_widget, _), (overlayed_widget, _)] = &widgets[..] else { return None };
+                let overlay = Overlay::new();
+                overlay.set_child(Some(wrapped_widget));
+                overlay.add_overlay(overlayed_widget);
+                align(overlayed_widget, *alignment);
+                Some(overlay.upcast())
+            },
+            _ => None,
+        }
+    }
+
+    /// Creates a button with the given label, which is rendered as a plain
+    /// string if it is a text and as a child widget otherwise.
+    fn build_button(&self, label: &Identified<Node>) -> Button {
+        let button = Button::new();
+        match label.value() {
+            Node::Text { content } => button.set_label(content),
+            _ => button.set_child(Some(&self.update_child(label, None))),
+        }
+        button
+    }
+
+    /// Creates a button firing taps for a button node.
+    fn build_action_button(&self, label: &Identified<Node>, role: Option<ButtonRole>) -> Button {
+        let button = self.build_button(label);
+        if let Some(ButtonRole::Destructive) = role {
+            button.add_css_class("destructive-action");
+        }
+        let event_sink = self.event_sink();
+        button.connect_clicked(move |_button| {
+            event_sink.fire(&Event::ButtonTap {});
+        });
+        button
+    }
+
+    /// Creates a button following a navigation link.
+    fn build_navigation_link(&self, label: &Identified<Node>) -> Button {
+        let button = self.build_button(label);
+        let weak_self = self.downgrade();
+        button.connect_clicked(move |_button| {
+            if let Some(widget) = weak_self.upgrade() {
+                widget.follow_navigation_link();
+            }
+        });
+        button
+    }
+
+    /// Creates a drop down for a picker. The options are looked up from the
+    /// current node when the selection changes.
+    fn build_picker(&self, title: &str, selection: &Id, content: &Identified<Node>) -> gtk::Widget {
+        let options = picker_options(content);
+        let labels: Vec<&str> = options.iter().map(|(_, label)| label.as_str()).collect();
+        let drop_down = DropDown::from_strings(&labels);
+        drop_down.set_selected(picker_position(&options, selection));
+        let event_sink = self.event_sink();
+        let weak_self = self.downgrade();
+        drop_down.connect_selected_notify(move |drop_down| {
+            let Some(widget) = weak_self.upgrade() else { return };
+            let id = match &*imp::NodeWidget::from_obj(&widget).node.borrow() {
+                Node::Picker { content, .. } => picker_options(content)
+                    .into_iter()
+                    .nth(drop_down.selected() as usize)
+                    .map(|(id, _)| id),
+                _ => None,
+            };
+            if let Some(id) = id {
+                event_sink.fire(&Event::UpdatePickerSelection { id });
+            }
+        });
+
+        titled(title, &drop_down).upcast()
+    }
+
+    /// Creates the widget for a geometry reader, which resolves its view
+    /// whenever it is resized.
+    fn build_geometry_reader(&self) -> Overlay {
+        // Drawing areas are the only widgets notifying us about their
+        // size, so we use one to fill the available space and overlay
+        // the resolved view on top of it.
+        let area = DrawingArea::new();
+        area.set_hexpand(true);
+        area.set_vexpand(true);
+        let weak_self = self.downgrade();
+        area.connect_resize(move |_area, _width, _height| {
+            // Changing widgets during size allocation is not allowed
+            let weak_self = weak_self.clone();
+            glib::idle_add_local_once(move || {
+                if let Some(widget) = weak_self.upgrade() {
+                    widget.update_geometry_reader();
+                }
+            });
+        });
+        let overlay = Overlay::new();
+        overlay.set_child(Some(&area));
+        overlay
+    }
+
+    /// Creates a drawing area for a shape, which draws the current node.
+    fn build_shape(&self) -> DrawingArea {
+        let area = DrawingArea::new();
+        area.set_hexpand(true);
+        area.set_vexpand(true);
+        let weak_self = self.downgrade();
+        area.set_draw_func(move |area, cr, width, height| {
+            let Some(widget) = weak_self.upgrade() else { return };
+            if let Node::Shape { shape } = &*imp::NodeWidget::from_obj(&widget).node.borrow() {
+                draw_shape(area, cr, shape, f64::from(width), f64::from(height));
+            }
+        });
+        area
+    }
+
+    /// Patches the existing GTK widget to render the given node, which has the
+    /// same variant as the old node. Returns false if the widget cannot be
+    /// patched and has to be rebuilt instead.
+    fn patch(&self, old_node: &Node, node: &Node, difference: &Difference<&Node>) -> bool {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(widget) = imp.widget.borrow().clone() else {
+            return matches!(node, Node::Empty {});
+        };
+        let difference = Some(difference);
+
+        match (old_node, node) {
+            (_, Node::Text { content }) => {
+                let Some(label) = widget.downcast_ref::<Label>() else { return false };
+                label.set_label(content);
+                true
+            },
+            (_, Node::TextField { content, prompt, kind, keyboard_type, is_autocorrection_disabled }) => {
+                patch_text_field(&widget, content, prompt.as_deref(), *kind, *keyboard_type, *is_autocorrection_disabled)
+            },
+            (Node::Button { label: old_label, role: old_role }, Node::Button { label, role }) => {
+                // The role determines the style classes, so we rebuild if it changes
+                let Some(button) = widget.downcast_ref::<Button>() else { return false };
+                old_role == role && self.patch_button(button, old_label, label, difference)
+            },
+            (Node::NavigationLink { label: old_label, .. }, Node::NavigationLink { label, .. }) => {
+                let Some(button) = widget.downcast_ref::<Button>() else { return false };
+                self.patch_button(button, old_label, label, difference)
+            },
+            (_, Node::Picker { title, selection, content }) => patch_picker(&widget, title, selection, content),
+            (_, Node::Slider { value, lower_bound, upper_bound, step }) => {
+                patch_slider(&widget, *value, *lower_bound, *upper_bound, *step)
+            },
+            (_, Node::Toggle { title, is_on }) => patch_toggle(&widget, title, *is_on),
+            (_, Node::Stepper { title, value, lower_bound, upper_bound, step }) => {
+                patch_stepper(&widget, title, *value, *lower_bound, *upper_bound, *step)
+            },
+            (_, Node::ProgressView { value }) => patch_progress_view(&widget, *value),
+            (_, Node::DatePicker { title, date }) => patch_date_picker(&widget, title, *date),
+            (Node::Child { wrapped: old_wrapped }, Node::Child { wrapped })
+            | (Node::Editable { wrapped: old_wrapped, .. }, Node::Editable { wrapped, .. })
+            | (Node::ContextMenu { wrapped: old_wrapped, .. }, Node::ContextMenu { wrapped, .. })
+            | (Node::SwipeActions { wrapped: old_wrapped, .. }, Node::SwipeActions { wrapped, .. })
+            | (Node::NavigationDestination { wrapped: old_wrapped }, Node::NavigationDestination { wrapped })
+            | (Node::ToolbarItem { wrapped: old_wrapped, .. }, Node::ToolbarItem { wrapped, .. }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                self.update_child(wrapped, difference);
+                true
+            },
+            (_, Node::Group { .. } | Node::GeometryReader {} | Node::HStack { .. } | Node::VStack { .. } | Node::ZStack { .. } | Node::Grid { .. } | Node::GridRow { .. })
+            | (Node::Overlay { .. }, Node::Overlay { .. }) => self.patch_layout(&widget, old_node, node, difference),
+            (Node::List { selection_mode: old_selection_mode, .. }, Node::List { selection_mode, .. }) => {
+                let Some(list_box) = widget.downcast_ref::<ListBox>() else { return false };
+                if old_selection_mode != selection_mode {
+                    return false;
+                }
+                self.patch_list(list_box, node, difference);
+                true
+            },
+            (_, Node::Section { .. }) => {
+                let Some(list_box) = widget.downcast_ref::<ListBox>() else { return false };
+                self.patch_list(list_box, node, difference);
+                true
+            },
+            (_, Node::LazyVStack { .. } | Node::LazyHStack { .. } | Node::LazyList { .. } | Node::LazyVGrid { .. } | Node::LazyHGrid { .. }) => {
+                self.patch_lazy_list(&widget, node)
+            },
+            (Node::NavigationStack { .. }, Node::NavigationStack { .. })
+            | (Node::NavigationSplitView { .. }, Node::NavigationSplitView { .. })
+            | (Node::Toolbar { .. }, Node::Toolbar { .. })
+            | (Node::Sheet { .. }, Node::Sheet { .. })
+            | (Node::Popover { .. }, Node::Popover { .. })
+            | (Node::Alert { .. }, Node::Alert { .. })
+            | (Node::ConfirmationDialog { .. }, Node::ConfirmationDialog { .. }) => self.patch_container(&widget, old_node, node, difference),
+            (_, Node::Shape { .. }) => {
+                let Some(area) = widget.downcast_ref::<DrawingArea>() else { return false };
+                area.queue_draw();
+                true
+            },
+            (Node::Gestured { wrapped: old_wrapped, gesture: old_gesture }, Node::Gestured { wrapped, gesture }) => {
+                // The event controllers capture the gesture, so we rebuild if it changes
+                if old_wrapped.id() != wrapped.id() || old_gesture != gesture {
+                    return false;
+                }
+                self.update_child(wrapped, difference);
+                true
+            },
+            (Node::Modified { wrapped: old_wrapped, modifier: old_modifier }, Node::Modified { wrapped, modifier }) => {
+                // Modifiers are only reapplied if they are of the same kind,
+                // since e.g. a padding would otherwise leave its margins behind.
+                if old_wrapped.id() != wrapped.id() || mem::discriminant(old_modifier) != mem::discriminant(modifier) {
+                    return false;
+                }
+                let child = self.update_child(wrapped, difference);
+                if old_modifier != modifier {
+                    let Some(ref css_provider) = *imp.css_provider.borrow() else { return false };
+                    apply_modifier(&child, Some(old_modifier), modifier, css_provider);
+                }
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Patches the widget of a node laying out its children, see
+    /// [`Self::build_layout`]. Returns false if the widget has to be rebuilt
+    /// instead.
+    #[allow(clippy::cast_possible_truncation)]
+    fn patch_layout(&self, widget: &gtk::Widget, old_node: &Node, node: &Node, difference: Option<&Difference<&Node>>) -> bool {
+        match (old_node, node) {
+            (_, Node::Group { .. }) => {
+                let Some(gtk_box) = widget.downcast_ref::<gtk::Box>() else { return false };
+                self.update_box_children(gtk_box, node.children(), difference);
+            },
+            (_, Node::GeometryReader {}) => self.update_geometry_reader(),
+            (_, Node::HStack { spacing, alignment, wrapped }) => {
+                let Some(gtk_box) = widget.downcast_ref::<gtk::Box>() else { return false };
+                gtk_box.set_spacing(*spacing as i32);
+                gtk_box.set_valign(alignment.to_gtk());
+                self.update_box_children(gtk_box, flattened_children(wrapped), difference);
+            },
+            (_, Node::VStack { spacing, alignment, wrapped }) => {
+                let Some(gtk_box) = widget.downcast_ref::<gtk::Box>() else { return false };
+                gtk_box.set_spacing(*spacing as i32);
+                gtk_box.set_halign(alignment.to_gtk());
+                self.update_box_children(gtk_box, flattened_children(wrapped), difference);
+            },
+            (_, Node::ZStack { alignment, spacing: _, wrapped }) => {
+                let Some(overlay) = widget.downcast_ref::<Overlay>() else { return false };
+                self.update_overlay_children(overlay, flattened_children(wrapped), *alignment, difference);
+            },
+            (_, Node::Grid { alignment, horizontal_spacing, vertical_spacing, wrapped }) => {
+                let Some(grid) = widget.downcast_ref::<gtk::Grid>() else { return false };
+                self.update_grid(grid, *alignment, *horizontal_spacing, *vertical_spacing, wrapped, difference);
+            },
+            (_, Node::GridRow { wrapped, .. }) => {
+                let Some(gtk_box) = widget.downcast_ref::<gtk::Box>() else { return false };
+                self.update_box_children(gtk_box, flattened_children(wrapped), difference);
+            },
+            (
+                Node::Overlay { wrapped: old_wrapped, overlayed: old_overlayed, .. },
+                Node::Overlay { wrapped, alignment, overlayed },
+            ) => {
+                if old_wrapped.id() != wrapped.id() || old_overlayed.id() != overlayed.id() {
+                    return false;
                 }
-                self.append(&gtk_box);
+                let (widgets, _) = self.reconcile_children(vec![
+                    (IdPathBuf::from(wrapped.id().clone()), wrapped.value()),
+                    (IdPathBuf::from(overlayed.id().clone()), overlayed.value()),
+                ], difference);
+                let [_, (overlayed_widget, _)] = &widgets[..] else { return false };
+                align(overlayed_widget, *alignment);
             },
-            Node::Modified { wrapped, modifier: _ } => {
-                // TODO: Implement modifiers
-                eprintln!("Warning: Modifiers are not supported yet and ignored");
-                self.append(&self.create_child_from_identified(wrapped));
+            _ => return false,
+        }
+        true
+    }
+
+    /// Patches the widget of a navigation view, toolbar or presentation.
+    /// Returns false if the widget has to be rebuilt instead.
+    fn patch_container(&self, widget: &gtk::Widget, old_node: &Node, node: &Node, difference: Option<&Difference<&Node>>) -> bool {
+        match (old_node, node) {
+            (Node::NavigationStack { wrapped: old_wrapped, .. }, Node::NavigationStack { path, wrapped }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                let Some(navigation_view) = widget.downcast_ref() else { return false };
+                self.patch_navigation_stack(navigation_view, path.as_deref(), wrapped, node, difference);
+            },
+            (
+                Node::NavigationSplitView { sidebar: old_sidebar, content: old_content, detail: old_detail },
+                Node::NavigationSplitView { sidebar, content, detail },
+            ) => {
+                let is_same_layout = old_sidebar.id() == sidebar.id()
+                    && old_content.id() == content.id()
+                    && old_detail.id() == detail.id()
+                    && matches!(old_content.value(), Node::Empty {}) == matches!(content.value(), Node::Empty {});
+                if !is_same_layout {
+                    return false;
+                }
+                let Some(split_view) = widget.downcast_ref() else { return false };
+                self.patch_navigation_split_view(split_view, sidebar, content, detail, node, difference);
             },
-            // TODO: Add remaining node types
+            (Node::Toolbar { wrapped: old_wrapped, .. }, Node::Toolbar { wrapped, items }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                self.update_toolbar(wrapped, items, difference);
+            },
+            (Node::Sheet { wrapped: old_wrapped, .. }, Node::Sheet { wrapped, is_presented, content }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                self.update_sheet(wrapped, *is_presented, content, difference);
+            },
+            (Node::Popover { wrapped: old_wrapped, .. }, Node::Popover { wrapped, is_presented, content }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                self.update_popover(wrapped, *is_presented, content, difference);
+            },
+            (Node::Alert { wrapped: old_wrapped, actions: old_actions, .. }, Node::Alert { wrapped, title, message, is_presented, actions })
+            | (Node::ConfirmationDialog { wrapped: old_wrapped, actions: old_actions, .. }, Node::ConfirmationDialog { wrapped, title, message, is_presented, actions }) => {
+                if old_wrapped.id() != wrapped.id() {
+                    return false;
+                }
+                // Responses cannot be changed once added, so we replace the
+                // dialog (closing it without dismissing) if the buttons differ
+                if action_buttons(old_actions) != action_buttons(actions) {
+                    self.remove_presentation();
+                }
+                self.update_alert(wrapped, title, message.as_deref(), *is_presented, actions, difference);
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    /// Patches the label of a button. Returns false if the button has to be
+    /// rebuilt instead.
+    fn patch_button(&self, button: &Button, old_label: &Identified<Node>, label: &Identified<Node>, difference: Option<&Difference<&Node>>) -> bool {
+        match (old_label.value(), label.value()) {
+            (Node::Text { .. }, Node::Text { content }) => button.set_label(content),
+            (Node::Text { .. }, _) | (_, Node::Text { .. }) => return false,
             _ => {
-                eprintln!("Warning: Unimplemented node type {node:?}");
+                if old_label.id() != label.id() {
+                    return false;
+                }
+                self.update_child(label, difference);
             },
         }
+        true
+    }
+
+    /// Resolves the view of the geometry reader rendered by this widget for
+    /// its current size.
+    fn update_geometry_reader(&self) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(overlay) = imp.widget.borrow().clone().and_downcast::<Overlay>() else { return };
+        let Some(area) = overlay.child() else { return };
+
+        let geometry = Geometry::new(Vec2::new(f64::from(area.width()), f64::from(area.height())));
+        let response = self.fire(IdPath::root(), &Event::GetGeometryReaderView { geometry });
+        let children = match &response {
+            EventResponse::Node { node } => vec![(IdPathBuf::from(node.id().clone()), node.value())],
+            EventResponse::Empty {} | EventResponse::Nodes { .. } | EventResponse::Error { .. } => Vec::new(),
+        };
+
+        let (widgets, removed) = self.reconcile_children(children, None);
+        for widget in removed {
+            overlay.remove_overlay(&widget);
+        }
+        for (widget, is_new) in widgets {
+            if is_new {
+                widget.set_halign(Align::Start);
+                widget.set_valign(Align::Start);
+                overlay.add_overlay(&widget);
+            }
+        }
     }
+
+    /// Updates the node widget for the given single child, reusing the
+    /// existing one if it is still present.
+    fn update_child(&self, child: &Identified<Node>, difference: Option<&Difference<&Node>>) -> Self {
+        let (mut widgets, _) = self.reconcile_children(vec![(IdPathBuf::from(child.id().clone()), child.value())], difference);
+        widgets.remove(0).0
+    }
+
+    /// Reconciles the node widgets for this widget's children with the given
+    /// children, keyed by their id paths relative to this widget. The widgets
+    /// of children whose id paths are still present are reused and, if a
+    /// difference is given, only updated if affected by it.
+    ///
+    /// Returns the widgets in order, along with whether they were newly
+    /// created, and the widgets that are no longer needed.
+    #[allow(clippy::type_complexity)]
+    fn reconcile_children(&self, children: Vec<(IdPathBuf, &Node)>, difference: Option<&Difference<&Node>>) -> (Vec<(Self, bool)>, Vec<Self>) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let mut old_children: HashMap<IdPathBuf, Self> = imp.children.take().into_iter().collect();
+        let mut new_children = Vec::new();
+        let mut widgets = Vec::new();
+
+        for (child_path, child) in children {
+            let (widget, is_new) = if let Some(widget) = old_children.remove(&child_path) {
+                let needs_update = difference.is_none_or(|difference| difference.affects(&child_path) || imp::NodeWidget::from_obj(&widget).has_dynamic_content.get());
+                if needs_update {
+                    widget.update(child.clone());
+                }
+                (widget, false)
+            } else {
+                (self.create_child_with_path(child.clone(), &child_path), true)
+            };
+            new_children.push((child_path, widget.clone()));
+            widgets.push((widget, is_new));
+        }
+
+        imp.children.replace(new_children);
+        (widgets, old_children.into_values().collect())
+    }
+
+    /// Updates the node widgets in the given box to match the given children.
+    fn update_box_children(&self, gtk_box: &gtk::Box, children: Vec<(IdPathBuf, &Node)>, difference: Option<&Difference<&Node>>) {
+        let (widgets, removed) = self.reconcile_children(children, difference);
+
+        for widget in removed {
+            gtk_box.remove(&widget);
+        }
+
+        let mut previous: Option<Self> = None;
+        for (widget, is_new) in widgets {
+            if is_new {
+                gtk_box.insert_child_after(&widget, previous.as_ref());
+            } else {
+                gtk_box.reorder_child_after(&widget, previous.as_ref());
+            }
+            previous = Some(widget);
+        }
+    }
+
+    /// Updates the node widgets stacked in the given overlay to match the
+    /// given children.
+    fn update_overlay_children(&self, overlay: &Overlay, children: Vec<(IdPathBuf, &Node)>, alignment: Alignment, difference: Option<&Difference<&Node>>) {
+        let (widgets, removed) = self.reconcile_children(children, difference);
+
+        for widget in removed {
+            overlay.remove_overlay(&widget);
+        }
+
+        // Overlays cannot be reordered, so we only append new widgets if the
+        // existing ones are still in order and re-add all of them otherwise.
+        let widgets: Vec<gtk::Widget> = widgets.into_iter().map(|(widget, _)| widget.upcast()).collect();
+        let mut existing: Vec<gtk::Widget> = iter::successors(overlay.first_child(), WidgetExt::next_sibling).collect();
+        if !widgets.starts_with(&existing) {
+            for widget in existing.drain(..) {
+                overlay.remove_overlay(&widget);
+            }
+        }
+
+        for widget in &widgets[existing.len()..] {
+            overlay.add_overlay(widget);
+            overlay.set_measure_overlay(widget, true);
+        }
+        for widget in &widgets {
+            align(widget, alignment);
+        }
+    }
+}
+
+/// The (flattened) children of the given wrapped node, keyed by their id paths
+/// relative to the parent of the wrapped node.
+fn flattened_children(wrapped: &Identified<Node>) -> Vec<(IdPathBuf, &Node)> {
+    wrapped.value().children_from(&IdPathBuf::from(wrapped.id().clone()))
+}
+
+/// Whether the given node contains views that are resolved through events
+/// and may thus change without showing up in the difference.
+fn has_dynamic_content(node: &Node) -> bool {
+    node.descendants().into_iter().any(|(_, node)| matches!(
+        node,
+        Node::GeometryReader {}
+        | Node::LazyVStack { .. }
+        | Node::LazyHStack { .. }
+        | Node::LazyList { .. }
+        | Node::LazyVGrid { .. }
+        | Node::LazyHGrid { .. }
+        | Node::NavigationStack { .. }
+        | Node::NavigationSplitView { .. }
+    ))
+}
+
+/// The ids and labels of the options of a picker. The labels are the first
+/// texts in the options.
+fn picker_options(content: &Identified<Node>) -> Vec<(Id, String)> {
+    flattened_children(content)
+        .into_iter()
+        .filter_map(|(path, option)| {
+            let id = path.last()?;
+            let label = first_text(option).unwrap_or_else(|| id.to_string());
+            Some((id, label))
+        })
+        .collect()
+}
+
+/// The content of the first text among the given node and its descendants.
+fn first_text(node: &Node) -> Option<String> {
+    node.descendants()
+        .into_iter()
+        .find_map(|(_, node)| match node {
+            Node::Text { content } => Some(content.clone()),
+            _ => None,
+        })
+}
+
+/// The position of the given selection among the given picker options.
+fn picker_position(options: &[(Id, String)], selection: &Id) -> u32 {
+    options.iter()
+        .position(|(id, _)| id == selection)
+        .and_then(|i| u32::try_from(i).ok())
+        .unwrap_or(gtk::INVALID_LIST_POSITION)
+}
+
+/// Updates the drop down of a picker. Returns false if the widget has to be
+/// rebuilt instead.
+fn patch_picker(widget: &gtk::Widget, title: &str, selection: &Id, content: &Identified<Node>) -> bool {
+    let Some(drop_down) = patch_titled::<DropDown>(widget, title) else { return false };
+    let Some(model) = drop_down.model().and_downcast::<StringList>() else { return false };
+
+    let options = picker_options(content);
+    let labels: Vec<&str> = options.iter().map(|(_, label)| label.as_str()).collect();
+    let old_labels: Vec<_> = (0..model.n_items()).filter_map(|i| model.string(i)).collect();
+    if old_labels.iter().map(glib::GString::as_str).ne(labels.iter().copied()) {
+        model.splice(0, model.n_items(), &labels);
+    }
+
+    let position = picker_position(&options, selection);
+    if drop_down.selected() != position {
+        drop_down.set_selected(position);
+    }
+    true
+}
+
+/// Aligns the given widget within its parent.
+fn align(widget: &impl IsA<gtk::Widget>, alignment: Alignment) {
+    let (halign, valign) = alignment.to_gtk();
+    widget.set_halign(halign);
+    widget.set_valign(valign);
+}
+
+/// Aligns the given GTK widget within the (possibly larger) node widget
+/// containing it.
+fn align_content(widget: &gtk::Widget, (halign, valign): (Align, Align)) {
+    // Expanding makes the widget fill the container. This doesn't propagate
+    // further up, since the frame modifier sets the container's expansion.
+    widget.set_hexpand(true);
+    widget.set_vexpand(true);
+    widget.set_halign(halign);
+    widget.set_valign(valign);
 }
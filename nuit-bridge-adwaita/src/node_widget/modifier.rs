@@ -0,0 +1,335 @@
+use std::{fmt::Write, iter};
+
+use adw::{glib, gtk::{self, accessible, AccessibleRole, Align, Button, CallbackAction, CssProvider, DirectionType, KeyvalTrigger, Overflow, Shortcut, ShortcutController, ShortcutScope, StateFlags}, prelude::*};
+use nuit_core::{AccessibilityRole, Alignment, Font, FontSize, Frame, KeyboardShortcut, ModifierNode};
+
+use crate::{convert::ToGtk, css::{css_string, ToCss}};
+
+use super::{image::wrapped_picture, NodeWidget};
+
+/// The name of the event controllers handling keyboard shortcuts.
+const SHORTCUT_CONTROLLER_NAME: &str = "nuit-keyboard-shortcut";
+
+/// The Adwaita style classes that fonts are mapped to.
+const FONT_CLASSES: [&str; 7] = ["title-1", "title-2", "title-3", "title-4", "heading", "body", "caption"];
+
+/// Applies the given modifier to the given (wrapped) node widget, using the
+/// given CSS provider (which should be attached to the widget) for styling.
+/// The previously applied modifier of the same kind, if any, is used to only
+/// act on changes where applying has side effects.
+///
+/// The modifiers map to GTK as follows:
+///
+/// - `Padding` sets margins
+/// - `Frame` sets a size request and the alignment of the content. Minimum (or
+///   else ideal) sizes are used as size requests, maximum sizes are only
+///   honored if they are infinite, in which case the widget is expanded.
+/// - `Opacity`, `Help`, `Hidden` and `Disabled` map to the corresponding
+///   widget properties. Note that hidden widgets, unlike in `SwiftUI`, don't
+///   take up any space.
+/// - `Font` uses Adwaita's typography classes for font levels and CSS for
+///   everything else. The rounded design falls back to the default font.
+/// - `ForegroundStyle`, `Background`, `Border`, `CornerRadius` and `Shadow`
+///   are rendered via CSS. Materials are approximated with translucent
+///   backgrounds and the safe area edges of backgrounds are ignored.
+/// - `Offset`, `ScaleEffect` and `RotationEffect` use CSS transforms.
+/// - `Blur`, `Grayscale`, `Brightness`, `Contrast`, `Saturation` and
+///   `HueRotation` use CSS filters.
+/// - `Clipped` (and `CornerRadius`) hide overflowing content.
+/// - `KeyboardShortcut` adds a shortcut controller clicking the first button
+///   in the widget, as long as it is shown and enabled.
+/// - `Resizable` expands the widget and lets a wrapped picture shrink and grow
+///   with it. Symbols keep their icon size.
+/// - `AspectRatio` makes a wrapped picture fit (contain) or fill (cover) its
+///   bounds. Pictures always keep their natural ratio, so explicit ratios are
+///   ignored.
+/// - `Focused` moves the focus to the first focusable widget within the widget
+///   or removes it from the window, but only when the value changes (the
+///   focus may have moved elsewhere in the meantime). Focus changes are
+///   reported by the text fields within the widget.
+///
+/// - `AccessibilityLabel`, `AccessibilityHint` (as the description),
+///   `AccessibilityValue`, `AccessibilityHeading` (as the level) and
+///   `AccessibilityHidden` set the corresponding accessible properties and
+///   states of the rendered widget. `AccessibilityRole` and
+///   `AccessibilityHeading` set its accessible role, which GTK only lets us do
+///   before the widget is realized, so later role changes are ignored.
+///
+/// - `NavigationTitle` is read by the enclosing navigation views, which use it
+///   as the title of the page.
+/// - `GridCellColumns` and `GridColumnAlignment` are read by the enclosing
+///   grid, which uses them to place and align the cells.
+///
+/// `Position`, `Fill`, `Overlay`, `ZIndex`, `NavigationSubtitle`,
+/// `NavigationTitleDisplayMode`, `Interpolation` and `AccessibilityAction`
+/// are not supported yet and ignored. GTK has no notion of custom accessible
+/// actions.
+#[allow(clippy::cast_possible_truncation, deprecated)]
+pub fn apply_modifier(widget: &NodeWidget, previous: Option<&ModifierNode>, modifier: &ModifierNode, css_provider: &CssProvider) {
+    let mut css = String::new();
+
+    match modifier {
+        ModifierNode::Padding { insets } => {
+            widget.set_margin_top(insets.top as i32);
+            widget.set_margin_start(insets.leading as i32);
+            widget.set_margin_bottom(insets.bottom as i32);
+            widget.set_margin_end(insets.trailing as i32);
+        },
+        ModifierNode::Opacity { opacity } => widget.set_opacity(*opacity),
+        ModifierNode::Frame { frame, alignment } => apply_frame(widget, *frame, *alignment),
+        ModifierNode::Font { font } => apply_font(widget, font, &mut css),
+        ModifierNode::Offset { .. }
+        | ModifierNode::ForegroundStyle { .. }
+        | ModifierNode::Background { .. }
+        | ModifierNode::ScaleEffect { .. }
+        | ModifierNode::RotationEffect { .. }
+        | ModifierNode::Border { .. }
+        | ModifierNode::Shadow { .. }
+        | ModifierNode::Blur { .. }
+        | ModifierNode::Grayscale { .. }
+        | ModifierNode::Brightness { .. }
+        | ModifierNode::Contrast { .. }
+        | ModifierNode::Saturation { .. }
+        | ModifierNode::HueRotation { .. } => write_css(&mut css, modifier),
+        ModifierNode::Help { text } => widget.set_tooltip_text(Some(text)),
+        ModifierNode::CornerRadius { radius } => {
+            let _ = write!(css, "border-radius: {radius}px;");
+            widget.set_overflow(Overflow::Hidden);
+        },
+        ModifierNode::Hidden { is_hidden } => widget.set_visible(!is_hidden),
+        ModifierNode::Disabled { is_disabled } => widget.set_sensitive(!is_disabled),
+        ModifierNode::Clipped {} => widget.set_overflow(Overflow::Hidden),
+        ModifierNode::KeyboardShortcut { shortcut } => set_keyboard_shortcut(widget, *shortcut),
+        ModifierNode::Focused { is_focused } => {
+            // Newly built widgets are unfocused
+            let was_focused = matches!(previous, Some(ModifierNode::Focused { is_focused: true }));
+            if *is_focused != was_focused {
+                set_focused(widget, *is_focused);
+            }
+        },
+        ModifierNode::Resizable {} => {
+            widget.set_hexpand(true);
+            widget.set_vexpand(true);
+            widget.set_halign(Align::Fill);
+            widget.set_valign(Align::Fill);
+            if let Some(picture) = wrapped_picture(widget) {
+                picture.set_can_shrink(true);
+                picture.set_hexpand(true);
+                picture.set_vexpand(true);
+            }
+        },
+        ModifierNode::AspectRatio { content_mode, .. } => {
+            if let Some(picture) = wrapped_picture(widget) {
+                picture.set_content_fit(content_mode.to_gtk());
+            }
+        },
+        ModifierNode::AccessibilityLabel { .. }
+        | ModifierNode::AccessibilityHint { .. }
+        | ModifierNode::AccessibilityValue { .. }
+        | ModifierNode::AccessibilityRole { .. }
+        | ModifierNode::AccessibilityHeading { .. }
+        | ModifierNode::AccessibilityHidden { .. } => apply_accessibility(widget, modifier),
+        ModifierNode::NavigationTitle { .. }
+        | ModifierNode::GridCellColumns { .. }
+        | ModifierNode::GridColumnAlignment { .. } => {},
+        ModifierNode::Position { .. }
+        | ModifierNode::Fill { .. }
+        | ModifierNode::Overlay { .. }
+        | ModifierNode::ZIndex { .. }
+        | ModifierNode::NavigationSubtitle { .. }
+        | ModifierNode::NavigationTitleDisplayMode { .. }
+        | ModifierNode::Interpolation { .. }
+        | ModifierNode::AccessibilityAction { .. } => {
+            // TODO: Implement remaining modifiers
+            eprintln!("Warning: Unsupported modifier {modifier:?} is ignored");
+        },
+    }
+
+    css_provider.load_from_data(&format!("* {{ {css} }}"));
+}
+
+/// Appends the CSS declarations for the given modifier, if it is rendered
+/// purely via CSS.
+fn write_css(css: &mut String, modifier: &ModifierNode) {
+    let _ = match modifier {
+        ModifierNode::Offset { delta } => write!(css, "transform: translate({}px, {}px);", delta.x, delta.y),
+        ModifierNode::ForegroundStyle { style } => write!(css, "color: {};", style.to_css()),
+        ModifierNode::Background { style, safe_area_ignoring_edges: _ } => write!(css, "background-color: {};", style.to_css()),
+        ModifierNode::ScaleEffect { factor, anchor } => {
+            let anchor = anchor.value();
+            write!(css, "transform: scale({factor}); transform-origin: {}% {}%;", anchor.x * 100.0, anchor.y * 100.0)
+        },
+        ModifierNode::RotationEffect { angle, anchor } => {
+            let anchor = anchor.value();
+            write!(css, "transform: rotate({}deg); transform-origin: {}% {}%;", angle.degrees(), anchor.x * 100.0, anchor.y * 100.0)
+        },
+        ModifierNode::Border { style, width } => write!(css, "border: {width}px solid {};", style.to_css()),
+        ModifierNode::Shadow { color, radius, offset } => {
+            write!(css, "box-shadow: {}px {}px {radius}px {};", offset.x, offset.y, color.to_css())
+        },
+        ModifierNode::Blur { radius } => write!(css, "filter: blur({radius}px);"),
+        ModifierNode::Grayscale { intensity } => write!(css, "filter: grayscale({intensity});"),
+        ModifierNode::Brightness { amount } => write!(css, "filter: brightness({});", 1.0 + amount),
+        ModifierNode::Contrast { amount } => write!(css, "filter: contrast({amount});"),
+        ModifierNode::Saturation { amount } => write!(css, "filter: saturate({amount});"),
+        ModifierNode::HueRotation { angle } => write!(css, "filter: hue-rotate({}deg);", angle.degrees()),
+        _ => Ok(()),
+    };
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn apply_frame(widget: &NodeWidget, frame: Frame, alignment: Alignment) {
+    let (width, height, hexpand, vexpand) = match frame {
+        Frame::Constrained { min_width, ideal_width, max_width, min_height, ideal_height, max_height } => (
+            min_width.or(ideal_width),
+            min_height.or(ideal_height),
+            max_width.is_some_and(f64::is_infinite),
+            max_height.is_some_and(f64::is_infinite),
+        ),
+        Frame::Exact { width, height } => (width, height, false, false),
+    };
+    widget.set_size_request(
+        width.map_or(-1, |w| w as i32),
+        height.map_or(-1, |h| h as i32),
+    );
+    widget.set_hexpand(hexpand);
+    widget.set_halign(if hexpand { Align::Fill } else { Align::Center });
+    widget.set_vexpand(vexpand);
+    widget.set_valign(if vexpand { Align::Fill } else { Align::Center });
+    widget.set_content_alignment(Some(alignment.to_gtk()));
+}
+
+fn apply_font(widget: &NodeWidget, font: &Font, css: &mut String) {
+    for class in FONT_CLASSES {
+        widget.remove_css_class(class);
+    }
+    match font {
+        Font::System { size, design, weight } => {
+            match size {
+                FontSize::Level { level } => widget.add_css_class(level.to_gtk()),
+                FontSize::Custom { size } => { let _ = write!(css, "font-size: {size}px;"); },
+            }
+            if let Some(design) = design {
+                let _ = write!(css, "font-family: {};", design.to_css());
+            }
+            if let Some(weight) = weight {
+                let _ = write!(css, "font-weight: {};", weight.to_css());
+            }
+        },
+        Font::Custom { name, size } => {
+            let _ = write!(css, "font-family: {}; font-size: {size}px;", css_string(name));
+        },
+    }
+}
+
+fn apply_accessibility(widget: &NodeWidget, modifier: &ModifierNode) {
+    match modifier {
+        ModifierNode::AccessibilityLabel { label } => {
+            accessible_widget(widget).update_property(&[accessible::Property::Label(label)]);
+        },
+        ModifierNode::AccessibilityHint { hint } => {
+            accessible_widget(widget).update_property(&[accessible::Property::Description(hint)]);
+        },
+        ModifierNode::AccessibilityValue { value } => {
+            accessible_widget(widget).update_property(&[accessible::Property::ValueText(value)]);
+        },
+        ModifierNode::AccessibilityRole { role } => set_accessible_role(widget, role.to_gtk()),
+        ModifierNode::AccessibilityHeading { level } => {
+            set_accessible_role(widget, AccessibilityRole::Heading.to_gtk());
+            if let Some(number) = level.number() {
+                accessible_widget(widget).update_property(&[accessible::Property::Level(i32::from(number))]);
+            }
+        },
+        ModifierNode::AccessibilityHidden { is_hidden } => {
+            accessible_widget(widget).update_state(&[accessible::State::Hidden(*is_hidden)]);
+        },
+        _ => {},
+    }
+}
+
+/// The widget rendered by the given node widget, looking through the node
+/// widgets of modifiers wrapping it, which is what assistive technologies
+/// present.
+fn accessible_widget(widget: &NodeWidget) -> gtk::Widget {
+    iter::successors(Some(widget.clone().upcast::<gtk::Widget>()), |widget| {
+        if widget.is::<NodeWidget>() { widget.first_child() } else { None }
+    })
+    .last()
+    .unwrap_or_else(|| widget.clone().upcast())
+}
+
+fn set_accessible_role(widget: &NodeWidget, role: AccessibleRole) {
+    let widget = accessible_widget(widget);
+    if widget.accessible_role() == role {
+        return;
+    }
+    if widget.is_realized() {
+        eprintln!("Warning: The accessible role of a realized widget cannot be changed to {role:?}");
+    } else {
+        widget.set_accessible_role(role);
+    }
+}
+
+/// Moves the focus into or out of the given widget, unless it already is
+/// (or isn't) focused.
+fn set_focused(widget: &NodeWidget, is_focused: bool) {
+    // Focus can only be moved within a window, which the widget may not be
+    // part of yet
+    let weak_widget = widget.downgrade();
+    glib::idle_add_local_once(move || {
+        let Some(widget) = weak_widget.upgrade() else { return };
+        let has_focus = widget.state_flags().contains(StateFlags::FOCUS_WITHIN);
+        if is_focused && !has_focus {
+            widget.child_focus(DirectionType::TabForward);
+        } else if !is_focused && has_focus {
+            if let Some(root) = widget.root() {
+                root.set_focus(None::<&gtk::Widget>);
+            }
+        }
+    });
+}
+
+/// Replaces the keyboard shortcut handled by the given widget.
+fn set_keyboard_shortcut(widget: &NodeWidget, shortcut: KeyboardShortcut) {
+    let old_controllers: Vec<_> = widget.observe_controllers()
+        .iter::<gtk::EventController>()
+        .filter_map(Result::ok)
+        .filter(|controller| controller.name().as_deref() == Some(SHORTCUT_CONTROLLER_NAME))
+        .collect();
+    for controller in old_controllers {
+        widget.remove_controller(&controller);
+    }
+
+    let action = CallbackAction::new(|widget, _args| {
+        match first_button(widget) {
+            Some(button) if button.is_mapped() && button.is_sensitive() => {
+                button.emit_clicked();
+                glib::Propagation::Stop
+            },
+            _ => glib::Propagation::Proceed,
+        }
+    });
+    let trigger = KeyvalTrigger::new(shortcut.key().to_gtk(), shortcut.modifiers().to_gtk());
+    let controller = ShortcutController::new();
+    controller.set_name(Some(SHORTCUT_CONTROLLER_NAME));
+    // Like in SwiftUI, shortcuts work anywhere in the window
+    controller.set_scope(ShortcutScope::Global);
+    controller.add_shortcut(Shortcut::new(Some(trigger), Some(action)));
+    widget.add_controller(controller);
+}
+
+/// The first button in the given widget (including itself), in pre-order.
+fn first_button(widget: &gtk::Widget) -> Option<Button> {
+    if let Some(button) = widget.downcast_ref::<Button>() {
+        return Some(button.clone());
+    }
+    iter::successors(widget.first_child(), WidgetExt::next_sibling).find_map(|child| first_button(&child))
+}
+
+/// Creates a CSS provider for styling the given widget.
+#[allow(deprecated)]
+pub fn attach_css_provider(widget: &impl IsA<gtk::Widget>) -> CssProvider {
+    let css_provider = CssProvider::new();
+    widget.style_context().add_provider(&css_provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
+    css_provider
+}
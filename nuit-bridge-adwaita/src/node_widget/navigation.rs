@@ -0,0 +1,264 @@
+use adw::{prelude::*, subclass::prelude::*, NavigationPage, NavigationSplitView, NavigationView};
+use nuit_core::{Difference, Event, EventResponse, IdPath, IdPathBuf, Identified, ModifierNode, Node};
+use serde_json::Value;
+
+use super::{imp, NodeWidget};
+
+impl NodeWidget {
+    /// Creates a navigation view for a navigation stack with the given
+    /// (optional) path binding and root view.
+    pub(super) fn build_navigation_stack(&self, path: Option<&[Value]>, wrapped: &Identified<Node>, node: &Node) -> NavigationView {
+        let navigation_view = NavigationView::new();
+        let root = self.update_child(wrapped, None);
+        navigation_view.push(&NavigationPage::new(&root, &navigation_title(wrapped.value())));
+
+        let weak_self = self.downgrade();
+        navigation_view.connect_popped(move |_navigation_view, page| {
+            if let Some(widget) = weak_self.upgrade() {
+                widget.navigation_page_popped(page);
+            }
+        });
+
+        self.sync_navigation_pages(&navigation_view, path.unwrap_or_default(), node);
+        navigation_view
+    }
+
+    pub(super) fn patch_navigation_stack(&self, navigation_view: &NavigationView, path: Option<&[Value]>, wrapped: &Identified<Node>, node: &Node, difference: Option<&Difference<&Node>>) {
+        self.update_child(wrapped, difference);
+        if let Some(root_page) = navigation_view.navigation_stack().item(0).and_downcast::<NavigationPage>() {
+            root_page.set_title(&navigation_title(wrapped.value()));
+        }
+
+        // Stacks without a path binding keep track of their path themselves
+        let path = path.map_or_else(
+            || imp::NodeWidget::from_obj(self).pages.borrow().iter().map(|(value, _)| value.clone()).collect(),
+            <[Value]>::to_vec,
+        );
+        self.sync_navigation_pages(navigation_view, &path, node);
+    }
+
+    /// Pushes and pops pages to match the given path, updating the pages that
+    /// are kept.
+    fn sync_navigation_pages(&self, navigation_view: &NavigationView, path: &[Value], node: &Node) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let mut pages = imp.pages.take();
+
+        let kept = pages.iter()
+            .zip(path)
+            .take_while(|((page_value, _), value)| page_value == *value)
+            .count();
+
+        if kept < pages.len() {
+            let last_kept = match kept {
+                0 => navigation_view.navigation_stack().item(0).and_downcast::<NavigationPage>(),
+                _ => Some(pages[kept - 1].1.clone()),
+            };
+            if let Some(last_kept) = last_kept {
+                navigation_view.pop_to_page(&last_kept);
+            }
+            pages.truncate(kept);
+        }
+
+        for (value, page) in &pages {
+            self.update_navigation_page(page, value, node);
+        }
+
+        for value in &path[kept..] {
+            let Some(page) = self.resolve_navigation_page(value, node) else { break };
+            navigation_view.push(&page);
+            pages.push((value.clone(), page));
+        }
+
+        imp.pages.replace(pages);
+    }
+
+    /// Creates a navigation view for a navigation split view with the given
+    /// columns. The content column is omitted if empty.
+    pub(super) fn build_navigation_split_view(&self, sidebar: &Identified<Node>, content: &Identified<Node>, detail: &Identified<Node>) -> NavigationSplitView {
+        let split_view = NavigationSplitView::new();
+
+        if matches!(content.value(), Node::Empty {}) {
+            let (widgets, _) = self.reconcile_children(vec![
+                (IdPathBuf::from(sidebar.id().clone()), sidebar.value()),
+                (IdPathBuf::from(detail.id().clone()), detail.value()),
+            ], None);
+            let [(sidebar_widget, _), (detail_widget, _)] = &widgets[..] else { return split_view };
+            split_view.set_sidebar(Some(&NavigationPage::new(sidebar_widget, &navigation_title(sidebar.value()))));
+            split_view.set_content(Some(&NavigationPage::new(detail_widget, &navigation_title(detail.value()))));
+        } else {
+            // Adwaita split views only have two columns, so we nest them
+            let (widgets, _) = self.reconcile_children(vec![
+                (IdPathBuf::from(sidebar.id().clone()), sidebar.value()),
+                (IdPathBuf::from(content.id().clone()), content.value()),
+                (IdPathBuf::from(detail.id().clone()), detail.value()),
+            ], None);
+            let [(sidebar_widget, _), (content_widget, _), (detail_widget, _)] = &widgets[..] else { return split_view };
+            let inner_split_view = NavigationSplitView::new();
+            inner_split_view.set_sidebar(Some(&NavigationPage::new(content_widget, &navigation_title(content.value()))));
+            inner_split_view.set_content(Some(&NavigationPage::new(detail_widget, &navigation_title(detail.value()))));
+            split_view.set_sidebar(Some(&NavigationPage::new(sidebar_widget, &navigation_title(sidebar.value()))));
+            split_view.set_content(Some(&NavigationPage::new(&inner_split_view, "")));
+        }
+
+        split_view
+    }
+
+    pub(super) fn patch_navigation_split_view(&self, split_view: &NavigationSplitView, sidebar: &Identified<Node>, content: &Identified<Node>, detail: &Identified<Node>, node: &Node, difference: Option<&Difference<&Node>>) {
+        let mut columns = vec![(IdPathBuf::from(sidebar.id().clone()), sidebar.value())];
+        if !matches!(content.value(), Node::Empty {}) {
+            columns.push((IdPathBuf::from(content.id().clone()), content.value()));
+        }
+        columns.push((IdPathBuf::from(detail.id().clone()), detail.value()));
+        self.reconcile_children(columns, difference);
+
+        let detail_split_view = detail_split_view(split_view);
+        if let Some(sidebar_page) = split_view.sidebar() {
+            sidebar_page.set_title(&navigation_title(sidebar.value()));
+        }
+        if let (false, Some(content_page)) = (matches!(content.value(), Node::Empty {}), detail_split_view.sidebar()) {
+            content_page.set_title(&navigation_title(content.value()));
+        }
+
+        let shown_page = imp::NodeWidget::from_obj(self).pages.borrow().first().cloned();
+        if let Some((value, page)) = shown_page {
+            self.update_navigation_page(&page, &value, node);
+        } else if let Some(detail_page) = detail_split_view.content() {
+            detail_page.set_title(&navigation_title(detail.value()));
+        }
+    }
+
+    /// Follows the navigation link rendered by this widget, i.e. pushes its
+    /// value onto the nearest enclosing navigation stack or shows it in the
+    /// detail column of the nearest enclosing navigation split view.
+    pub(super) fn follow_navigation_link(&self) {
+        let value = match &*imp::NodeWidget::from_obj(self).node.borrow() {
+            Node::NavigationLink { value, .. } => value.clone(),
+            _ => return,
+        };
+
+        let mut ancestor = self.parent();
+        while let Some(widget) = ancestor {
+            if let Some(node_widget) = widget.downcast_ref::<Self>() {
+                let (is_stack, is_split_view) = {
+                    let node = imp::NodeWidget::from_obj(node_widget).node.borrow();
+                    (matches!(*node, Node::NavigationStack { .. }), matches!(*node, Node::NavigationSplitView { .. }))
+                };
+                if is_stack {
+                    node_widget.push_navigation_value(value);
+                    return;
+                }
+                if is_split_view {
+                    node_widget.show_navigation_value(value);
+                    return;
+                }
+            }
+            ancestor = widget.parent();
+        }
+
+        eprintln!("Warning: Navigation link is not inside a navigation stack or split view");
+    }
+
+    /// Pushes the given value onto the navigation stack rendered by this widget.
+    fn push_navigation_value(&self, value: Value) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let node = imp.node.borrow().clone();
+        let Node::NavigationStack { path, .. } = &node else { return };
+
+        if let Some(path) = path {
+            // The page is pushed once the view is re-rendered with the new path
+            let mut path = path.clone();
+            path.push(value);
+            self.fire(IdPath::root(), &Event::UpdateNavigationPath { path });
+        } else {
+            let Some(navigation_view) = imp.widget.borrow().clone().and_downcast::<NavigationView>() else { return };
+            let Some(page) = self.resolve_navigation_page(&value, &node) else { return };
+            navigation_view.push(&page);
+            imp.pages.borrow_mut().push((value, page));
+        }
+    }
+
+    /// Shows the given value in the detail column of the navigation split view
+    /// rendered by this widget.
+    fn show_navigation_value(&self, value: Value) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let node = imp.node.borrow().clone();
+        let Some(split_view) = imp.widget.borrow().clone().and_downcast::<NavigationSplitView>() else { return };
+        let Some(page) = self.resolve_navigation_page(&value, &node) else { return };
+
+        detail_split_view(&split_view).set_content(Some(&page));
+        split_view.set_show_content(true);
+        imp.pages.replace(vec![(value, page)]);
+    }
+
+    /// Handles the user navigating back from the given page.
+    fn navigation_page_popped(&self, page: &NavigationPage) {
+        let imp = imp::NodeWidget::from_obj(self);
+        if imp.is_updating.get() {
+            return;
+        }
+
+        let path: Vec<Value> = {
+            let mut pages = imp.pages.borrow_mut();
+            let Some(index) = pages.iter().position(|(_, p)| p == page) else { return };
+            pages.truncate(index);
+            pages.iter().map(|(value, _)| value.clone()).collect()
+        };
+
+        let has_path_binding = matches!(*imp.node.borrow(), Node::NavigationStack { path: Some(_), .. });
+        if has_path_binding {
+            self.fire(IdPath::root(), &Event::UpdateNavigationPath { path });
+        }
+    }
+
+    /// Resolves the destination view for the given value through the first
+    /// navigation destination in the given node (rendered by this widget),
+    /// returning its id path relative to this widget.
+    fn resolve_navigation_destination(&self, value: &Value, node: &Node) -> Option<(IdPathBuf, Node)> {
+        let destination_path = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::NavigationDestination { .. }))
+            .map(|(path, _)| path)?;
+        match self.fire(&destination_path, &Event::GetNavigationDestination { value: value.clone() }) {
+            EventResponse::Node { node: destination } => Some((
+                destination_path.child(destination.id().clone()),
+                destination.value().clone(),
+            )),
+            EventResponse::Empty {} | EventResponse::Nodes { .. } | EventResponse::Error { .. } => None,
+        }
+    }
+
+    fn resolve_navigation_page(&self, value: &Value, node: &Node) -> Option<NavigationPage> {
+        let (path, destination) = self.resolve_navigation_destination(value, node)?;
+        let widget = self.create_child_with_path(destination, &path);
+        let title = navigation_title(&imp::NodeWidget::from_obj(&widget).node.borrow());
+        Some(NavigationPage::new(&widget, &title))
+    }
+
+    fn update_navigation_page(&self, page: &NavigationPage, value: &Value, node: &Node) {
+        let Some((_, destination)) = self.resolve_navigation_destination(value, node) else { return };
+        page.set_title(&navigation_title(&destination));
+        if let Some(widget) = page.child().and_downcast::<Self>() {
+            widget.update(destination);
+        }
+    }
+}
+
+/// The split view containing the detail column of the given split view, which
+/// is a nested one for three-column layouts.
+fn detail_split_view(split_view: &NavigationSplitView) -> NavigationSplitView {
+    split_view.content()
+        .and_then(|page| page.child())
+        .and_downcast::<NavigationSplitView>()
+        .unwrap_or_else(|| split_view.clone())
+}
+
+/// The navigation title set on the given node, if any.
+fn navigation_title(node: &Node) -> String {
+    node.descendants()
+        .into_iter()
+        .find_map(|(_, node)| match node {
+            Node::Modified { modifier: ModifierNode::NavigationTitle { title }, .. } => Some(title.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,235 @@
+use adw::{glib, gtk::{self, Popover}, prelude::*, subclass::prelude::*, AlertDialog, Dialog, ResponseAppearance};
+use nuit_core::{ButtonRole, Difference, Event, IdPathBuf, Identified, Node};
+
+use super::{first_text, flattened_children, imp, NodeWidget};
+
+/// The response of alerts without actions, which is also emitted when an
+/// alert is closed without tapping an action.
+const CLOSE_RESPONSE: &str = "close";
+
+impl NodeWidget {
+    /// Updates the node widgets for the wrapped view and the content of a
+    /// sheet, returning the former. The content is shown in a dialog while
+    /// the sheet is presented.
+    pub(super) fn update_sheet(&self, wrapped: &Identified<Node>, is_presented: bool, content: &Identified<Node>, difference: Option<&Difference<&Node>>) -> Self {
+        let imp = imp::NodeWidget::from_obj(self);
+        let (wrapped_widget, content_widget) = self.update_presented_children(wrapped, content, difference);
+
+        let existing = imp.presentation.borrow().clone().and_downcast::<Dialog>();
+        let dialog = existing.unwrap_or_else(|| {
+            let dialog = Dialog::new();
+            dialog.set_follows_content_size(true);
+            let weak_self = self.downgrade();
+            dialog.connect_closed(move |dialog| {
+                if let Some(widget) = weak_self.upgrade() {
+                    widget.presentation_closed(dialog);
+                }
+            });
+            imp.presentation.replace(Some(dialog.clone().upcast()));
+            dialog
+        });
+
+        if content_widget.parent().is_none() {
+            dialog.set_child(Some(&content_widget));
+        }
+        self.sync_dialog(&dialog, is_presented);
+
+        wrapped_widget
+    }
+
+    /// Updates the node widgets for the wrapped view and the content of a
+    /// popover, returning the former. The popover is attached to this widget.
+    pub(super) fn update_popover(&self, wrapped: &Identified<Node>, is_presented: bool, content: &Identified<Node>, difference: Option<&Difference<&Node>>) -> Self {
+        let imp = imp::NodeWidget::from_obj(self);
+        let (wrapped_widget, content_widget) = self.update_presented_children(wrapped, content, difference);
+
+        let existing = imp.presentation.borrow().clone().and_downcast::<Popover>();
+        let popover = existing.unwrap_or_else(|| {
+            let popover = Popover::new();
+            // Popovers are native widgets and thus not laid out by the box
+            popover.set_parent(self);
+            let weak_self = self.downgrade();
+            popover.connect_closed(move |popover| {
+                if let Some(widget) = weak_self.upgrade() {
+                    widget.presentation_closed(popover);
+                }
+            });
+            imp.presentation.replace(Some(popover.clone().upcast()));
+            popover
+        });
+
+        if content_widget.parent().is_none() {
+            popover.set_child(Some(&content_widget));
+        }
+
+        if is_presented && !popover.is_visible() {
+            // Popovers can only pop up once mapped, which this widget may not be yet
+            let weak_self = self.downgrade();
+            glib::idle_add_local_once(move || {
+                let Some(widget) = weak_self.upgrade() else { return };
+                if widget.is_presenting(&popover) && !popover.is_visible() {
+                    popover.popup();
+                }
+            });
+        } else if !is_presented && popover.is_visible() {
+            popover.popdown();
+        }
+
+        wrapped_widget
+    }
+
+    /// Updates the node widget for the wrapped view of an alert or
+    /// confirmation dialog and returns it. The actions are not rendered as
+    /// widgets, instead their buttons become the responses of an alert dialog.
+    pub(super) fn update_alert(&self, wrapped: &Identified<Node>, title: &str, message: Option<&str>, is_presented: bool, actions: &Identified<Node>, difference: Option<&Difference<&Node>>) -> Self {
+        let imp = imp::NodeWidget::from_obj(self);
+        let wrapped_widget = self.update_child(wrapped, difference);
+
+        let existing = imp.presentation.borrow().clone().and_downcast::<AlertDialog>();
+        match existing {
+            // Alerts with different buttons have already been removed, so
+            // we only update the text of alerts that are already shown
+            Some(dialog) if dialog.parent().is_some() => {
+                if is_presented {
+                    dialog.set_heading(Some(title));
+                    dialog.set_body(message.unwrap_or_default());
+                } else {
+                    dialog.force_close();
+                }
+            },
+            _ if is_presented => {
+                let dialog = self.build_alert_dialog(title, message, actions);
+                imp.presentation.replace(Some(dialog.clone().upcast()));
+                self.sync_dialog(dialog.upcast_ref(), true);
+            },
+            _ => {},
+        }
+
+        wrapped_widget
+    }
+
+    /// Creates an alert dialog with a response for each button among the
+    /// given actions. Choosing a response taps the corresponding button.
+    fn build_alert_dialog(&self, title: &str, message: Option<&str>, actions: &Identified<Node>) -> AlertDialog {
+        let dialog = AlertDialog::new(Some(title), message);
+        let buttons = action_buttons(actions);
+
+        for (i, (_, label, role)) in buttons.iter().enumerate() {
+            let response = i.to_string();
+            dialog.add_response(&response, label);
+            match role {
+                Some(ButtonRole::Destructive) => dialog.set_response_appearance(&response, ResponseAppearance::Destructive),
+                Some(ButtonRole::Cancel) => dialog.set_close_response(&response),
+                None => {},
+            }
+        }
+        if buttons.is_empty() {
+            dialog.add_response(CLOSE_RESPONSE, "OK");
+        }
+
+        let button_paths: Vec<IdPathBuf> = buttons.into_iter().map(|(path, _, _)| path).collect();
+        let weak_self = self.downgrade();
+        dialog.connect_response(None, move |dialog, response| {
+            let Some(widget) = weak_self.upgrade() else { return };
+            if !widget.is_presenting(dialog) {
+                return;
+            }
+            match response.parse::<usize>().ok().and_then(|i| button_paths.get(i)) {
+                Some(path) => widget.event_sink_at(path).fire(&Event::ButtonTap {}),
+                None => widget.event_sink().fire(&Event::Dismiss {}),
+            }
+        });
+
+        dialog
+    }
+
+    /// Updates the node widgets for the wrapped view and the presented
+    /// content, returning both.
+    fn update_presented_children(&self, wrapped: &Identified<Node>, content: &Identified<Node>, difference: Option<&Difference<&Node>>) -> (Self, Self) {
+        let (mut widgets, _) = self.reconcile_children(vec![
+            (IdPathBuf::from(wrapped.id().clone()), wrapped.value()),
+            (IdPathBuf::from(content.id().clone()), content.value()),
+        ], difference);
+        let (content_widget, _) = widgets.remove(1);
+        let (wrapped_widget, _) = widgets.remove(0);
+        (wrapped_widget, content_widget)
+    }
+
+    /// Presents or closes the given dialog, depending on whether it should be
+    /// presented.
+    fn sync_dialog(&self, dialog: &Dialog, is_presented: bool) {
+        let is_open = dialog.parent().is_some();
+        if is_presented && !is_open {
+            // Dialogs can only be presented from widgets within a window,
+            // which this widget may not be yet
+            let weak_self = self.downgrade();
+            let dialog = dialog.clone();
+            glib::idle_add_local_once(move || {
+                let Some(widget) = weak_self.upgrade() else { return };
+                if widget.is_presenting(&dialog) && dialog.parent().is_none() {
+                    dialog.present(Some(&widget));
+                }
+            });
+        } else if !is_presented && is_open {
+            dialog.force_close();
+        }
+    }
+
+    /// Whether the given dialog or popover is the current presentation of
+    /// this widget and the rendered node is presented.
+    fn is_presenting(&self, presentation: &impl IsA<gtk::Widget>) -> bool {
+        let imp = imp::NodeWidget::from_obj(self);
+        let is_current = imp.presentation.borrow().as_ref() == Some(presentation.upcast_ref());
+        is_current && is_presented(&imp.node.borrow())
+    }
+
+    /// Notifies the view that the given presentation was closed by the user.
+    fn presentation_closed(&self, presentation: &impl IsA<gtk::Widget>) {
+        if self.is_presenting(presentation) {
+            self.event_sink().fire(&Event::Dismiss {});
+        }
+    }
+
+    /// Closes and discards the current dialog or popover, if any.
+    pub(super) fn remove_presentation(&self) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(presentation) = imp.presentation.take() else { return };
+        if let Some(dialog) = presentation.downcast_ref::<Dialog>() {
+            if dialog.parent().is_some() {
+                dialog.force_close();
+            }
+        } else if presentation.parent().is_some() {
+            presentation.unparent();
+        }
+    }
+}
+
+/// Whether the given node is a presentation that is currently presented.
+const fn is_presented(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Sheet { is_presented: true, .. }
+        | Node::Popover { is_presented: true, .. }
+        | Node::Alert { is_presented: true, .. }
+        | Node::ConfirmationDialog { is_presented: true, .. }
+    )
+}
+
+/// The id paths (relative to the parent of the actions), labels and roles of
+/// the buttons among the given actions of an alert or menu.
+pub(super) fn action_buttons(actions: &Identified<Node>) -> Vec<(IdPathBuf, String, Option<ButtonRole>)> {
+    flattened_children(actions)
+        .into_iter()
+        .filter_map(|(path, action)| {
+            action.descendants_from(&path)
+                .into_iter()
+                .find_map(|(path, node)| match node {
+                    Node::Button { label, role } => {
+                        let text = first_text(label.value()).unwrap_or_default();
+                        Some((path, text, *role))
+                    },
+                    _ => None,
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,136 @@
+use std::f64::consts::PI;
+
+use adw::{gtk::{self, cairo::Context}, prelude::*};
+use nuit_core::{Material, SemanticStyle, ShapeNode, Style};
+
+/// An RGBA color as used by cairo.
+type Rgba = (f64, f64, f64, f64);
+
+/// Draws the given shape, filling the given size. Unstyled shapes are filled
+/// with the foreground color, like in `SwiftUI`.
+pub fn draw_shape(widget: &impl IsA<gtk::Widget>, cr: &Context, shape: &ShapeNode, width: f64, height: f64) {
+    match shape {
+        ShapeNode::Fill { wrapped, style } => {
+            draw_styled_layers(widget, cr, wrapped, width, height);
+            trace_path(cr, wrapped, width, height);
+            set_source(cr, resolve_style(widget, style));
+            let _ = cr.fill();
+        },
+        ShapeNode::Stroke { wrapped, style } => {
+            draw_styled_layers(widget, cr, wrapped, width, height);
+            trace_path(cr, wrapped, width, height);
+            set_source(cr, resolve_style(widget, style));
+            cr.set_line_width(1.0);
+            let _ = cr.stroke();
+        },
+        _ => {
+            trace_path(cr, shape, width, height);
+            set_source(cr, resolve_style(widget, &Style::FOREGROUND));
+            let _ = cr.fill();
+        },
+    }
+}
+
+/// Draws the fills and strokes below the outermost one, if any.
+fn draw_styled_layers(widget: &impl IsA<gtk::Widget>, cr: &Context, shape: &ShapeNode, width: f64, height: f64) {
+    if matches!(shape, ShapeNode::Fill { .. } | ShapeNode::Stroke { .. }) {
+        draw_shape(widget, cr, shape, width, height);
+    }
+}
+
+/// Traces the outline of the given shape as the current path.
+fn trace_path(cr: &Context, shape: &ShapeNode, width: f64, height: f64) {
+    let (cx, cy) = (width / 2.0, height / 2.0);
+    cr.new_path();
+    match shape {
+        ShapeNode::Capsule {} => trace_rounded_rect(cr, width, height, width.min(height) / 2.0),
+        ShapeNode::Circle {} => cr.arc(cx, cy, width.min(height) / 2.0, 0.0, 2.0 * PI),
+        ShapeNode::Ellipse {} => {
+            if width > 0.0 && height > 0.0 {
+                let _ = cr.save();
+                cr.translate(cx, cy);
+                cr.scale(cx, cy);
+                cr.arc(0.0, 0.0, 1.0, 0.0, 2.0 * PI);
+                let _ = cr.restore();
+            }
+        },
+        ShapeNode::Rectangle {} => cr.rectangle(0.0, 0.0, width, height),
+        // Cairo has no elliptical arcs, so we use the smaller corner dimension
+        ShapeNode::RoundedRectangle { corner_size } => trace_rounded_rect(cr, width, height, corner_size.x.min(corner_size.y)),
+        ShapeNode::Sector { start_angle, end_angle, inner_radius_fraction } => {
+            let outer_radius = width.min(height) / 2.0;
+            let inner_radius = inner_radius_fraction * outer_radius;
+            cr.arc(cx, cy, outer_radius, start_angle.radians(), end_angle.radians());
+            if inner_radius <= 0.0 {
+                cr.line_to(cx, cy);
+            } else {
+                cr.arc_negative(cx, cy, inner_radius, end_angle.radians(), start_angle.radians());
+            }
+            cr.close_path();
+        },
+        ShapeNode::Fill { wrapped, .. }
+        | ShapeNode::Stroke { wrapped, .. } => trace_path(cr, wrapped, width, height),
+    }
+}
+
+fn trace_rounded_rect(cr: &Context, width: f64, height: f64, radius: f64) {
+    let radius = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+    cr.new_sub_path();
+    cr.arc(width - radius, radius, radius, -PI / 2.0, 0.0);
+    cr.arc(width - radius, height - radius, radius, 0.0, PI / 2.0);
+    cr.arc(radius, height - radius, radius, PI / 2.0, PI);
+    cr.arc(radius, radius, radius, PI, 3.0 * PI / 2.0);
+    cr.close_path();
+}
+
+fn set_source(cr: &Context, (red, green, blue, alpha): Rgba) {
+    cr.set_source_rgba(red, green, blue, alpha);
+}
+
+/// Resolves the given style to a concrete color using the widget's theme.
+#[allow(deprecated)]
+fn resolve_style(widget: &impl IsA<gtk::Widget>, style: &Style) -> Rgba {
+    let style_context = widget.style_context();
+    let named = |name: &str| style_context.lookup_color(name)
+        .unwrap_or_else(|| style_context.color());
+    let rgba = |color: gtk::gdk::RGBA, opacity: f64| (
+        f64::from(color.red()),
+        f64::from(color.green()),
+        f64::from(color.blue()),
+        f64::from(color.alpha()) * opacity,
+    );
+
+    match style {
+        Style::Color { color } => (color.red(), color.green(), color.blue(), color.alpha()),
+        Style::Hierarchical { level } => rgba(style_context.color(), match level {
+            0 => 1.0,
+            1 => 0.55,
+            2 => 0.3,
+            _ => 0.15,
+        }),
+        Style::Material { material } => match material {
+            Material::Bar => rgba(named("headerbar_bg_color"), 1.0),
+            Material::UltraThin => rgba(named("window_bg_color"), 0.3),
+            Material::Thin => rgba(named("window_bg_color"), 0.5),
+            Material::Regular => rgba(named("window_bg_color"), 0.7),
+            Material::Thick => rgba(named("window_bg_color"), 0.85),
+            Material::UltraThick => rgba(named("window_bg_color"), 0.95),
+        },
+        Style::Semantic { style } => match style {
+            SemanticStyle::Foreground => rgba(style_context.color(), 1.0),
+            SemanticStyle::Background | SemanticStyle::WindowBackground => rgba(named("window_bg_color"), 1.0),
+            SemanticStyle::Selection => rgba(named("accent_bg_color"), 0.3),
+            SemanticStyle::Separator => rgba(named("borders"), 1.0),
+            SemanticStyle::Tint => rgba(named("accent_bg_color"), 1.0),
+            SemanticStyle::Placeholder => rgba(style_context.color(), 0.55),
+            SemanticStyle::Link => rgba(named("accent_color"), 1.0),
+            SemanticStyle::Fill => rgba(style_context.color(), 0.1),
+        },
+        Style::BlendMode { wrapped, .. }
+        | Style::Shadow { wrapped, .. } => resolve_style(widget, wrapped),
+        Style::Opacity { wrapped, opacity } => {
+            let (red, green, blue, alpha) = resolve_style(widget, wrapped);
+            (red, green, blue, alpha * opacity)
+        },
+    }
+}
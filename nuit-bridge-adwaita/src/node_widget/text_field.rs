@@ -0,0 +1,114 @@
+use adw::{gtk::{self, EventControllerFocus, InputHints, InputPurpose, PasswordEntry, Text, TextView, WrapMode}, prelude::*};
+use nuit_core::{clone, Event, KeyboardType, TextFieldKind};
+
+use super::event_sink::EventSink;
+
+/// Creates a text widget for a text field of the given kind. Single-line
+/// fields fire a submit event on Enter, all fields fire focus events.
+pub fn build_text_field(content: &str, prompt: Option<&str>, kind: TextFieldKind, keyboard_type: KeyboardType, is_autocorrection_disabled: bool, event_sink: EventSink) -> gtk::Widget {
+    let widget: gtk::Widget = match kind {
+        TextFieldKind::Plain => {
+            let text = Text::builder().text(content).build();
+            text.set_placeholder_text(prompt);
+            text.set_input_purpose(input_purpose(keyboard_type));
+            text.set_input_hints(input_hints(is_autocorrection_disabled));
+            text.connect_changed(clone!(event_sink => move |text| {
+                event_sink.fire(&Event::UpdateText { content: text.text().into() });
+            }));
+            text.connect_activate(clone!(event_sink => move |_text| {
+                event_sink.fire(&Event::Submit {});
+            }));
+            text.upcast()
+        },
+        TextFieldKind::Secure => {
+            let entry = PasswordEntry::new();
+            entry.set_text(content);
+            entry.set_placeholder_text(prompt);
+            entry.set_show_peek_icon(true);
+            entry.connect_changed(clone!(event_sink => move |entry| {
+                event_sink.fire(&Event::UpdateText { content: entry.text().into() });
+            }));
+            entry.connect_activate(clone!(event_sink => move |_entry| {
+                event_sink.fire(&Event::Submit {});
+            }));
+            entry.upcast()
+        },
+        TextFieldKind::Multiline => {
+            // Text views have no placeholder, so the prompt is not shown
+            let text_view = TextView::new();
+            text_view.set_wrap_mode(WrapMode::WordChar);
+            text_view.set_input_purpose(input_purpose(keyboard_type));
+            text_view.set_input_hints(input_hints(is_autocorrection_disabled));
+            text_view.buffer().set_text(content);
+            text_view.buffer().connect_changed(clone!(event_sink => move |buffer| {
+                let (start, end) = buffer.bounds();
+                event_sink.fire(&Event::UpdateText { content: buffer.text(&start, &end, false).into() });
+            }));
+            text_view.upcast()
+        },
+    };
+
+    let focus_controller = EventControllerFocus::new();
+    focus_controller.connect_enter(clone!(event_sink => move |_controller| {
+        event_sink.fire(&Event::UpdateFocus { is_focused: true });
+    }));
+    focus_controller.connect_leave(move |_controller| {
+        event_sink.fire(&Event::UpdateFocus { is_focused: false });
+    });
+    widget.add_controller(focus_controller);
+
+    widget
+}
+
+pub fn patch_text_field(widget: &gtk::Widget, content: &str, prompt: Option<&str>, kind: TextFieldKind, keyboard_type: KeyboardType, is_autocorrection_disabled: bool) -> bool {
+    // Only replace the text if it actually differs, since doing so resets the
+    // cursor position.
+    match kind {
+        TextFieldKind::Plain => {
+            let Some(text) = widget.downcast_ref::<Text>() else { return false };
+            text.set_placeholder_text(prompt);
+            text.set_input_purpose(input_purpose(keyboard_type));
+            text.set_input_hints(input_hints(is_autocorrection_disabled));
+            if text.text() != content {
+                text.set_text(content);
+            }
+        },
+        TextFieldKind::Secure => {
+            let Some(entry) = widget.downcast_ref::<PasswordEntry>() else { return false };
+            entry.set_placeholder_text(prompt);
+            if entry.text() != content {
+                entry.set_text(content);
+            }
+        },
+        TextFieldKind::Multiline => {
+            let Some(text_view) = widget.downcast_ref::<TextView>() else { return false };
+            text_view.set_input_purpose(input_purpose(keyboard_type));
+            text_view.set_input_hints(input_hints(is_autocorrection_disabled));
+            let buffer = text_view.buffer();
+            let (start, end) = buffer.bounds();
+            if buffer.text(&start, &end, false) != content {
+                buffer.set_text(content);
+            }
+        },
+    }
+    true
+}
+
+const fn input_purpose(keyboard_type: KeyboardType) -> InputPurpose {
+    match keyboard_type {
+        KeyboardType::Default => InputPurpose::FreeForm,
+        KeyboardType::EmailAddress => InputPurpose::Email,
+        KeyboardType::Url => InputPurpose::Url,
+        KeyboardType::Number => InputPurpose::Digits,
+        KeyboardType::Decimal => InputPurpose::Number,
+        KeyboardType::PhoneNumber => InputPurpose::Phone,
+    }
+}
+
+const fn input_hints(is_autocorrection_disabled: bool) -> InputHints {
+    if is_autocorrection_disabled {
+        InputHints::NO_SPELLCHECK
+    } else {
+        InputHints::NONE
+    }
+}
@@ -0,0 +1,73 @@
+use adw::{prelude::*, subclass::prelude::*};
+use nuit_core::{Difference, IdPathBuf, Identified, Node, ToolbarItemPlacement};
+
+use super::{flattened_children, imp, NodeWidget};
+
+impl NodeWidget {
+    /// Updates the node widgets for the wrapped view and the items of a
+    /// toolbar, returning the former. The items are shown in the header bar
+    /// while this widget is mapped.
+    pub(super) fn update_toolbar(&self, wrapped: &Identified<Node>, items: &Identified<Node>, difference: Option<&Difference<&Node>>) -> Self {
+        let item_children = flattened_children(items);
+        let placements: Vec<_> = item_children.iter()
+            .map(|(_, item)| match item {
+                Node::ToolbarItem { placement, .. } => *placement,
+                _ => ToolbarItemPlacement::default(),
+            })
+            .collect();
+
+        let mut children = vec![(IdPathBuf::from(wrapped.id().clone()), wrapped.value())];
+        children.extend(item_children);
+        let (mut widgets, _) = self.reconcile_children(children, difference);
+        let wrapped_widget = widgets.remove(0).0;
+
+        // We simply re-add all items, since the header bar cannot reorder them
+        self.detach_toolbar_items();
+        imp::NodeWidget::from_obj(self).toolbar_items.replace(placements.into_iter().zip(widgets.into_iter().map(|(widget, _)| widget)).collect());
+        if self.is_mapped() {
+            self.attach_toolbar_items();
+        }
+
+        wrapped_widget
+    }
+
+    /// Adds the toolbar items to the header bar. Navigation items are packed
+    /// at the start, principal items replace the title and all others are
+    /// packed at the end.
+    pub(super) fn attach_toolbar_items(&self) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(ref header_bar) = *imp.header_bar.borrow() else { return };
+        let items = imp.toolbar_items.borrow();
+
+        for (placement, widget) in items.iter() {
+            if widget.parent().is_some() {
+                continue;
+            }
+            match placement {
+                ToolbarItemPlacement::Navigation => header_bar.pack_start(widget),
+                ToolbarItemPlacement::Principal => header_bar.set_title_widget(Some(widget)),
+                ToolbarItemPlacement::Automatic | ToolbarItemPlacement::PrimaryAction => {},
+            }
+        }
+
+        // Items packed at the end are laid out from the end, so we pack them
+        // in reverse to preserve their order
+        for (placement, widget) in items.iter().rev() {
+            if widget.parent().is_none() && matches!(placement, ToolbarItemPlacement::Automatic | ToolbarItemPlacement::PrimaryAction) {
+                header_bar.pack_end(widget);
+            }
+        }
+    }
+
+    /// Removes the toolbar items from the header bar.
+    pub(super) fn detach_toolbar_items(&self) {
+        let imp = imp::NodeWidget::from_obj(self);
+        let Some(ref header_bar) = *imp.header_bar.borrow() else { return };
+
+        for (_, widget) in imp.toolbar_items.borrow().iter() {
+            if widget.parent().is_some() {
+                header_bar.remove(widget);
+            }
+        }
+    }
+}
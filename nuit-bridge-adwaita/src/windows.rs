@@ -0,0 +1,213 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::ReentrantLock};
+
+use adw::{gio::{self, SimpleAction}, glib, gtk::{Box, MenuButton, Orientation}, prelude::*, Application, ApplicationWindow, HeaderBar};
+use nuit_core::{clone, App, AnyView, CommandItemInfo, EventResponse, OpenWindow, Root, SceneInfo, SceneKind};
+use serde_json::Value;
+
+use crate::{convert::ToGtk, executor::GlibExecutor, node_widget::NodeWidget};
+
+const DEFAULT_TITLE: &str = "Nuit App";
+const DEFAULT_WIDTH: i32 = 640;
+const DEFAULT_HEIGHT: i32 = 480;
+
+/// Manages the windows presenting the app's scenes. Every window has its own
+/// root and therefore its own state.
+pub struct Windows {
+    app: App,
+    application: Application,
+    /// The open windows, keyed by their scene ids and the (JSON-serialized)
+    /// values they present.
+    opened: RefCell<HashMap<(String, String), ApplicationWindow>>,
+    /// The menu presenting the app's commands, if there are any.
+    commands_menu: Option<gio::Menu>,
+}
+
+impl Windows {
+    pub fn new(app: App, application: Application) -> Rc<Self> {
+        let commands_menu = (!app.commands().is_empty()).then(|| build_commands_menu(&app));
+        let windows = Rc::new(Self {
+            app,
+            application,
+            opened: RefCell::new(HashMap::new()),
+            commands_menu,
+        });
+        windows.register_command_actions();
+        windows
+    }
+
+    /// Registers an application action (along with its accelerator) for every
+    /// command button.
+    fn register_command_actions(self: &Rc<Self>) {
+        for (menu_index, menu) in self.app.commands().menus().iter().enumerate() {
+            for (item_index, item) in menu.items().iter().enumerate() {
+                let CommandItemInfo::Button { shortcut, .. } = item.info() else { continue };
+                let name = command_action_name(menu_index, item_index);
+                let action = SimpleAction::new(&name, None);
+                let weak_self = Rc::downgrade(self);
+                action.connect_activate(move |_action, _parameter| {
+                    if let Some(windows) = weak_self.upgrade() {
+                        if let Err(error) = windows.app.commands().perform(menu_index, item_index) {
+                            eprintln!("Warning: {error}");
+                        }
+                    }
+                });
+                self.application.add_action(&action);
+                if let Some(shortcut) = shortcut {
+                    self.application.set_accels_for_action(&format!("app.{name}"), &[shortcut.to_gtk().as_str()]);
+                }
+            }
+        }
+    }
+
+    /// Writes the app's persisted state that hasn't been written yet.
+    pub fn flush_persisted_state(&self) {
+        self.app.flush_persisted_state();
+    }
+
+    /// Opens the scene that should be shown on launch.
+    pub fn open_launch_scene(self: &Rc<Self>) {
+        if let Some(scene) = self.app.launch_scene() {
+            let scene_id = scene.info().id().to_owned();
+            self.open(&scene_id, Value::Null);
+        } else {
+            eprintln!("Warning: The app has no scene to open on launch");
+        }
+    }
+
+    /// Opens a window for the given scene presenting the given value, or
+    /// brings the existing one to the front.
+    pub fn open(self: &Rc<Self>, scene_id: &str, value: Value) {
+        let key = (scene_id.to_owned(), value.to_string());
+        let existing = self.opened.borrow().get(&key).cloned();
+        if let Some(window) = existing {
+            window.present();
+            return;
+        }
+
+        let weak_self = Rc::downgrade(self);
+        let open_window = OpenWindow::new(move |id, value| {
+            if let Some(windows) = weak_self.upgrade() {
+                windows.open(id, value);
+            }
+        });
+
+        let root = match self.app.make_root(scene_id, value, open_window) {
+            Ok(root) => root,
+            Err(error) => {
+                eprintln!("Warning: Could not open window: {error}");
+                return;
+            },
+        };
+        let Some(scene) = self.app.find_scene(scene_id) else { return };
+        let window = self.build_window(root, scene.info());
+
+        if scene.info().kind() == SceneKind::WindowGroup {
+            let weak_self = Rc::downgrade(self);
+            window.connect_close_request(clone!(key => move |_window| {
+                if let Some(windows) = weak_self.upgrade() {
+                    windows.opened.borrow_mut().remove(&key);
+                }
+                glib::Propagation::Proceed
+            }));
+        } else {
+            // Scenes with a single window can only create their view once, so
+            // we keep the window (and its state) around
+            window.set_hide_on_close(true);
+        }
+
+        self.opened.borrow_mut().insert(key, window.clone());
+        window.present();
+    }
+
+    fn build_window(&self, root: Root<AnyView>, info: &SceneInfo) -> ApplicationWindow {
+        root.set_executor(GlibExecutor);
+        // The root is neither `Send` nor `Sync` and only used on the main
+        // thread, but it has to be reentrant since events trigger updates
+        let root = Rc::new(ReentrantLock::new(root));
+
+        let header_bar = HeaderBar::new();
+        if let Some(commands_menu) = &self.commands_menu {
+            let menu_button = MenuButton::new();
+            menu_button.set_icon_name("open-menu-symbolic");
+            menu_button.set_menu_model(Some(commands_menu));
+            header_bar.pack_end(&menu_button);
+        }
+
+        let node = Root::render(&root.lock());
+        let node_widget = NodeWidget::root(node, &header_bar, clone!(root => move |id_path, event| {
+            // Events may be stale (e.g. if fired by a widget that is about to
+            // be removed), which we don't consider fatal
+            root.lock().fire_event(id_path, event).unwrap_or_else(|error| {
+                eprintln!("Warning: {error}");
+                EventResponse::default()
+            })
+        }));
+
+        // The root is owned by the widget tree, so we only reference the
+        // widget and the root weakly here to avoid cycles
+        let weak_root = Rc::downgrade(&root);
+        let weak_node_widget = node_widget.downgrade();
+        root.lock().set_update_callback(move |_update| {
+            if let (Some(root), Some(node_widget)) = (weak_root.upgrade(), weak_node_widget.upgrade()) {
+                node_widget.update(Root::render(&root.lock()));
+            }
+        });
+
+        let content = Box::new(Orientation::Vertical, 0);
+        content.append(&header_bar);
+        content.append(&node_widget);
+
+        let (default_width, default_height) = info.default_size()
+            .map_or((DEFAULT_WIDTH, DEFAULT_HEIGHT), |size| (to_pixels(size.x), to_pixels(size.y)));
+        let window = ApplicationWindow::builder()
+            .application(&self.application)
+            .title(info.title().unwrap_or(DEFAULT_TITLE))
+            .default_width(default_width)
+            .default_height(default_height)
+            .content(&content)
+            .build();
+        if let Some(min_size) = info.min_size() {
+            window.set_size_request(to_pixels(min_size.x), to_pixels(min_size.y));
+        }
+        window
+    }
+}
+
+/// Converts a window dimension to whole pixels. Dimensions are rounded and
+/// clamped to the range GTK accepts, i.e. negative (and NaN) dimensions become
+/// zero and huge (or infinite) ones `i32::MAX`.
+#[allow(clippy::cast_possible_truncation)]
+fn to_pixels(dimension: f64) -> i32 {
+    // Casting a NaN yields zero, the clamped value is cast exactly
+    dimension.round().clamp(0.0, f64::from(i32::MAX)) as i32
+}
+
+/// The name of the application action performing the command at the given
+/// indices.
+fn command_action_name(menu_index: usize, item_index: usize) -> String {
+    format!("command-{menu_index}-{item_index}")
+}
+
+/// Builds the menu presenting the app's commands, with a submenu per command
+/// menu. Dividers are rendered by splitting the items into sections.
+fn build_commands_menu(app: &App) -> gio::Menu {
+    let menu = gio::Menu::new();
+    for (menu_index, command_menu) in app.commands().menus().iter().enumerate() {
+        let submenu = gio::Menu::new();
+        let mut section = gio::Menu::new();
+        for (item_index, item) in command_menu.items().iter().enumerate() {
+            match item.info() {
+                CommandItemInfo::Button { title, .. } => {
+                    section.append(Some(title.as_str()), Some(&format!("app.{}", command_action_name(menu_index, item_index))));
+                },
+                CommandItemInfo::Divider {} => {
+                    submenu.append_section(None, &section);
+                    section = gio::Menu::new();
+                },
+            }
+        }
+        submenu.append_section(None, &section);
+        menu.append_submenu(Some(command_menu.title()), &submenu);
+    }
+    menu
+}
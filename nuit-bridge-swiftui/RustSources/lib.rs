@@ -1,13 +1,13 @@
 #![allow(clippy::doc_markdown)]
 
 #[cfg(target_vendor = "apple")]
-use nuit_core::CRoot;
+use nuit_core::CApp;
 
 #[cfg(target_vendor = "apple")]
 extern "C" {
-    /// Runs the given app root using SwiftUI.
+    /// Runs the given app using SwiftUI.
     #[link_name = "nuit_bridge_swiftui_run_app"]
-    pub fn run_app(root: *const CRoot);
+    pub fn run_app(app: *const CApp);
 }
 
 #[cfg(all(test, target_vendor = "apple"))]
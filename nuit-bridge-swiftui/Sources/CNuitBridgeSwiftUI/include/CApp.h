@@ -0,0 +1,15 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "CRoot.h"
+
+struct CApp {
+    void *wrapped;
+    const char *(*info_json)(const struct CApp *);
+    struct CRoot *(*create_root)(const struct CApp *, const char *, const char *);
+    void (*drop_root)(const struct CApp *, struct CRoot *);
+    void (*set_open_window_callback)(const struct CApp *, void (*)(const char *, const char *));
+    void (*perform_command)(const struct CApp *, size_t, size_t);
+    void (*flush_persisted_state)(const struct CApp *);
+};
@@ -1,4 +1,5 @@
 #pragma once
 
+#include "CApp.h"
 #include "CRoot.h"
 #include "CString.h"
@@ -4,5 +4,7 @@ struct CRoot {
     void *wrapped;
     const char *(*render_json)(const struct CRoot *);
     const char *(*fire_event_json)(const struct CRoot *, const char *, const char *);
-    void (*set_update_callback)(const struct CRoot *, void (*)(const char *));
+    void (*set_update_callback)(const struct CRoot *, void *, void (*)(void *, const char *));
+    void (*run_tasks)(const struct CRoot *);
+    void (*set_wake_callback)(const struct CRoot *, void *, void (*)(void *));
 };
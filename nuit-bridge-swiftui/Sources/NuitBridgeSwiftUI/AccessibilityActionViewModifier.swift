@@ -0,0 +1,18 @@
+import NuitBridgeSwiftUICore
+import SwiftUI
+
+/// Offers a named action to assistive technologies, which performs the action
+/// of the corresponding Nuit view.
+struct AccessibilityActionViewModifier: ViewModifier {
+    let name: String
+    let idPath: [Id]
+
+    @EnvironmentObject private var root: Root
+
+    func body(content: Content) -> some View {
+        content
+            .accessibilityAction(named: name) {
+                root.fire(event: .performAccessibilityAction(name: name), for: idPath)
+            }
+    }
+}
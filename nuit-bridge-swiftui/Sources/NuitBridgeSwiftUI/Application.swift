@@ -0,0 +1,66 @@
+import CNuitBridgeSwiftUI
+import Foundation
+
+/// The Nuit app, creating a root for every window.
+class Application {
+    private let cApp: UnsafePointer<CApp>
+
+    let info: AppInfo
+
+    /// Opens the window for the given request. Set by the windows, since
+    /// SwiftUI only provides this action through the environment.
+    var openWindowAction: ((WindowRequest) -> Void)?
+    /// Opens the settings window.
+    var openSettingsAction: (() -> Void)?
+
+    init(cApp: UnsafePointer<CApp>) {
+        self.cApp = cApp
+
+        let cString = cApp.pointee.info_json(cApp)!
+        defer { nuit_c_string_drop(cString) }
+        info = try! JSONDecoder().decode(AppInfo.self, from: String(cString: cString).data(using: .utf8)!)
+    }
+
+    var launchRequest: WindowRequest {
+        WindowRequest(sceneId: info.launchSceneId ?? "", valueJson: "null")
+    }
+
+    var settingsScene: SceneInfo? {
+        info.scenes.first { $0.kind == .settings }
+    }
+
+    func scene(id: String) -> SceneInfo? {
+        info.scenes.first { $0.id == id }
+    }
+
+    func createRoot(for request: WindowRequest) -> Root? {
+        guard let cRoot = cApp.pointee.create_root(cApp, request.sceneId, request.valueJson) else {
+            print("Warning: Could not create root for scene \(request.sceneId)")
+            return nil
+        }
+        return Root(cApp: cApp, cRoot: cRoot)
+    }
+
+    func performCommand(menu: Int, item: Int) {
+        cApp.pointee.perform_command(cApp, menu, item)
+    }
+
+    /// Writes the persisted state that hasn't been written yet.
+    func flushPersistedState() {
+        cApp.pointee.flush_persisted_state(cApp)
+    }
+
+    func open(window request: WindowRequest) {
+        #if os(macOS)
+        if scene(id: request.sceneId)?.kind == .settings, let openSettingsAction {
+            openSettingsAction()
+            return
+        }
+        #endif
+        guard let openWindowAction else {
+            print("Warning: Cannot open window for scene \(request.sceneId) before the first window has appeared")
+            return
+        }
+        openWindowAction(request)
+    }
+}
@@ -4,9 +4,22 @@ enum Event: Codable, Hashable {
     case buttonTap
     case gesture(gesture: GestureEvent)
     case updateText(content: String)
+    case updateFocus(isFocused: Bool)
+    case submit
     case updatePickerSelection(id: Id)
     case updateSliderValue(value: Double)
+    case updateToggle(isOn: Bool)
+    case updateStepperValue(value: Int)
+    case updateDate(date: CalendarDate)
+    case updateSelection(selection: [Id])
+    case deleteRows(indices: [Int])
+    case moveRows(indices: [Int], destination: Int)
+    case performAccessibilityAction(name: String)
     case updateNavigationPath(path: [Value])
     case getNavigationDestination(value: Value)
+    case dismiss
     case getGeometryReaderView(geometry: Geometry)
+    case getRows(start: Int, end: Int)
+    case appear
+    case disappear
 }
@@ -3,4 +3,6 @@ import NuitBridgeSwiftUICore
 enum EventResponse: Codable, Hashable {
     case empty
     case node(node: Identified<Node>)
+    case nodes(nodes: [Identified<Node>])
+    case error(message: String)
 }
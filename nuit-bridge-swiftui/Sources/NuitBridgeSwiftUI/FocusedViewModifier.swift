@@ -0,0 +1,28 @@
+import NuitBridgeSwiftUICore
+import SwiftUI
+
+/// Keeps the focus of the modified view in sync with the focus state of the
+/// corresponding Nuit view.
+struct FocusedViewModifier: ViewModifier {
+    let isFocused: Bool
+    let idPath: [Id]
+
+    @EnvironmentObject private var root: Root
+    @FocusState private var hasFocus: Bool
+
+    func body(content: Content) -> some View {
+        content
+            .focused($hasFocus)
+            .onAppear {
+                hasFocus = isFocused
+            }
+            .onChange(of: isFocused) { _, newValue in
+                hasFocus = newValue
+            }
+            .onChange(of: hasFocus) { _, newValue in
+                if newValue != isFocused {
+                    root.fire(event: .updateFocus(isFocused: newValue), for: idPath)
+                }
+            }
+    }
+}
@@ -0,0 +1,74 @@
+import NuitBridgeSwiftUICore
+import SwiftUI
+
+#if canImport(AppKit)
+private typealias PlatformImage = NSImage
+#else
+private typealias PlatformImage = UIImage
+#endif
+
+struct ImageNodeView: View {
+    let source: ImageSource
+
+    @Environment(\.isImageResizable) private var isResizable
+    @Environment(\.imageInterpolation) private var interpolation
+
+    var body: some View {
+        let image = baseImage.interpolation(.init(interpolation))
+        if isResizable {
+            image.resizable()
+        } else {
+            image
+        }
+    }
+
+    private var baseImage: Image {
+        switch source {
+        case let .file(path: path):
+            return Image(platformImage: PlatformImage(contentsOfFile: path))
+        case let .bytes(data: data):
+            return Image(platformImage: PlatformImage(data: data))
+        case let .symbol(name: name):
+            return Image(systemName: name)
+        }
+    }
+}
+
+private extension Image {
+    /// An image from the given platform image, or a placeholder if it could
+    /// not be loaded.
+    init(platformImage: PlatformImage?) {
+        guard let platformImage else {
+            self.init(systemName: "photo")
+            return
+        }
+        #if canImport(AppKit)
+        self.init(nsImage: platformImage)
+        #else
+        self.init(uiImage: platformImage)
+        #endif
+    }
+}
+
+// Resizability and interpolation only apply to images in SwiftUI, so the
+// corresponding modifiers pass them down through the environment.
+
+private struct IsImageResizableKey: EnvironmentKey {
+    static let defaultValue = false
+}
+
+private struct ImageInterpolationKey: EnvironmentKey {
+    static let defaultValue: NuitBridgeSwiftUICore.Interpolation = .high
+}
+
+extension EnvironmentValues {
+    var isImageResizable: Bool {
+        get { self[IsImageResizableKey.self] }
+        set { self[IsImageResizableKey.self] = newValue }
+    }
+
+    var imageInterpolation: NuitBridgeSwiftUICore.Interpolation {
+        get { self[ImageInterpolationKey.self] }
+        set { self[ImageInterpolationKey.self] = newValue }
+    }
+}
@@ -30,4 +30,18 @@ enum ModifierNode: Codable, Hashable {
     case saturation(amount: Double)
     case hueRotation(angle: Angle)
     case clipped
+    case resizable
+    case aspectRatio(ratio: Double?, contentMode: ContentMode)
+    case interpolation(interpolation: Interpolation)
+    case keyboardShortcut(shortcut: KeyboardShortcut)
+    case focused(isFocused: Bool)
+    case gridCellColumns(count: Int)
+    case gridColumnAlignment(alignment: HorizontalAlignment)
+    case accessibilityLabel(label: String)
+    case accessibilityHint(hint: String)
+    case accessibilityValue(value: String)
+    case accessibilityRole(role: AccessibilityRole)
+    case accessibilityHeading(level: AccessibilityHeadingLevel)
+    case accessibilityHidden(isHidden: Bool)
+    case accessibilityAction(name: String)
 }
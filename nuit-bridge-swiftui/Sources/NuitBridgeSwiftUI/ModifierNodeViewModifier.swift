@@ -3,6 +3,7 @@ import SwiftUI
 
 struct ModifierNodeViewModifier: ViewModifier {
     let modifier: ModifierNode
+    let idPath: [Id]
 
     func body(content: Content) -> some View {
         switch modifier {
@@ -105,6 +106,36 @@ struct ModifierNodeViewModifier: ViewModifier {
             content.hueRotation(.init(angle))
         case .clipped:
             content.clipped()
+        case .resizable:
+            content.environment(\.isImageResizable, true)
+        case let .aspectRatio(ratio: ratio, contentMode: contentMode):
+            content.aspectRatio(ratio.map { CGFloat($0) }, contentMode: .init(contentMode))
+        case let .interpolation(interpolation: interpolation):
+            content.environment(\.imageInterpolation, interpolation)
+        case let .keyboardShortcut(shortcut: shortcut):
+            content.keyboardShortcut(SwiftUI.KeyboardShortcut(shortcut))
+        case let .focused(isFocused: isFocused):
+            content.modifier(FocusedViewModifier(isFocused: isFocused, idPath: idPath))
+        case let .gridCellColumns(count: count):
+            content.gridCellColumns(count)
+        case let .gridColumnAlignment(alignment: alignment):
+            content.gridColumnAlignment(.init(alignment))
+        case let .accessibilityLabel(label: label):
+            content.accessibilityLabel(label)
+        case let .accessibilityHint(hint: hint):
+            content.accessibilityHint(hint)
+        case let .accessibilityValue(value: value):
+            content.accessibilityValue(value)
+        case let .accessibilityRole(role: role):
+            content.accessibilityAddTraits(.init(role))
+        case let .accessibilityHeading(level: level):
+            content
+                .accessibilityAddTraits(.isHeader)
+                .accessibilityHeading(.init(level))
+        case let .accessibilityHidden(isHidden: isHidden):
+            content.accessibilityHidden(isHidden)
+        case let .accessibilityAction(name: name):
+            content.modifier(AccessibilityActionViewModifier(name: name, idPath: idPath))
         }
     }
 }
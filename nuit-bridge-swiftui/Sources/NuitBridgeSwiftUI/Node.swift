@@ -5,21 +5,35 @@ indirect enum Node: Codable, Hashable {
 
     // MARK: Widget
     case text(content: String)
-    case textField(content: String)
-    case button(label: Identified<Node>)
+    case textField(content: String, prompt: String?, kind: TextFieldKind, keyboardType: KeyboardType, isAutocorrectionDisabled: Bool)
+    case button(label: Identified<Node>, role: ButtonRole?)
     case picker(title: String, selection: Id, content: Identified<Node>)
     case slider(value: Double, lowerBound: Double, upperBound: Double, step: Double?)
+    case image(source: ImageSource)
+    case toggle(title: String, isOn: Bool)
+    case stepper(title: String, value: Int, lowerBound: Int, upperBound: Int, step: Int)
+    case progressView(value: Double?)
+    case datePicker(title: String, date: CalendarDate)
 
     // MARK: Aggregation
     case child(wrapped: Identified<Node>)
     case group(children: [Identified<Node>])
+    case editable(wrapped: Identified<Node>, canDelete: Bool, canMove: Bool)
 
     // MARK: Layout
     case geometryReader
     case vStack(alignment: HorizontalAlignment, spacing: Double, wrapped: Identified<Node>)
     case hStack(alignment: VerticalAlignment, spacing: Double, wrapped: Identified<Node>)
     case zStack(alignment: Alignment, spacing: Double, wrapped: Identified<Node>)
-    case list(wrapped: Identified<Node>)
+    case list(wrapped: Identified<Node>, selectionMode: SelectionMode, selection: [Id])
+    case section(header: Identified<Node>, content: Identified<Node>, footer: Identified<Node>)
+    case lazyVStack(alignment: HorizontalAlignment, spacing: Double, count: Int)
+    case lazyHStack(alignment: VerticalAlignment, spacing: Double, count: Int)
+    case lazyList(count: Int)
+    case grid(alignment: Alignment, horizontalSpacing: Double, verticalSpacing: Double, wrapped: Identified<Node>)
+    case gridRow(alignment: VerticalAlignment?, wrapped: Identified<Node>)
+    case lazyVGrid(columns: [GridItem], alignment: HorizontalAlignment, spacing: Double, count: Int)
+    case lazyHGrid(rows: [GridItem], alignment: VerticalAlignment, spacing: Double, count: Int)
     case overlay(wrapped: Identified<Node>, alignment: Alignment, overlayed: Identified<Node>)
 
     // MARK: Navigation
@@ -28,6 +42,18 @@ indirect enum Node: Codable, Hashable {
     case navigationLink(label: Identified<Node>, value: Value)
     case navigationDestination(wrapped: Identified<Node>)
 
+    // MARK: Presentation
+    case sheet(wrapped: Identified<Node>, isPresented: Bool, content: Identified<Node>)
+    case popover(wrapped: Identified<Node>, isPresented: Bool, content: Identified<Node>)
+    case alert(wrapped: Identified<Node>, title: String, message: String?, isPresented: Bool, actions: Identified<Node>)
+    case confirmationDialog(wrapped: Identified<Node>, title: String, message: String?, isPresented: Bool, actions: Identified<Node>)
+    case contextMenu(wrapped: Identified<Node>, actions: Identified<Node>)
+    case swipeActions(wrapped: Identified<Node>, actions: Identified<Node>)
+
+    // MARK: Toolbar
+    case toolbar(wrapped: Identified<Node>, items: Identified<Node>)
+    case toolbarItem(placement: ToolbarItemPlacement, wrapped: Identified<Node>)
+
     // MARK: Wrapper
     case shape(shape: ShapeNode)
     case gestured(wrapped: Identified<Node>, gesture: Identified<GestureNode>)
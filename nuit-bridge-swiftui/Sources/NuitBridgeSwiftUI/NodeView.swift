@@ -15,15 +15,17 @@ struct NodeView: View {
         // MARK: Widget
         case let .text(content: content):
             Text(content)
-        case let .textField(content: content):
-            TextField(text: Binding(
-                get: { content },
-                set: { root.fire(event: .updateText(content: $0), for: idPath) }
-            )) {
-                // TODO: Investigate adding a label
-            }
-        case let .button(label: label):
-            Button {
+        case let .textField(content: content, prompt: prompt, kind: kind, keyboardType: keyboardType, isAutocorrectionDisabled: isAutocorrectionDisabled):
+            TextFieldNodeView(
+                content: content,
+                prompt: prompt,
+                kind: kind,
+                keyboardType: keyboardType,
+                isAutocorrectionDisabled: isAutocorrectionDisabled,
+                idPath: idPath
+            )
+        case let .button(label: label, role: role):
+            Button(role: role.map { SwiftUI.ButtonRole($0) }) {
                 root.fire(event: .buttonTap, for: idPath)
             } label: {
                 childView(for: label)
@@ -45,6 +47,29 @@ struct NodeView: View {
             } else {
                 Slider(value: binding, in: lowerBound...upperBound)
             }
+        case let .image(source: source):
+            ImageNodeView(source: source)
+        case let .toggle(title: title, isOn: isOn):
+            Toggle(title, isOn: Binding(
+                get: { isOn },
+                set: { root.fire(event: .updateToggle(isOn: $0), for: idPath) }
+            ))
+        case let .stepper(title: title, value: value, lowerBound: lowerBound, upperBound: upperBound, step: step):
+            Stepper(title, value: Binding(
+                get: { value },
+                set: { root.fire(event: .updateStepperValue(value: $0), for: idPath) }
+            ), in: lowerBound...upperBound, step: step)
+        case let .progressView(value: value):
+            if let value {
+                ProgressView(value: value)
+            } else {
+                ProgressView()
+            }
+        case let .datePicker(title: title, date: date):
+            DatePicker(title, selection: Binding(
+                get: { Date(date) },
+                set: { root.fire(event: .updateDate(date: CalendarDate($0)), for: idPath) }
+            ), displayedComponents: .date)
 
         // MARK: Aggregation
         case let .child(wrapped: wrapped):
@@ -53,6 +78,20 @@ struct NodeView: View {
             ForEach(children) { child in
                 childView(for: child)
             }
+        case let .editable(wrapped: wrapped, canDelete: canDelete, canMove: canMove):
+            if case let .group(children: children) = wrapped.value {
+                ForEach(children) { child in
+                    NodeView(node: child.value, idPath: idPath + [wrapped.id, child.id])
+                }
+                .onDelete(perform: canDelete ? { offsets in
+                    root.fire(event: .deleteRows(indices: Array(offsets)), for: idPath)
+                } : nil)
+                .onMove(perform: canMove ? { offsets, destination in
+                    root.fire(event: .moveRows(indices: Array(offsets), destination: destination), for: idPath)
+                } : nil)
+            } else {
+                childView(for: wrapped)
+            }
 
         // MARK: Layout
         case .geometryReader:
@@ -74,10 +113,79 @@ struct NodeView: View {
             ZStack(alignment: .init(alignment)) {
                 childView(for: wrapped)
             }
-        case let .list(wrapped: wrapped):
-            List {
+        case let .list(wrapped: wrapped, selectionMode: selectionMode, selection: selection):
+            switch selectionMode {
+            case .none:
+                List {
+                    childView(for: wrapped)
+                }
+            case .single:
+                List(selection: Binding<Id?>(
+                    get: { selection.first },
+                    set: { root.fire(event: .updateSelection(selection: $0.map { [$0] } ?? []), for: idPath) }
+                )) {
+                    childView(for: wrapped)
+                }
+            case .multiple:
+                List(selection: Binding<Set<Id>>(
+                    get: { Set(selection) },
+                    set: { root.fire(event: .updateSelection(selection: Array($0)), for: idPath) }
+                )) {
+                    childView(for: wrapped)
+                }
+            }
+        case let .section(header: header, content: content, footer: footer):
+            Section {
+                childView(for: content)
+            } header: {
+                childView(for: header)
+            } footer: {
+                childView(for: footer)
+            }
+        case let .lazyVStack(alignment: alignment, spacing: spacing, count: count):
+            ScrollView(.vertical) {
+                LazyVStack(alignment: .init(alignment), spacing: spacing) {
+                    ForEach(0..<count, id: \.self) { index in
+                        lazyRow(at: index)
+                    }
+                }
+            }
+        case let .lazyHStack(alignment: alignment, spacing: spacing, count: count):
+            ScrollView(.horizontal) {
+                LazyHStack(alignment: .init(alignment), spacing: spacing) {
+                    ForEach(0..<count, id: \.self) { index in
+                        lazyRow(at: index)
+                    }
+                }
+            }
+        case let .lazyList(count: count):
+            List(0..<count, id: \.self) { index in
+                lazyRow(at: index)
+            }
+        case let .grid(alignment: alignment, horizontalSpacing: horizontalSpacing, verticalSpacing: verticalSpacing, wrapped: wrapped):
+            Grid(alignment: .init(alignment), horizontalSpacing: horizontalSpacing, verticalSpacing: verticalSpacing) {
+                childView(for: wrapped)
+            }
+        case let .gridRow(alignment: alignment, wrapped: wrapped):
+            GridRow(alignment: alignment.map { .init($0) }) {
                 childView(for: wrapped)
             }
+        case let .lazyVGrid(columns: columns, alignment: alignment, spacing: spacing, count: count):
+            ScrollView(.vertical) {
+                LazyVGrid(columns: columns.map { SwiftUI.GridItem($0) }, alignment: .init(alignment), spacing: spacing) {
+                    ForEach(0..<count, id: \.self) { index in
+                        lazyRow(at: index)
+                    }
+                }
+            }
+        case let .lazyHGrid(rows: rows, alignment: alignment, spacing: spacing, count: count):
+            ScrollView(.horizontal) {
+                LazyHGrid(rows: rows.map { SwiftUI.GridItem($0) }, alignment: .init(alignment), spacing: spacing) {
+                    ForEach(0..<count, id: \.self) { index in
+                        lazyRow(at: index)
+                    }
+                }
+            }
         case let .overlay(wrapped: wrapped, alignment: alignment, overlayed: overlayed):
             childView(for: wrapped)
                 .overlay(alignment: .init(alignment)) {
@@ -126,6 +234,60 @@ struct NodeView: View {
                     }
                 }
 
+        // MARK: Presentation
+        case let .sheet(wrapped: wrapped, isPresented: isPresented, content: content):
+            childView(for: wrapped)
+                .sheet(isPresented: dismissBinding(isPresented)) {
+                    childView(for: content)
+                }
+        case let .popover(wrapped: wrapped, isPresented: isPresented, content: content):
+            childView(for: wrapped)
+                .popover(isPresented: dismissBinding(isPresented)) {
+                    childView(for: content)
+                }
+        case let .alert(wrapped: wrapped, title: title, message: message, isPresented: isPresented, actions: actions):
+            childView(for: wrapped)
+                .alert(title, isPresented: dismissBinding(isPresented)) {
+                    childView(for: actions)
+                } message: {
+                    if let message {
+                        Text(message)
+                    }
+                }
+        case let .confirmationDialog(wrapped: wrapped, title: title, message: message, isPresented: isPresented, actions: actions):
+            childView(for: wrapped)
+                .confirmationDialog(title, isPresented: dismissBinding(isPresented), titleVisibility: .visible) {
+                    childView(for: actions)
+                } message: {
+                    if let message {
+                        Text(message)
+                    }
+                }
+
+        case let .contextMenu(wrapped: wrapped, actions: actions):
+            childView(for: wrapped)
+                .contextMenu {
+                    childView(for: actions)
+                }
+        case let .swipeActions(wrapped: wrapped, actions: actions):
+            childView(for: wrapped)
+                .swipeActions {
+                    childView(for: actions)
+                }
+
+        // MARK: Toolbar
+        case let .toolbar(wrapped: wrapped, items: items):
+            childView(for: wrapped)
+                .toolbar {
+                    ForEach(toolbarItems(in: items), id: \.idPath) { item in
+                        ToolbarItem(placement: .init(item.placement)) {
+                            NodeView(node: item.node, idPath: item.idPath)
+                        }
+                    }
+                }
+        case let .toolbarItem(placement: _, wrapped: wrapped):
+            childView(for: wrapped)
+
         // MARK: Wrapper
         case let .shape(shape: shape):
             ShapeNodeView(shape: shape)
@@ -134,11 +296,61 @@ struct NodeView: View {
                 .modifier(GestureNodeViewModifier(node: gesture.value, idPath: idPath + [gesture.id]))
         case let .modified(wrapped: wrapped, modifier: modifier):
             childView(for: wrapped)
-                .modifier(ModifierNodeViewModifier(modifier: modifier))
+                .modifier(ModifierNodeViewModifier(modifier: modifier, idPath: idPath))
         }
     }
 
     private func childView(for child: Identified<Node>) -> some View {
         NodeView(node: child.value, idPath: idPath + [child.id])
     }
+
+    /// The row at the given index of a lazy stack, list or grid, which is
+    /// requested from the view once SwiftUI needs it. Rows are not part of
+    /// the node tree, so we notify them when they appear and disappear.
+    @ViewBuilder
+    private func lazyRow(at index: Int) -> some View {
+        if case let .nodes(nodes: nodes) = root.fire(event: .getRows(start: index, end: index + 1), for: idPath), let row = nodes.first {
+            childView(for: row)
+                .onAppear {
+                    root.fire(event: .appear, for: idPath + [row.id])
+                }
+                .onDisappear {
+                    root.fire(event: .disappear, for: idPath + [row.id])
+                }
+        }
+    }
+
+    /// A binding for the presentation state of a sheet, popover or dialog that
+    /// notifies the view when it is dismissed.
+    private func dismissBinding(_ isPresented: Bool) -> Binding<Bool> {
+        Binding(
+            get: { isPresented },
+            set: {
+                if !$0 {
+                    root.fire(event: .dismiss, for: idPath)
+                }
+            }
+        )
+    }
+
+    /// The (flattened) items of a toolbar along with their placements. Items
+    /// that aren't wrapped in a toolbar item are placed automatically.
+    private func toolbarItems(in items: Identified<Node>) -> [(idPath: [Id], placement: NuitBridgeSwiftUICore.ToolbarItemPlacement, node: Node)] {
+        flattenedChildren(of: items, at: idPath).map { (idPath, node) in
+            if case let .toolbarItem(placement: placement, wrapped: _) = node {
+                return (idPath: idPath, placement: placement, node: node)
+            }
+            return (idPath: idPath, placement: .automatic, node: node)
+        }
+    }
+
+    /// The given child, or its children if it is a group, along with their id
+    /// paths.
+    private func flattenedChildren(of child: Identified<Node>, at parentPath: [Id]) -> [(idPath: [Id], node: Node)] {
+        let childPath = parentPath + [child.id]
+        if case let .group(children: children) = child.value {
+            return children.flatMap { flattenedChildren(of: $0, at: childPath) }
+        }
+        return [(idPath: childPath, node: child.value)]
+    }
 }
@@ -7,6 +7,12 @@ class AppDelegate: NSObject, NSApplicationDelegate {
         NSApplication.shared.setActivationPolicy(.regular)
         NSApplication.shared.activate(ignoringOtherApps: true)
     }
+
+    func applicationWillTerminate(_ notification: Notification) {
+        // The app exits right after this, so persisted state has to be
+        // written now
+        NuitApp.application.flushPersistedState()
+    }
 }
 #endif
 
@@ -15,12 +21,35 @@ struct NuitApp: App {
     @NSApplicationDelegateAdaptor(AppDelegate.self) private var appDelegate
     #endif
 
-    static var root: Root!
+    static var application: Application!
+
+    @Environment(\.scenePhase) private var scenePhase
 
     var body: some Scene {
-        WindowGroup {
-            RootView()
-                .environmentObject(Self.root)
+        // Every window is identified by its scene and value, which also
+        // ensures that scenes with a single window only get one.
+        WindowGroup(for: WindowRequest.self) { $request in
+            WindowView(request: request)
+        } defaultValue: {
+            Self.application.launchRequest
+        }
+        .defaultSize(Self.application.scene(id: Self.application.launchRequest.sceneId)?.defaultSize.map(CGSize.init) ?? CGSize(width: 640, height: 480))
+        .commands {
+            AppCommands(menus: Self.application.info.commands)
+        }
+        .onChange(of: scenePhase) { _, newPhase in
+            // Backgrounded apps may be terminated without further notice
+            if newPhase == .background {
+                Self.application.flushPersistedState()
+            }
+        }
+
+        #if os(macOS)
+        Settings {
+            if let settingsScene = Self.application.settingsScene {
+                WindowView(request: WindowRequest(sceneId: settingsScene.id, valueJson: "null"))
+            }
         }
+        #endif
     }
 }
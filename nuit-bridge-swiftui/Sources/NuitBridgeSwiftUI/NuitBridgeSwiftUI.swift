@@ -3,13 +3,15 @@ import NuitBridgeSwiftUICore
 import Foundation
 
 @_cdecl("nuit_bridge_swiftui_run_app")
-func runApp(cRoot: UnsafePointer<CRoot>) {
-    NuitApp.root = Root(cRoot: cRoot)
+func runApp(cApp: UnsafePointer<CApp>) {
+    NuitApp.application = Application(cApp: cApp)
 
-    cRoot.pointee.set_update_callback(cRoot) { updateJsonCString in
-        let updateJson = String(cString: updateJsonCString!)
-        let update = try! JSONDecoder().decode(Update.self, from: updateJson.data(using: .utf8)!)
-        NuitApp.root.trigger(update: update)
+    cApp.pointee.set_open_window_callback(cApp) { sceneIdCString, valueJsonCString in
+        let request = WindowRequest(
+            sceneId: String(cString: sceneIdCString!),
+            valueJson: String(cString: valueJsonCString!)
+        )
+        NuitApp.application.open(window: request)
     }
 
     NuitApp.main()
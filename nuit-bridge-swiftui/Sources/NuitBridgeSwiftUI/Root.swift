@@ -4,15 +4,40 @@ import Foundation
 import Combine
 import SwiftUI
 
-/// The central state of the Nuit application.
+/// The central state of a window in the Nuit application.
 class Root: ObservableObject {
-    private let cRoot: UnsafePointer<CRoot>
+    private let cApp: UnsafePointer<CApp>
+    private let cRoot: UnsafeMutablePointer<CRoot>
 
     /// A manually installed publisher since we don't use `@Published`.
     var objectWillChange = ObservableObjectPublisher()
 
-    init(cRoot: UnsafePointer<CRoot>) {
+    init(cApp: UnsafePointer<CApp>, cRoot: UnsafeMutablePointer<CRoot>) {
+        self.cApp = cApp
         self.cRoot = cRoot
+
+        // The root outlives the callbacks, since they are dropped along with
+        // the Rust root, so we can pass it unretained.
+        let context = Unmanaged.passUnretained(self).toOpaque()
+
+        cRoot.pointee.set_update_callback(cRoot, context) { context, updateJsonCString in
+            let root = Unmanaged<Root>.fromOpaque(context!).takeUnretainedValue()
+            let updateJson = String(cString: updateJsonCString!)
+            let update = try! JSONDecoder().decode(Update.self, from: updateJson.data(using: .utf8)!)
+            root.trigger(update: update)
+        }
+
+        cRoot.pointee.set_wake_callback(cRoot, context) { context in
+            let root = Unmanaged<Root>.fromOpaque(context!).takeUnretainedValue()
+            // Tasks may be woken from any thread, but must run on the main thread
+            DispatchQueue.main.async {
+                root.runTasks()
+            }
+        }
+    }
+
+    deinit {
+        cApp.pointee.drop_root(cApp, cRoot)
     }
 
     func trigger(update: Update) {
@@ -35,7 +60,16 @@ class Root: ObservableObject {
         let idPathJson = String(data: try! encoder.encode(idPath), encoding: .utf8)!
         let eventJson = String(data: try! encoder.encode(event), encoding: .utf8)!
         let responseJson = fire(eventJson: eventJson, for: idPathJson)
-        return try! JSONDecoder().decode(EventResponse.self, from: responseJson.data(using: .utf8)!)
+        let response = try! JSONDecoder().decode(EventResponse.self, from: responseJson.data(using: .utf8)!)
+        if case let .error(message: message) = response {
+            // Events may be stale, e.g. if fired by a view that has been removed in the meantime
+            print("Warning: \(message)")
+        }
+        return response
+    }
+
+    func runTasks() {
+        cRoot.pointee.run_tasks(cRoot)
     }
 
     // MARK: JSON FFI wrappers
@@ -0,0 +1,28 @@
+import SwiftUI
+
+/// The app-level command menus, starting at the given index. Since commands
+/// cannot be built from arrays, we recurse over the menus instead.
+struct AppCommands: Commands {
+    let menus: [CommandMenuInfo]
+    var index: Int = 0
+
+    var body: some Commands {
+        if index < menus.count {
+            let menu = menus[index]
+            CommandMenu(menu.title) {
+                ForEach(Array(menu.items.enumerated()), id: \.offset) { (itemIndex, item) in
+                    switch item {
+                    case let .button(title: title, shortcut: shortcut):
+                        Button(title) {
+                            NuitApp.application.performCommand(menu: index, item: itemIndex)
+                        }
+                        .keyboardShortcut(shortcut.map { SwiftUI.KeyboardShortcut($0) })
+                    case .divider:
+                        Divider()
+                    }
+                }
+            }
+            AppCommands(menus: menus, index: index + 1)
+        }
+    }
+}
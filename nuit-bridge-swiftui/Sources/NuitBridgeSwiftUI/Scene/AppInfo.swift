@@ -0,0 +1,6 @@
+struct AppInfo: Codable, Hashable {
+    let id: String
+    let launchSceneId: String?
+    let scenes: [SceneInfo]
+    let commands: [CommandMenuInfo]
+}
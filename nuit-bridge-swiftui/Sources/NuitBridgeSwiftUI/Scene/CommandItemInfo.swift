@@ -0,0 +1,6 @@
+import NuitBridgeSwiftUICore
+
+enum CommandItemInfo: Codable, Hashable {
+    case button(title: String, shortcut: KeyboardShortcut?)
+    case divider
+}
@@ -0,0 +1,4 @@
+struct CommandMenuInfo: Codable, Hashable {
+    let title: String
+    let items: [CommandItemInfo]
+}
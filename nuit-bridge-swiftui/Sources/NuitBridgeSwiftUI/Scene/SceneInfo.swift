@@ -0,0 +1,9 @@
+import NuitBridgeSwiftUICore
+
+struct SceneInfo: Codable, Hashable {
+    let id: String
+    let kind: SceneKind
+    let title: String?
+    let defaultSize: Vec2<Double>?
+    let minSize: Vec2<Double>?
+}
@@ -0,0 +1,5 @@
+enum SceneKind: String, Codable, Hashable {
+    case windowGroup
+    case window
+    case settings
+}
@@ -0,0 +1,6 @@
+/// The value identifying a window, i.e. the scene it belongs to and the
+/// (JSON-serialized) value it presents.
+struct WindowRequest: Codable, Hashable {
+    let sceneId: String
+    let valueJson: String
+}
@@ -0,0 +1,45 @@
+import NuitBridgeSwiftUICore
+import SwiftUI
+
+/// The content of a window, rendering its own root.
+struct WindowView: View {
+    let request: WindowRequest
+
+    @State private var root: Root?
+
+    @Environment(\.openWindow) private var openWindow
+    #if os(macOS)
+    @Environment(\.openSettings) private var openSettings
+    #endif
+
+    private var scene: SceneInfo? {
+        NuitApp.application.scene(id: request.sceneId)
+    }
+
+    var body: some View {
+        Group {
+            if let root {
+                RootView()
+                    .environmentObject(root)
+            } else {
+                Color.clear
+            }
+        }
+        .navigationTitle(scene?.title ?? "")
+        .frame(
+            minWidth: scene?.minSize.map { CGFloat($0.x) },
+            minHeight: scene?.minSize.map { CGFloat($0.y) }
+        )
+        .onAppear {
+            // Roots are only created once the window appears, since SwiftUI
+            // may create the view itself several times.
+            if root == nil {
+                root = NuitApp.application.createRoot(for: request)
+            }
+            NuitApp.application.openWindowAction = { openWindow(value: $0) }
+            #if os(macOS)
+            NuitApp.application.openSettingsAction = { openSettings() }
+            #endif
+        }
+    }
+}
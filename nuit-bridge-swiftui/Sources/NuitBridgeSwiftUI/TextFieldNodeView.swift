@@ -0,0 +1,45 @@
+import NuitBridgeSwiftUICore
+import SwiftUI
+
+struct TextFieldNodeView: View {
+    let content: String
+    let prompt: String?
+    let kind: TextFieldKind
+    let keyboardType: KeyboardType
+    let isAutocorrectionDisabled: Bool
+    let idPath: [Id]
+
+    @EnvironmentObject private var root: Root
+    @FocusState private var isFocused: Bool
+
+    var body: some View {
+        field
+            .focused($isFocused)
+            .autocorrectionDisabled(isAutocorrectionDisabled)
+            #if canImport(UIKit)
+            .keyboardType(UIKeyboardType(keyboardType))
+            #endif
+            .onSubmit {
+                root.fire(event: .submit, for: idPath)
+            }
+            .onChange(of: isFocused) { _, newValue in
+                root.fire(event: .updateFocus(isFocused: newValue), for: idPath)
+            }
+    }
+
+    @ViewBuilder
+    private var field: some View {
+        let text = Binding(
+            get: { content },
+            set: { root.fire(event: .updateText(content: $0), for: idPath) }
+        )
+        switch kind {
+        case .plain:
+            TextField(prompt ?? "", text: text)
+        case .secure:
+            SecureField(prompt ?? "", text: text)
+        case .multiline:
+            TextEditor(text: text)
+        }
+    }
+}
@@ -0,0 +1,15 @@
+import SwiftUI
+
+public extension SwiftUI.AccessibilityHeadingLevel {
+    init(_ level: AccessibilityHeadingLevel) {
+        switch level {
+        case .unspecified: self = .unspecified
+        case .h1: self = .h1
+        case .h2: self = .h2
+        case .h3: self = .h3
+        case .h4: self = .h4
+        case .h5: self = .h5
+        case .h6: self = .h6
+        }
+    }
+}
@@ -0,0 +1,9 @@
+public enum AccessibilityHeadingLevel: String, Codable, Hashable {
+    case unspecified
+    case h1
+    case h2
+    case h3
+    case h4
+    case h5
+    case h6
+}
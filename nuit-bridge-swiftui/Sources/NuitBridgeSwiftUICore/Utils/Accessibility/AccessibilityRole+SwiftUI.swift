@@ -0,0 +1,18 @@
+import SwiftUI
+
+public extension AccessibilityTraits {
+    /// The traits conveying the given role. Roles without a corresponding
+    /// trait, e.g. sliders, map to no traits.
+    init(_ role: AccessibilityRole) {
+        switch role {
+        case .button: self = .isButton
+        case .link: self = .isLink
+        case .heading: self = .isHeader
+        case .image: self = .isImage
+        case .staticText: self = .isStaticText
+        case .searchField: self = .isSearchField
+        case .toggle: self = .isToggle
+        case .textField, .slider, .stepper, .progressIndicator, .picker, .list, .group: self = []
+        }
+    }
+}
@@ -0,0 +1,16 @@
+public enum AccessibilityRole: String, Codable, Hashable {
+    case button
+    case link
+    case heading
+    case image
+    case staticText
+    case textField
+    case searchField
+    case toggle
+    case slider
+    case stepper
+    case progressIndicator
+    case picker
+    case list
+    case group
+}
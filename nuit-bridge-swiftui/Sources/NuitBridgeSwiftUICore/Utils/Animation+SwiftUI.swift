@@ -11,6 +11,17 @@ public extension SwiftUI.Animation {
             case .easeOut: self = duration.map { .easeOut(duration: $0) } ?? .easeOut
             case .easeInOut: self = duration.map { .easeInOut(duration: $0) } ?? .easeInOut
             }
+        case let .timingCurve(controlPoint1: c1, controlPoint2: c2, durationSeconds: duration):
+            self = duration.map { .timingCurve(c1.x, c1.y, c2.x, c2.y, duration: $0) } ?? .timingCurve(c1.x, c1.y, c2.x, c2.y)
+        case let .spring(response: response, dampingFraction: dampingFraction):
+            self = .spring(response: response, dampingFraction: dampingFraction)
+        case let .interpolatingSpring(mass: mass, stiffness: stiffness, damping: damping, initialVelocity: initialVelocity):
+            self = .interpolatingSpring(mass: mass, stiffness: stiffness, damping: damping, initialVelocity: initialVelocity)
+        case let .repeat(wrapped: wrapped, count: count, autoreverses: autoreverses):
+            let wrapped = Self(wrapped)
+            self = count.map { wrapped.repeatCount($0, autoreverses: autoreverses) } ?? wrapped.repeatForever(autoreverses: autoreverses)
+        case let .delay(wrapped: wrapped, delaySeconds: delay):
+            self = Self(wrapped).delay(delay)
         }
     }
 }
@@ -1,8 +1,13 @@
 import Foundation
 
-public enum Animation: Codable, Hashable {
+public indirect enum Animation: Codable, Hashable {
     case `default`
     case curve(curve: Curve, durationSeconds: TimeInterval?)
+    case timingCurve(controlPoint1: Vec2<Double>, controlPoint2: Vec2<Double>, durationSeconds: TimeInterval?)
+    case spring(response: Double, dampingFraction: Double)
+    case interpolatingSpring(mass: Double, stiffness: Double, damping: Double, initialVelocity: Double)
+    case `repeat`(wrapped: Animation, count: Int?, autoreverses: Bool)
+    case delay(wrapped: Animation, delaySeconds: TimeInterval)
 
     public enum Curve: String, Codable, Hashable {
         case linear
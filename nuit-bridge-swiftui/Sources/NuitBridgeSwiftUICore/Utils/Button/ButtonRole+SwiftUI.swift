@@ -0,0 +1,10 @@
+import SwiftUI
+
+public extension SwiftUI.ButtonRole {
+    init(_ role: ButtonRole) {
+        switch role {
+        case .cancel: self = .cancel
+        case .destructive: self = .destructive
+        }
+    }
+}
@@ -0,0 +1,4 @@
+public enum ButtonRole: String, Codable, Hashable {
+    case cancel
+    case destructive
+}
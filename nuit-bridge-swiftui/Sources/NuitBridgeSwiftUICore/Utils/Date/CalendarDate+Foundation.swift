@@ -0,0 +1,17 @@
+import Foundation
+
+public extension CalendarDate {
+    /// The day of the given date in the given calendar.
+    init(_ date: Date, in calendar: Calendar = .current) {
+        let components = calendar.dateComponents([.year, .month, .day], from: date)
+        self.init(year: components.year ?? 1, month: components.month ?? 1, day: components.day ?? 1)
+    }
+}
+
+public extension Date {
+    /// The start of the given day in the given calendar.
+    init(_ date: CalendarDate, in calendar: Calendar = .current) {
+        let components = DateComponents(year: date.year, month: date.month, day: date.day)
+        self = calendar.date(from: components) ?? Date(timeIntervalSince1970: 0)
+    }
+}
@@ -0,0 +1,14 @@
+import SwiftUI
+
+public extension SwiftUI.GridItem {
+    init(_ item: GridItem) {
+        switch item.size {
+        case let .fixed(size: size):
+            self.init(.fixed(size), spacing: item.spacing)
+        case let .flexible(minimum: minimum, maximum: maximum):
+            self.init(.flexible(minimum: minimum, maximum: maximum ?? .infinity), spacing: item.spacing)
+        case let .adaptive(minimum: minimum, maximum: maximum):
+            self.init(.adaptive(minimum: minimum, maximum: maximum ?? .infinity), spacing: item.spacing)
+        }
+    }
+}
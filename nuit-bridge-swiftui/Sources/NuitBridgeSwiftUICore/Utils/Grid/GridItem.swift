@@ -0,0 +1,4 @@
+public struct GridItem: Codable, Hashable {
+    public let size: GridItemSize
+    public let spacing: Double?
+}
@@ -0,0 +1,5 @@
+public enum GridItemSize: Codable, Hashable {
+    case fixed(size: Double)
+    case flexible(minimum: Double, maximum: Double?)
+    case adaptive(minimum: Double, maximum: Double?)
+}
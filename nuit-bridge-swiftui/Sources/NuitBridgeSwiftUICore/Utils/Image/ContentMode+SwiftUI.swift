@@ -0,0 +1,10 @@
+import SwiftUI
+
+public extension SwiftUI.ContentMode {
+    init(_ contentMode: ContentMode) {
+        switch contentMode {
+        case .fit: self = .fit
+        case .fill: self = .fill
+        }
+    }
+}
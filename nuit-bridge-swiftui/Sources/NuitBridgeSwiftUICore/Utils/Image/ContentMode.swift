@@ -0,0 +1,4 @@
+public enum ContentMode: String, Codable, Hashable {
+    case fit
+    case fill
+}
@@ -0,0 +1,8 @@
+import Foundation
+
+public enum ImageSource: Codable, Hashable {
+    case file(path: String)
+    /// Encoded image data, which is sent as a base64 string.
+    case bytes(data: Data)
+    case symbol(name: String)
+}
@@ -0,0 +1,12 @@
+import SwiftUI
+
+public extension Image.Interpolation {
+    init(_ interpolation: Interpolation) {
+        switch interpolation {
+        case .none: self = .none
+        case .low: self = .low
+        case .medium: self = .medium
+        case .high: self = .high
+        }
+    }
+}
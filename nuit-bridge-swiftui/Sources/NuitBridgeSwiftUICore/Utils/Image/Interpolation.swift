@@ -0,0 +1,6 @@
+public enum Interpolation: String, Codable, Hashable {
+    case none
+    case low
+    case medium
+    case high
+}
@@ -0,0 +1,19 @@
+import SwiftUI
+
+public extension SwiftUI.EventModifiers {
+    init(_ modifiers: EventModifiers) {
+        self.init()
+        if modifiers.contains(.command) {
+            insert(.command)
+        }
+        if modifiers.contains(.shift) {
+            insert(.shift)
+        }
+        if modifiers.contains(.option) {
+            insert(.option)
+        }
+        if modifiers.contains(.control) {
+            insert(.control)
+        }
+    }
+}
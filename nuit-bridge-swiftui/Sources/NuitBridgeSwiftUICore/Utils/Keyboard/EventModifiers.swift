@@ -0,0 +1,16 @@
+public struct EventModifiers: Codable, Hashable {
+    public static let command = Self(rawValue: 1 << 0)
+    public static let shift = Self(rawValue: 1 << 1)
+    public static let option = Self(rawValue: 1 << 2)
+    public static let control = Self(rawValue: 1 << 3)
+
+    public var rawValue: UInt8
+
+    public init(rawValue: UInt8) {
+        self.rawValue = rawValue
+    }
+
+    public func contains(_ modifiers: Self) -> Bool {
+        (rawValue & modifiers.rawValue) == modifiers.rawValue
+    }
+}
@@ -0,0 +1,23 @@
+import SwiftUI
+
+public extension KeyEquivalent {
+    init(_ key: Key) {
+        switch key {
+        case let .character(character: character): self.init(character.first ?? " ")
+        case .return: self = .return
+        case .escape: self = .escape
+        case .delete: self = .delete
+        case .deleteForward: self = .deleteForward
+        case .tab: self = .tab
+        case .space: self = .space
+        case .upArrow: self = .upArrow
+        case .downArrow: self = .downArrow
+        case .leftArrow: self = .leftArrow
+        case .rightArrow: self = .rightArrow
+        case .home: self = .home
+        case .end: self = .end
+        case .pageUp: self = .pageUp
+        case .pageDown: self = .pageDown
+        }
+    }
+}
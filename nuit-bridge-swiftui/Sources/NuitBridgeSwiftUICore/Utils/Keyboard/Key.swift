@@ -0,0 +1,17 @@
+public enum Key: Codable, Hashable {
+    case character(character: String)
+    case `return`
+    case escape
+    case delete
+    case deleteForward
+    case tab
+    case space
+    case upArrow
+    case downArrow
+    case leftArrow
+    case rightArrow
+    case home
+    case end
+    case pageUp
+    case pageDown
+}
@@ -0,0 +1,7 @@
+import SwiftUI
+
+public extension SwiftUI.KeyboardShortcut {
+    init(_ shortcut: KeyboardShortcut) {
+        self.init(KeyEquivalent(shortcut.key), modifiers: SwiftUI.EventModifiers(shortcut.modifiers))
+    }
+}
@@ -0,0 +1,4 @@
+public struct KeyboardShortcut: Codable, Hashable {
+    public let key: Key
+    public let modifiers: EventModifiers
+}
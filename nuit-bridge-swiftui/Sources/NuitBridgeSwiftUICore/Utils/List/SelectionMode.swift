@@ -0,0 +1,5 @@
+public enum SelectionMode: String, Codable, Hashable {
+    case none
+    case single
+    case multiple
+}
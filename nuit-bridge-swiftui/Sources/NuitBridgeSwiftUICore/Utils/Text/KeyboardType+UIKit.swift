@@ -0,0 +1,16 @@
+#if canImport(UIKit)
+import UIKit
+
+public extension UIKeyboardType {
+    init(_ keyboardType: KeyboardType) {
+        switch keyboardType {
+        case .default: self = .default
+        case .emailAddress: self = .emailAddress
+        case .url: self = .URL
+        case .number: self = .numberPad
+        case .decimal: self = .decimalPad
+        case .phoneNumber: self = .phonePad
+        }
+    }
+}
+#endif
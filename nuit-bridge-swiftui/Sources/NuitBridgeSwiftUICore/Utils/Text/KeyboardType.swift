@@ -0,0 +1,8 @@
+public enum KeyboardType: String, Codable, Hashable {
+    case `default`
+    case emailAddress
+    case url
+    case number
+    case decimal
+    case phoneNumber
+}
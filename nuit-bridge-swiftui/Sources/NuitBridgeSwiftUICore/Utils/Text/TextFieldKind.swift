@@ -0,0 +1,5 @@
+public enum TextFieldKind: String, Codable, Hashable {
+    case plain
+    case secure
+    case multiline
+}
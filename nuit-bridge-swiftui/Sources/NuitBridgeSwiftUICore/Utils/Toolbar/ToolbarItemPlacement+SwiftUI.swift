@@ -0,0 +1,12 @@
+import SwiftUI
+
+public extension SwiftUI.ToolbarItemPlacement {
+    init(_ placement: ToolbarItemPlacement) {
+        switch placement {
+        case .automatic: self = .automatic
+        case .navigation: self = .navigation
+        case .principal: self = .principal
+        case .primaryAction: self = .primaryAction
+        }
+    }
+}
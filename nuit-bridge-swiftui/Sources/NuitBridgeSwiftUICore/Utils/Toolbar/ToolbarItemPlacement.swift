@@ -0,0 +1,6 @@
+public enum ToolbarItemPlacement: String, Codable, Hashable {
+    case automatic
+    case navigation
+    case principal
+    case primaryAction
+}
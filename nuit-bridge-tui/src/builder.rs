@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+
+use nuit_core::{AnyView, Event, EventResponse, IdPath, IdPathBuf, Identified, ModifierNode, Node, Root, TextFieldKind};
+use ratatui::style::{Style, Stylize};
+use serde_json::Value;
+
+use crate::element::{Axis, Control, Element, FocusKey};
+
+/// The number of points (as used by Nuit's spacings) per terminal row.
+const POINTS_PER_ROW: f64 = 16.0;
+/// The number of points per terminal column.
+const POINTS_PER_COLUMN: f64 = 8.0;
+/// The width of the bar drawn for progress views.
+const PROGRESS_WIDTH: u16 = 20;
+
+/// A navigation stack encountered while building, along with its path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavigationStack {
+    pub id_path: IdPathBuf,
+    /// The path binding of the stack, if it has one.
+    pub bound_path: Option<Vec<Value>>,
+    /// The number of pages pushed onto the root page.
+    pub depth: usize,
+}
+
+/// Builds the element tree for a rendered node tree. Views that are resolved
+/// through events (like navigation destinations and the rows of lazy views)
+/// are requested from the root.
+pub struct Builder<'a> {
+    root: &'a Root<AnyView>,
+    /// The pages pushed onto navigation stacks without a path binding, keyed
+    /// by the stacks' id paths.
+    pages: &'a HashMap<IdPathBuf, Vec<Value>>,
+    /// The size of the element tree that can be visible, i.e. from its
+    /// top-left corner to the bottom-right corner of the scrolled viewport.
+    /// Since every row of a lazy view takes up at least one cell, rows beyond
+    /// it are not requested.
+    visible_size: (u16, u16),
+    stacks: Vec<NavigationStack>,
+    /// The id paths of the lazy rows built so far, in order.
+    lazy_rows: Vec<IdPathBuf>,
+    enclosing_stack: Option<IdPathBuf>,
+    is_disabled: bool,
+}
+
+impl<'a> Builder<'a> {
+    pub const fn new(root: &'a Root<AnyView>, pages: &'a HashMap<IdPathBuf, Vec<Value>>, visible_size: (u16, u16)) -> Self {
+        Self {
+            root,
+            pages,
+            visible_size,
+            stacks: Vec::new(),
+            lazy_rows: Vec::new(),
+            enclosing_stack: None,
+            is_disabled: false,
+        }
+    }
+
+    /// The navigation stacks encountered so far, outermost first, and the id
+    /// paths of the lazy rows built so far.
+    pub fn into_parts(self) -> (Vec<NavigationStack>, Vec<IdPathBuf>) {
+        (self.stacks, self.lazy_rows)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    pub fn build(&mut self, node: &Node, path: &IdPath) -> Element {
+        match node {
+            Node::Empty {} | Node::GeometryReader {} | Node::Image { .. } | Node::Shape { .. } => Element::Empty,
+            Node::Text { content } => Element::Text { content: content.clone(), style: Style::new() },
+            Node::TextField { content, prompt, kind, .. } => self.control(path, Control::TextField {
+                content: content.clone(),
+                prompt: prompt.clone(),
+                is_secure: *kind == TextFieldKind::Secure,
+            }),
+            Node::Button { label, role } => self.control(path, Control::Button { label: label_text(label.value()), role: *role }),
+            Node::Picker { title, selection, content } => {
+                let mut children = vec![Element::Text { content: title.clone(), style: Style::new().bold() }];
+                for (option_path, option) in content.value().children_from(&path.child(content.id().clone())) {
+                    let Some(id) = option_path.last() else { continue };
+                    children.push(Element::Control {
+                        key: FocusKey::with_option(path.to_owned(), id.clone()),
+                        control: Control::PickerOption { label: label_text(option), is_selected: id == *selection, id },
+                        is_disabled: self.is_disabled,
+                    });
+                }
+                Element::Stack { axis: Axis::Vertical, spacing: 0, children }
+            },
+            Node::Slider { value, lower_bound, upper_bound, step } => self.control(path, Control::Slider {
+                value: *value,
+                lower_bound: *lower_bound,
+                upper_bound: *upper_bound,
+                step: *step,
+            }),
+            Node::Toggle { title, is_on } => self.control(path, Control::Toggle { title: title.clone(), is_on: *is_on }),
+            Node::Stepper { title, value, lower_bound, upper_bound, step } => self.control(path, Control::Stepper {
+                title: title.clone(),
+                value: *value,
+                lower_bound: *lower_bound,
+                upper_bound: *upper_bound,
+                step: *step,
+            }),
+            Node::ProgressView { value } => Element::Text { content: progress_bar(*value), style: Style::new() },
+            Node::DatePicker { title, date } => Element::Text { content: format!("{title}: {date}"), style: Style::new() },
+            Node::Child { wrapped }
+            | Node::Editable { wrapped, .. }
+            | Node::NavigationDestination { wrapped }
+            | Node::ContextMenu { wrapped, .. }
+            | Node::SwipeActions { wrapped, .. }
+            | Node::ToolbarItem { wrapped, .. }
+            | Node::Gestured { wrapped, .. } => self.build_child(wrapped, path),
+            Node::Group { children } => Element::Stack {
+                axis: Axis::Vertical,
+                spacing: 0,
+                children: children.iter().map(|child| self.build_child(child, path)).collect(),
+            },
+            Node::VStack { spacing, wrapped, .. } | Node::Grid { vertical_spacing: spacing, wrapped, .. } => self.build_stack(Axis::Vertical, rows(*spacing), wrapped, path),
+            Node::HStack { spacing, wrapped, .. } => self.build_stack(Axis::Horizontal, columns(*spacing), wrapped, path),
+            Node::GridRow { wrapped, .. } => self.build_stack(Axis::Horizontal, 1, wrapped, path),
+            Node::ZStack { wrapped, .. } => self.build_stack(Axis::Depth, 0, wrapped, path),
+            Node::Overlay { wrapped, overlayed, .. } => Element::Stack {
+                axis: Axis::Depth,
+                spacing: 0,
+                children: vec![self.build_child(wrapped, path), self.build_child(overlayed, path)],
+            },
+            Node::List { wrapped, .. } => Element::Block { title: None, child: Box::new(self.build_stack(Axis::Vertical, 0, wrapped, path)) },
+            Node::Section { header, content, footer } => {
+                let mut children = Vec::new();
+                if !matches!(header.value(), Node::Empty {}) {
+                    children.push(self.build_child(header, path).styled(Style::new().bold()));
+                }
+                children.push(self.build_stack(Axis::Vertical, 0, content, path));
+                if !matches!(footer.value(), Node::Empty {}) {
+                    children.push(self.build_child(footer, path).styled(Style::new().dim()));
+                }
+                Element::Stack { axis: Axis::Vertical, spacing: 0, children }
+            },
+            Node::LazyVStack { spacing, count, .. } | Node::LazyVGrid { spacing, count, .. } => self.build_lazy_stack(Axis::Vertical, rows(*spacing), *count, path),
+            Node::LazyHStack { spacing, count, .. } | Node::LazyHGrid { spacing, count, .. } => self.build_lazy_stack(Axis::Horizontal, columns(*spacing), *count, path),
+            Node::LazyList { count } => Element::Block { title: None, child: Box::new(self.build_lazy_stack(Axis::Vertical, 0, *count, path)) },
+            Node::NavigationStack { path: bound_path, wrapped } => self.build_navigation_stack(node, bound_path.as_deref(), wrapped, path),
+            Node::NavigationSplitView { sidebar, content, detail } => {
+                let children = [sidebar, content, detail].into_iter()
+                    .filter(|column| !matches!(column.value(), Node::Empty {}))
+                    .map(|column| {
+                        let title = Some(navigation_title(column.value())).filter(|title| !title.is_empty());
+                        Element::Block { title, child: Box::new(self.build_child(column, path)) }
+                    })
+                    .collect();
+                Element::Stack { axis: Axis::Horizontal, spacing: 1, children }
+            },
+            Node::NavigationLink { label, value } => self.control(path, Control::NavigationLink {
+                label: label_text(label.value()),
+                stack: self.enclosing_stack.clone(),
+                value: value.clone(),
+            }),
+            Node::Sheet { wrapped, is_presented, content } | Node::Popover { wrapped, is_presented, content } => {
+                let wrapped = self.build_child(wrapped, path);
+                if *is_presented {
+                    let content = Element::Block { title: None, child: Box::new(self.build_child(content, path)) };
+                    Element::Stack { axis: Axis::Vertical, spacing: 1, children: vec![wrapped, content] }
+                } else {
+                    wrapped
+                }
+            },
+            Node::Alert { wrapped, title, message, is_presented, actions } | Node::ConfirmationDialog { wrapped, title, message, is_presented, actions } => {
+                let wrapped = self.build_child(wrapped, path);
+                if *is_presented {
+                    let mut children = Vec::new();
+                    if let Some(message) = message {
+                        children.push(Element::Text { content: message.clone(), style: Style::new() });
+                    }
+                    children.push(self.build_stack(Axis::Horizontal, 1, actions, path));
+                    let dialog = Element::Block {
+                        title: Some(title.clone()),
+                        child: Box::new(Element::Stack { axis: Axis::Vertical, spacing: 1, children }),
+                    };
+                    Element::Stack { axis: Axis::Vertical, spacing: 1, children: vec![wrapped, dialog] }
+                } else {
+                    wrapped
+                }
+            },
+            Node::Toolbar { wrapped, items } => Element::Stack {
+                axis: Axis::Vertical,
+                spacing: 1,
+                children: vec![self.build_stack(Axis::Horizontal, 1, items, path), self.build_child(wrapped, path)],
+            },
+            Node::Modified { wrapped, modifier } => match modifier {
+                ModifierNode::Hidden { is_hidden } | ModifierNode::AccessibilityHidden { is_hidden } if *is_hidden => Element::Empty,
+                ModifierNode::Disabled { is_disabled } => {
+                    let was_disabled = self.is_disabled;
+                    self.is_disabled |= *is_disabled;
+                    let element = self.build_child(wrapped, path);
+                    self.is_disabled = was_disabled;
+                    element
+                },
+                _ => self.build_child(wrapped, path),
+            },
+        }
+    }
+
+    fn build_child(&mut self, child: &Identified<Node>, path: &IdPath) -> Element {
+        self.build(child.value(), &path.child(child.id().clone()))
+    }
+
+    /// Builds a stack from the flattened children of the given node.
+    fn build_stack(&mut self, axis: Axis, spacing: u16, wrapped: &Identified<Node>, path: &IdPath) -> Element {
+        let children = wrapped.value().children_from(&path.child(wrapped.id().clone()))
+            .into_iter()
+            .map(|(child_path, child)| self.build(child, &child_path))
+            .collect();
+        Element::Stack { axis, spacing, children }
+    }
+
+    /// Builds a stack from the rows of the lazy view at the given path. Since
+    /// the terminal scrolls the entire view, we request the leading rows that
+    /// can be visible, plus one more in vertical stacks, so that moving the
+    /// focus down scrolls into it and thereby requests more.
+    fn build_lazy_stack(&mut self, axis: Axis, spacing: u16, count: usize, path: &IdPath) -> Element {
+        let (visible_width, visible_height) = self.visible_size;
+        let visible_count = match axis {
+            Axis::Horizontal => usize::from(visible_width),
+            Axis::Vertical | Axis::Depth => usize::from(visible_height) + 1,
+        };
+        let end = count.min(visible_count);
+        let rows = match self.fire(path, &Event::GetRows { start: 0, end }) {
+            EventResponse::Nodes { nodes } => nodes,
+            EventResponse::Empty {} | EventResponse::Node { .. } | EventResponse::Error { .. } => Vec::new(),
+        };
+        let children = rows.iter()
+            .map(|row| {
+                self.lazy_rows.push(path.child(row.id().clone()));
+                self.build_child(row, path)
+            })
+            .collect();
+        Element::Stack { axis, spacing, children }
+    }
+
+    /// Builds the topmost page of the given navigation stack, i.e. the
+    /// destination of the last value on its path or otherwise its root view.
+    fn build_navigation_stack(&mut self, node: &Node, bound_path: Option<&[Value]>, wrapped: &Identified<Node>, path: &IdPath) -> Element {
+        let values = bound_path.or_else(|| self.pages.get(path).map(Vec::as_slice)).unwrap_or_default();
+        let enclosing_stack = self.enclosing_stack.replace(path.to_owned());
+        let destination = values.last().and_then(|value| self.resolve_navigation_destination(node, value, path));
+        let (title, child) = match destination {
+            Some((destination_path, destination)) => (navigation_title(&destination), self.build(&destination, &destination_path)),
+            None => (navigation_title(wrapped.value()), self.build_child(wrapped, path)),
+        };
+        self.enclosing_stack = enclosing_stack;
+
+        let depth = values.len();
+        self.stacks.push(NavigationStack { id_path: path.to_owned(), bound_path: bound_path.map(<[Value]>::to_vec), depth });
+        Element::Page { title, can_go_back: depth > 0, child: Box::new(child) }
+    }
+
+    /// Resolves the destination view for the given value through the first
+    /// navigation destination in the given navigation stack, returning it
+    /// along with its id path.
+    fn resolve_navigation_destination(&self, node: &Node, value: &Value, path: &IdPath) -> Option<(IdPathBuf, Node)> {
+        let destination_path = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::NavigationDestination { .. }))
+            .map(|(destination_path, _)| path.join(&destination_path))?;
+        match self.fire(&destination_path, &Event::GetNavigationDestination { value: value.clone() }) {
+            EventResponse::Node { node: destination } => Some((
+                destination_path.child(destination.id().clone()),
+                destination.value().clone(),
+            )),
+            EventResponse::Empty {} | EventResponse::Nodes { .. } | EventResponse::Error { .. } => None,
+        }
+    }
+
+    fn control(&self, path: &IdPath, control: Control) -> Element {
+        Element::Control { key: FocusKey::new(path.to_owned()), control, is_disabled: self.is_disabled }
+    }
+
+    fn fire(&self, path: &IdPath, event: &Event) -> EventResponse {
+        // Events may be stale, which we don't consider fatal
+        self.root.fire_event(path, event).unwrap_or_default()
+    }
+}
+
+/// Converts the given spacing to terminal rows.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn rows(spacing: f64) -> u16 {
+    (spacing / POINTS_PER_ROW).round() as u16
+}
+
+/// Converts the given spacing to terminal columns.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn columns(spacing: f64) -> u16 {
+    (spacing / POINTS_PER_COLUMN).round() as u16
+}
+
+/// The texts in the given node joined by spaces, e.g. the label of a button.
+fn label_text(node: &Node) -> String {
+    node.descendants()
+        .into_iter()
+        .filter_map(|(_, node)| match node {
+            Node::Text { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A textual progress bar for the given fraction, which is indeterminate if
+/// none.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn progress_bar(value: Option<f64>) -> String {
+    match value {
+        Some(value) => {
+            let filled = (value.clamp(0.0, 1.0) * f64::from(PROGRESS_WIDTH)).round() as usize;
+            let empty = usize::from(PROGRESS_WIDTH).saturating_sub(filled);
+            format!("{}{} {}%", "█".repeat(filled), "░".repeat(empty), (value * 100.0).round())
+        },
+        None => "…".to_owned(),
+    }
+}
+
+/// The navigation title set on the given node, if any.
+fn navigation_title(node: &Node) -> String {
+    node.descendants()
+        .into_iter()
+        .find_map(|(_, node)| match node {
+            Node::Modified { modifier: ModifierNode::NavigationTitle { title }, .. } => Some(title.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use nuit_core::{Access, AnyView, Button, IdPath, LazyVStack, Root, State, Text, Toggle, View, ViewExt, VStack};
+    use nuit_derive::Bind;
+    use ratatui::style::{Style, Stylize};
+
+    use crate::element::{Axis, Control, Element};
+
+    use super::Builder;
+
+    #[derive(Bind, Default)]
+    struct SettingsView {
+        is_on: State<bool>,
+    }
+
+    impl View for SettingsView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            VStack::from((
+                Text::new("Settings"),
+                Toggle::new("Dark mode", self.is_on.binding()),
+                Button::with_text("Delete", || {}).disabled(true),
+                LazyVStack::new(100, |i| Text::new(format!("Row {i}"))),
+            ))
+        }
+    }
+
+    #[test]
+    fn build() {
+        let root = Root::new(AnyView::new(SettingsView::default()));
+        let node = root.render();
+        let pages = HashMap::new();
+        let mut builder = Builder::new(&root, &pages, (40, 9));
+        let element = builder.build(&node, IdPath::root());
+        let (stacks, lazy_rows) = builder.into_parts();
+        assert!(stacks.is_empty());
+
+        let Element::Stack { axis: Axis::Vertical, children, .. } = &element else { panic!("Unexpected element {element:?}") };
+        let [title, toggle, button, lazy_stack] = children.as_slice() else { panic!("Unexpected children {children:?}") };
+        assert_eq!(*title, Element::Text { content: "Settings".to_owned(), style: Style::new() });
+        assert!(matches!(toggle, Element::Control { control: Control::Toggle { title, is_on: false }, is_disabled: false, .. } if title == "Dark mode"));
+        assert!(matches!(button, Element::Control { control: Control::Button { label, .. }, is_disabled: true, .. } if label == "Delete"));
+        assert_eq!(element.focusables().len(), 1);
+
+        // Only the rows that can be visible (plus one) are requested
+        let Element::Stack { axis: Axis::Vertical, children: rows, .. } = lazy_stack else { panic!("Unexpected element {lazy_stack:?}") };
+        assert_eq!(rows.len(), 10);
+        assert_eq!(rows[9], Element::Text { content: "Row 9".to_owned(), style: Style::new() });
+        assert_eq!(lazy_rows.len(), 10);
+
+        // Styles only apply to texts
+        let styled = title.clone().styled(Style::new().bold());
+        assert_eq!(styled, Element::Text { content: "Settings".to_owned(), style: Style::new().bold() });
+    }
+}
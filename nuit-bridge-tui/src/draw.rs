@@ -0,0 +1,212 @@
+use nuit_core::ButtonRole;
+use ratatui::{buffer::Buffer, layout::{Position, Rect}, style::{Style, Stylize}, text::Span, widgets::{Block, Widget}};
+
+use crate::element::{Axis, Control, Element, FocusKey};
+
+/// The width of text fields.
+const FIELD_WIDTH: u16 = 24;
+/// The width of the gauge drawn for sliders.
+const GAUGE_WIDTH: u16 = 20;
+
+/// The state shared while drawing an element tree.
+pub struct DrawContext<'a> {
+    focus: Option<&'a FocusKey>,
+    /// The cursor position (in characters) within the focused text field.
+    cursor: usize,
+    /// The area of the focused control, once drawn.
+    pub focused_area: Option<Rect>,
+    /// The position of the terminal cursor, if a text field is focused.
+    pub cursor_position: Option<Position>,
+}
+
+impl<'a> DrawContext<'a> {
+    pub const fn new(focus: Option<&'a FocusKey>, cursor: usize) -> Self {
+        Self { focus, cursor, focused_area: None, cursor_position: None }
+    }
+}
+
+impl Element {
+    /// The natural size of this element in cells, as (width, height).
+    pub fn size(&self) -> (u16, u16) {
+        match self {
+            Self::Empty => (0, 0),
+            Self::Text { content, .. } => {
+                let width = content.lines().map(width_of).max().unwrap_or_default();
+                (width, u16::try_from(content.lines().count()).unwrap_or(u16::MAX).max(1))
+            },
+            Self::Control { control, .. } => (width_of(&control_line(control, false).content), 1),
+            Self::Stack { axis, spacing, children } => {
+                let sizes: Vec<_> = children.iter().map(Self::size).filter(|&size| size != (0, 0)).collect();
+                let gaps = spacing.saturating_mul(u16::try_from(sizes.len().saturating_sub(1)).unwrap_or(u16::MAX));
+                let widths = sizes.iter().map(|&(width, _)| width);
+                let heights = sizes.iter().map(|&(_, height)| height);
+                match axis {
+                    Axis::Horizontal => (widths.fold(gaps, u16::saturating_add), heights.max().unwrap_or_default()),
+                    Axis::Vertical => (widths.max().unwrap_or_default(), heights.fold(gaps, u16::saturating_add)),
+                    Axis::Depth => (widths.max().unwrap_or_default(), heights.max().unwrap_or_default()),
+                }
+            },
+            Self::Block { title, child } => {
+                let (width, height) = child.size();
+                let title_width = title.as_deref().map(width_of).unwrap_or_default();
+                (width.max(title_width).saturating_add(2), height.saturating_add(2))
+            },
+            Self::Page { title, can_go_back, child } => {
+                let (width, height) = child.size();
+                if has_title_bar(title, *can_go_back) {
+                    (width.max(width_of(&title_bar(title, *can_go_back))), height.saturating_add(2))
+                } else {
+                    (width, height)
+                }
+            },
+        }
+    }
+
+    /// Draws this element into the given area of the buffer, which must lie
+    /// within the buffer.
+    pub fn draw(&self, area: Rect, buffer: &mut Buffer, context: &mut DrawContext) {
+        if area.is_empty() {
+            return;
+        }
+        match self {
+            Self::Empty => {},
+            Self::Text { content, style } => {
+                for (line, y) in content.lines().zip(area.top()..area.bottom()) {
+                    buffer.set_stringn(area.x, y, line, usize::from(area.width), *style);
+                }
+            },
+            Self::Control { key, control, is_disabled } => {
+                let is_focused = context.focus == Some(key);
+                let span = control_line(control, is_focused);
+                let style = if *is_disabled { span.style.dim() } else { span.style };
+                buffer.set_stringn(area.x, area.y, &span.content, usize::from(area.width), style);
+                if is_focused {
+                    context.focused_area = Some(Rect { height: 1, ..area });
+                    if let Control::TextField { content, .. } = control {
+                        // Account for contents that are scrolled to show their end
+                        let count = content.chars().count();
+                        let hidden = count.saturating_sub(usize::from(FIELD_WIDTH - 1));
+                        let cursor = u16::try_from(context.cursor.min(count).saturating_sub(hidden)).unwrap_or(u16::MAX);
+                        let x = area.x.saturating_add(1).saturating_add(cursor.min(FIELD_WIDTH - 1));
+                        context.cursor_position = Some(Position::new(x.min(area.right() - 1), area.y));
+                    }
+                }
+            },
+            Self::Stack { axis, spacing, children } => {
+                let mut remaining = area;
+                for child in children {
+                    let (width, height) = child.size();
+                    if (width, height) == (0, 0) {
+                        continue;
+                    }
+                    match axis {
+                        Axis::Horizontal => {
+                            let width = width.min(remaining.width);
+                            child.draw(Rect { width, ..remaining }, buffer, context);
+                            let advance = width.saturating_add(*spacing).min(remaining.width);
+                            remaining = Rect { x: remaining.x + advance, width: remaining.width - advance, ..remaining };
+                        },
+                        Axis::Vertical => {
+                            let height = height.min(remaining.height);
+                            child.draw(Rect { height, ..remaining }, buffer, context);
+                            let advance = height.saturating_add(*spacing).min(remaining.height);
+                            remaining = Rect { y: remaining.y + advance, height: remaining.height - advance, ..remaining };
+                        },
+                        Axis::Depth => child.draw(area, buffer, context),
+                    }
+                }
+            },
+            Self::Block { title, child } => {
+                let (_, height) = self.size();
+                let area = Rect { height: height.min(area.height), ..area };
+                let mut block = Block::bordered();
+                if let Some(title) = title {
+                    block = block.title(title.as_str());
+                }
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                child.draw(inner, buffer, context);
+            },
+            Self::Page { title, can_go_back, child } => {
+                if has_title_bar(title, *can_go_back) {
+                    buffer.set_stringn(area.x, area.y, title_bar(title, *can_go_back), usize::from(area.width), Style::new().bold());
+                    if area.height > 1 {
+                        buffer.set_stringn(area.x, area.y + 1, "─".repeat(usize::from(area.width)), usize::from(area.width), Style::new().dim());
+                    }
+                    let offset = area.height.min(2);
+                    child.draw(Rect { y: area.y + offset, height: area.height - offset, ..area }, buffer, context);
+                } else {
+                    child.draw(area, buffer, context);
+                }
+            },
+        }
+    }
+}
+
+/// The single line presenting the given control.
+fn control_line(control: &Control, is_focused: bool) -> Span<'static> {
+    let span = match control {
+        Control::Button { label, role } => {
+            let span = Span::raw(format!("[ {label} ]"));
+            match role {
+                Some(ButtonRole::Destructive) => span.red(),
+                _ => span,
+            }
+        },
+        Control::NavigationLink { label, .. } => Span::raw(format!("{label} ›")),
+        Control::TextField { content, prompt, is_secure } => {
+            let width = usize::from(FIELD_WIDTH);
+            let (text, style) = if content.is_empty() {
+                (prompt.clone().unwrap_or_default(), Style::new().dim())
+            } else if *is_secure {
+                ("•".repeat(content.chars().count()), Style::new())
+            } else {
+                (content.clone(), Style::new())
+            };
+            // Long contents are scrolled to show their end
+            let visible: String = text.chars().skip(text.chars().count().saturating_sub(width - 1)).collect();
+            Span::styled(format!("▏{visible:<width$}▕", width = width - 1), style.underlined())
+        },
+        Control::Slider { value, lower_bound, upper_bound, .. } => {
+            let fraction = if upper_bound > lower_bound { ((value - lower_bound) / (upper_bound - lower_bound)).clamp(0.0, 1.0) } else { 0.0 };
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let filled = (fraction * f64::from(GAUGE_WIDTH)).round() as usize;
+            let empty = usize::from(GAUGE_WIDTH).saturating_sub(filled);
+            Span::raw(format!("◀ {}{} ▶ {}", "█".repeat(filled), "░".repeat(empty), format_number(*value)))
+        },
+        Control::Stepper { title, value, .. } => Span::raw(format!("{title} ◀ {value} ▶")),
+        Control::Toggle { title, is_on } => Span::raw(format!("[{}] {title}", if *is_on { "x" } else { " " })),
+        Control::PickerOption { label, is_selected, .. } => Span::raw(format!("({}) {label}", if *is_selected { "•" } else { " " })),
+    };
+    if is_focused {
+        span.reversed()
+    } else {
+        span
+    }
+}
+
+const fn has_title_bar(title: &str, can_go_back: bool) -> bool {
+    !title.is_empty() || can_go_back
+}
+
+fn title_bar(title: &str, can_go_back: bool) -> String {
+    if can_go_back {
+        format!("‹ Esc  {title}")
+    } else {
+        title.to_owned()
+    }
+}
+
+/// Formats the given number without decimals if it is integral.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+/// The width of the given string in cells.
+fn width_of(string: &str) -> u16 {
+    u16::try_from(Span::raw(string).width()).unwrap_or(u16::MAX)
+}
@@ -0,0 +1,93 @@
+use nuit_core::{ButtonRole, Id, IdPathBuf};
+use ratatui::style::Style;
+use serde_json::Value;
+
+/// Identifies a focusable control. Pickers present several controls (one per
+/// option), which are told apart by the option ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusKey {
+    pub path: IdPathBuf,
+    pub option: Option<Id>,
+}
+
+impl FocusKey {
+    pub const fn new(path: IdPathBuf) -> Self {
+        Self { path, option: None }
+    }
+
+    pub const fn with_option(path: IdPathBuf, option: Id) -> Self {
+        Self { path, option: Some(option) }
+    }
+}
+
+/// The direction along which a stack lays out its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+    /// The children are drawn on top of each other.
+    Depth,
+}
+
+/// An element of the text UI, which is built from a rendered node tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+    Empty,
+    Text { content: String, style: Style },
+    Control { key: FocusKey, control: Control, is_disabled: bool },
+    Stack { axis: Axis, spacing: u16, children: Vec<Self> },
+    /// A bordered box, e.g. around a list.
+    Block { title: Option<String>, child: Box<Self> },
+    /// The topmost page of a navigation stack, with a title bar above it.
+    Page { title: String, can_go_back: bool, child: Box<Self> },
+}
+
+/// An interactive element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Control {
+    Button { label: String, role: Option<ButtonRole> },
+    /// A link pushing the given value onto the navigation stack at the given
+    /// id path, if it is inside one.
+    NavigationLink { label: String, stack: Option<IdPathBuf>, value: Value },
+    TextField { content: String, prompt: Option<String>, is_secure: bool },
+    Slider { value: f64, lower_bound: f64, upper_bound: f64, step: Option<f64> },
+    Stepper { title: String, value: i64, lower_bound: i64, upper_bound: i64, step: i64 },
+    Toggle { title: String, is_on: bool },
+    PickerOption { label: String, id: Id, is_selected: bool },
+}
+
+impl Element {
+    /// The keys of the focusable controls in this element, in focus order.
+    pub fn focusables(&self) -> Vec<&FocusKey> {
+        match self {
+            Self::Empty | Self::Text { .. } => Vec::new(),
+            Self::Control { key, is_disabled, .. } => if *is_disabled { Vec::new() } else { vec![key] },
+            Self::Stack { children, .. } => children.iter().flat_map(Self::focusables).collect(),
+            Self::Block { child, .. } | Self::Page { child, .. } => child.focusables(),
+        }
+    }
+
+    /// The control with the given key.
+    pub fn control(&self, key: &FocusKey) -> Option<&Control> {
+        match self {
+            Self::Empty | Self::Text { .. } => None,
+            Self::Control { key: control_key, control, .. } => (control_key == key).then_some(control),
+            Self::Stack { children, .. } => children.iter().find_map(|child| child.control(key)),
+            Self::Block { child, .. } | Self::Page { child, .. } => child.control(key),
+        }
+    }
+
+    /// Applies the given style to the texts in this element.
+    #[must_use]
+    pub fn styled(self, style: Style) -> Self {
+        match self {
+            Self::Text { content, style: text_style } => Self::Text { content, style: text_style.patch(style) },
+            Self::Stack { axis, spacing, children } => Self::Stack {
+                axis,
+                spacing,
+                children: children.into_iter().map(|child| child.styled(style)).collect(),
+            },
+            _ => self,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg_attr(test, feature(impl_trait_in_assoc_type))]
+
+mod builder;
+mod draw;
+mod element;
+mod tui;
+
+use nuit_core::{App, OpenWindow};
+use serde_json::Value;
+use tui::Tui;
+
+// Make nuit-derive's derive macros (which refer to `::nuit`) usable in our own
+// tests, analogous to the alias in nuit-core.
+#[cfg(test)]
+extern crate nuit_core as nuit;
+
+/// Runs the given app in the terminal. The launch scene is presented as a
+/// text UI that is navigated with the keyboard.
+///
+/// # Panics
+///
+/// Panics if the launch scene cannot be opened or the terminal cannot be
+/// drawn to.
+#[allow(clippy::needless_pass_by_value)]
+pub fn run_app(app: App) {
+    let Some(scene) = app.launch_scene() else {
+        eprintln!("Warning: The app has no scene to open on launch");
+        return;
+    };
+
+    let open_window = OpenWindow::new(|id, _value| {
+        eprintln!("Warning: Cannot open a window for scene {id}, since the terminal backend only presents the launch scene");
+    });
+    let root = app.make_root(scene.info().id(), Value::Null, open_window)
+        .unwrap_or_else(|error| panic!("Could not open launch scene: {error}"));
+
+    let mut terminal = ratatui::init();
+    let result = Tui::new(root).run(&mut terminal);
+    ratatui::restore();
+    app.flush_persisted_state();
+    if let Err(error) = result {
+        panic!("Could not run terminal UI: {error}");
+    }
+}
@@ -0,0 +1,515 @@
+use std::{cell::Cell, collections::HashMap, io, mem, rc::Rc, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::Duration};
+
+use nuit_core::{AnyView, Event, IdPath, IdPathBuf, Node, Root};
+use ratatui::{buffer::Buffer, crossterm::event::{self, KeyCode, KeyEvent, KeyEventKind, KeyModifiers}, layout::Rect, style::{Style, Stylize}, DefaultTerminal, Frame};
+use serde_json::Value;
+
+use crate::{builder::{Builder, NavigationStack}, draw::DrawContext, element::{Control, Element, FocusKey}};
+
+/// How long to wait for terminal events before checking for state changes
+/// and tasks.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const KEY_HINTS: &str = "Tab/↑↓: focus  Enter: activate  ←→: adjust  Esc: back  Ctrl+C: quit";
+
+/// Presents a root in the terminal and handles keyboard input.
+pub struct Tui {
+    root: Root<AnyView>,
+    node: Node,
+    element: Element,
+    stacks: Vec<NavigationStack>,
+    /// The pages pushed onto navigation stacks without a path binding, keyed
+    /// by the stacks' id paths.
+    pages: HashMap<IdPathBuf, Vec<Value>>,
+    focus: Option<FocusKey>,
+    /// The cursor position (in characters) within the focused text field,
+    /// which is clamped to the end of its content.
+    cursor: usize,
+    /// The first row of the element tree that is shown.
+    scroll: u16,
+    /// The size of the element tree that was visible when it was last drawn,
+    /// which limits the rows requested from lazy views.
+    visible_size: (u16, u16),
+    /// The id paths of the lazy rows in the element tree, which are notified
+    /// when they are added and removed.
+    lazy_rows: Vec<IdPathBuf>,
+    /// The last warning, which is shown in the status line since printing it
+    /// would garble the terminal.
+    warning: Option<String>,
+    needs_render: Rc<Cell<bool>>,
+    needs_build: bool,
+    needs_tasks: Arc<AtomicBool>,
+    is_running: bool,
+}
+
+impl Tui {
+    pub fn new(root: Root<AnyView>) -> Self {
+        let needs_render = Rc::new(Cell::new(true));
+        let needs_tasks = Arc::new(AtomicBool::new(true));
+        root.set_update_callback({
+            let needs_render = needs_render.clone();
+            move |_update| needs_render.set(true)
+        });
+        root.set_wake_callback({
+            let needs_tasks = needs_tasks.clone();
+            move || needs_tasks.store(true, Ordering::Release)
+        });
+
+        Self {
+            root,
+            node: Node::Empty {},
+            element: Element::Empty,
+            stacks: Vec::new(),
+            pages: HashMap::new(),
+            focus: None,
+            cursor: usize::MAX,
+            scroll: 0,
+            visible_size: (0, 0),
+            lazy_rows: Vec::new(),
+            warning: None,
+            needs_render,
+            needs_build: true,
+            needs_tasks,
+            is_running: true,
+        }
+    }
+
+    /// Draws the root and handles input until the user quits.
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while self.is_running {
+            self.update();
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(POLL_INTERVAL)? {
+                continue;
+            }
+            if let event::Event::Key(key @ KeyEvent { kind: KeyEventKind::Press, .. }) = event::read()? {
+                self.handle_key(key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs pending tasks and renders and builds the element tree again if
+    /// needed.
+    fn update(&mut self) {
+        if self.needs_tasks.swap(false, Ordering::AcqRel) {
+            self.root.run_tasks();
+        }
+        if self.needs_render.replace(false) {
+            self.node = self.root.render();
+            self.needs_build = true;
+        }
+        if self.needs_build {
+            self.build();
+        }
+    }
+
+    /// Rebuilds the element tree, keeping the focus if the focused control
+    /// still exists, and notifies the lazy rows that were added or removed.
+    fn build(&mut self) {
+        let mut builder = Builder::new(&self.root, &self.pages, self.visible_size);
+        self.element = builder.build(&self.node, IdPath::root());
+        let (stacks, lazy_rows) = builder.into_parts();
+        self.stacks = stacks;
+        self.needs_build = false;
+
+        let previous_lazy_rows = mem::replace(&mut self.lazy_rows, lazy_rows.clone());
+        for row_path in previous_lazy_rows.iter().filter(|&row_path| !lazy_rows.contains(row_path)) {
+            // The lazy view may be gone entirely (e.g. after navigating), in
+            // which case its rows are gone with it
+            let _ = self.root.fire_event(row_path, &Event::Disappear {});
+        }
+        for row_path in lazy_rows.iter().filter(|&row_path| !previous_lazy_rows.contains(row_path)) {
+            self.fire(row_path, &Event::Appear {});
+        }
+
+        let focusables = self.element.focusables();
+        if !self.focus.as_ref().is_some_and(|focus| focusables.contains(&focus)) {
+            self.focus = focusables.first().map(|&key| key.clone());
+            self.cursor = usize::MAX;
+        }
+    }
+
+    /// Draws the element tree, scrolled to show the focused control, along
+    /// with a status line.
+    fn draw(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let viewport = Rect { height: area.height.saturating_sub(1), ..area };
+        let (_, height) = self.element.size();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, area.width, height.max(viewport.height)));
+        let mut context = DrawContext::new(self.focus.as_ref(), self.cursor);
+        self.element.draw(buffer.area, &mut buffer, &mut context);
+
+        if let Some(focused_area) = context.focused_area {
+            if focused_area.y < self.scroll {
+                self.scroll = focused_area.y;
+            } else if focused_area.bottom() > self.scroll + viewport.height {
+                self.scroll = focused_area.bottom() - viewport.height;
+            }
+        }
+        self.scroll = self.scroll.min(buffer.area.height - viewport.height);
+
+        // Scrolling further (or resizing the terminal) may reveal more rows of
+        // lazy views, which we request by building again
+        let visible_size = (viewport.width, self.scroll.saturating_add(viewport.height));
+        if visible_size != self.visible_size {
+            self.visible_size = visible_size;
+            self.needs_build = true;
+        }
+
+        let frame_buffer = frame.buffer_mut();
+        for y in 0..viewport.height {
+            for x in 0..viewport.width {
+                if let (Some(cell), Some(frame_cell)) = (buffer.cell((x, y + self.scroll)), frame_buffer.cell_mut((x, y))) {
+                    *frame_cell = cell.clone();
+                }
+            }
+        }
+
+        let status = self.warning.as_deref().unwrap_or(KEY_HINTS);
+        let status_style = if self.warning.is_some() { Style::new().yellow() } else { Style::new().dim() };
+        frame_buffer.set_stringn(0, viewport.bottom(), status, usize::from(area.width), status_style);
+
+        let visible_rows = self.scroll..self.scroll + viewport.height;
+        if let Some(mut position) = context.cursor_position.filter(|position| visible_rows.contains(&position.y)) {
+            position.y -= self.scroll;
+            frame.set_cursor_position(position);
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        self.warning = None;
+        if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('c' | 'q')) {
+            self.is_running = false;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.move_focus(1),
+            KeyCode::BackTab | KeyCode::Up => self.move_focus(-1),
+            KeyCode::Esc => self.navigate_back(),
+            _ => {
+                let Some(focus) = self.focus.clone() else { return };
+                let Some(control) = self.element.control(&focus).cloned() else { return };
+                self.handle_control_key(&focus, control, key.code);
+            },
+        }
+    }
+
+    /// Handles a key pressed while the given control is focused.
+    fn handle_control_key(&mut self, focus: &FocusKey, control: Control, code: KeyCode) {
+        let path = &focus.path;
+        match (control, code) {
+            (Control::TextField { .. }, KeyCode::Enter) => self.fire(path, &Event::Submit {}),
+            (Control::TextField { content, .. }, code) => self.edit_text(path, &content, code),
+            (Control::Button { .. }, KeyCode::Enter | KeyCode::Char(' ')) => self.fire(path, &Event::ButtonTap {}),
+            (Control::NavigationLink { stack, value, .. }, KeyCode::Enter | KeyCode::Char(' ')) => self.follow_navigation_link(stack.as_deref(), value),
+            (Control::Toggle { is_on, .. }, KeyCode::Enter | KeyCode::Char(' ')) => self.fire(path, &Event::UpdateToggle { is_on: !is_on }),
+            (Control::PickerOption { id, .. }, KeyCode::Enter | KeyCode::Char(' ')) => self.fire(path, &Event::UpdatePickerSelection { id }),
+            (Control::Slider { value, lower_bound, upper_bound, step }, KeyCode::Left | KeyCode::Right) => {
+                // Clamping panics for empty or NaN bounds, which the view
+                // doesn't rule out
+                if lower_bound.is_nan() || upper_bound.is_nan() || lower_bound > upper_bound {
+                    self.warning = Some(format!("Warning: Slider has an invalid range {lower_bound}...{upper_bound}"));
+                    return;
+                }
+                // Sliders without a step move in twentieths of their range
+                let step = step.unwrap_or((upper_bound - lower_bound) / 20.0);
+                let delta = if code == KeyCode::Left { -step } else { step };
+                let value = (value + delta).clamp(lower_bound, upper_bound);
+                if value.is_nan() {
+                    self.warning = Some("Warning: Slider value is not a number".to_owned());
+                    return;
+                }
+                self.fire(path, &Event::UpdateSliderValue { value });
+            },
+            (Control::Stepper { value, lower_bound, upper_bound, step, .. }, KeyCode::Left | KeyCode::Right) => {
+                if lower_bound > upper_bound {
+                    self.warning = Some(format!("Warning: Stepper has an invalid range {lower_bound}...{upper_bound}"));
+                    return;
+                }
+                let delta = if code == KeyCode::Left { -step } else { step };
+                let value = value.saturating_add(delta).clamp(lower_bound, upper_bound);
+                self.fire(path, &Event::UpdateStepperValue { value });
+            },
+            _ => {},
+        }
+    }
+
+    /// Applies a line editing key to the given text field content.
+    fn edit_text(&mut self, path: &IdPath, content: &str, code: KeyCode) {
+        let mut chars: Vec<char> = content.chars().collect();
+        let cursor = self.cursor.min(chars.len());
+        match code {
+            KeyCode::Char(c) => {
+                chars.insert(cursor, c);
+                self.cursor = cursor + 1;
+            },
+            KeyCode::Backspace if cursor > 0 => {
+                chars.remove(cursor - 1);
+                self.cursor = cursor - 1;
+            },
+            KeyCode::Delete if cursor < chars.len() => {
+                chars.remove(cursor);
+            },
+            KeyCode::Left => self.cursor = cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (cursor + 1).min(chars.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = chars.len(),
+            _ => {},
+        }
+        let new_content: String = chars.into_iter().collect();
+        if new_content != content {
+            self.fire(path, &Event::UpdateText { content: new_content });
+        }
+    }
+
+    /// Moves the focus by the given number of controls, wrapping around.
+    fn move_focus(&mut self, offset: isize) {
+        let focusables = self.element.focusables();
+        let Ok(count) = isize::try_from(focusables.len()) else { return };
+        if count == 0 {
+            return;
+        }
+        let index = self.focus.as_ref()
+            .and_then(|focus| focusables.iter().position(|&key| key == focus))
+            .and_then(|index| isize::try_from(index).ok())
+            .map_or(0, |index| (index + offset).rem_euclid(count));
+        self.focus = usize::try_from(index).ok().and_then(|index| focusables.get(index)).map(|&key| key.clone());
+        self.cursor = usize::MAX;
+    }
+
+    /// Pushes the given value onto the navigation stack at the given id path.
+    fn follow_navigation_link(&mut self, stack_path: Option<&IdPath>, value: Value) {
+        let Some(stack) = stack_path.and_then(|stack_path| self.stacks.iter().find(|stack| &*stack.id_path == stack_path)).cloned() else {
+            self.warning = Some("Warning: Navigation link is not inside a navigation stack".to_owned());
+            return;
+        };
+        if let Some(mut path) = stack.bound_path {
+            // The page is shown once the view is re-rendered with the new path
+            path.push(value);
+            self.fire(&stack.id_path, &Event::UpdateNavigationPath { path });
+        } else {
+            self.pages.entry(stack.id_path).or_default().push(value);
+            self.needs_build = true;
+        }
+    }
+
+    /// Pops the topmost page of the innermost navigation stack that has one.
+    fn navigate_back(&mut self) {
+        let Some(stack) = self.stacks.iter().rev().find(|stack| stack.depth > 0).cloned() else { return };
+        if let Some(mut path) = stack.bound_path {
+            path.pop();
+            self.fire(&stack.id_path, &Event::UpdateNavigationPath { path });
+        } else if let Some(pages) = self.pages.get_mut(&stack.id_path) {
+            pages.pop();
+            self.needs_build = true;
+        }
+    }
+
+    fn fire(&mut self, path: &IdPath, event: &Event) {
+        // Events may be stale (e.g. if fired by a control that is about to be
+        // removed), which we don't consider fatal
+        if let Err(error) = self.root.fire_event(path, event) {
+            self.warning = Some(format!("Warning: {error}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use nuit_core::{clone, Access, AnyView, Button, IdPathBuf, LazyList, NavigationLink, NavigationStack, Root, State, Text, TextField, View, ViewExt, VStack};
+    use nuit_derive::Bind;
+    use ratatui::{backend::TestBackend, crossterm::event::{KeyCode, KeyEvent}, Terminal};
+
+    use crate::element::{Control, Element, FocusKey};
+
+    use super::Tui;
+
+    #[derive(Bind, Default)]
+    struct FormView {
+        name: State<String>,
+        path: State<Vec<i32>>,
+    }
+
+    impl View for FormView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let name = self.name.clone();
+            NavigationStack::with_path(
+                self.path.binding(),
+                VStack::from((
+                    TextField::new(name.binding()),
+                    Button::with_text("Reset", move || name.set(String::new())),
+                    NavigationLink::with_text("Next", 1),
+                ))
+                .navigation_destination(|i: i32| Text::new(format!("Page {i}")))
+            )
+        }
+    }
+
+    fn press(tui: &mut Tui, code: KeyCode) {
+        tui.handle_key(KeyEvent::from(code));
+        tui.update();
+    }
+
+    fn focused_control(tui: &Tui) -> Option<&Control> {
+        tui.focus.as_ref().and_then(|focus| tui.element.control(focus))
+    }
+
+    fn text_field_content(tui: &Tui) -> String {
+        match focused_control(tui) {
+            Some(Control::TextField { content, .. }) => content.clone(),
+            control => panic!("Unexpected control {control:?}"),
+        }
+    }
+
+    #[test]
+    fn edit_text() {
+        let mut tui = Tui::new(Root::new(AnyView::new(FormView::default())));
+        tui.update();
+
+        for code in [KeyCode::Char('a'), KeyCode::Char('b'), KeyCode::Left, KeyCode::Char('c')] {
+            press(&mut tui, code);
+        }
+        assert_eq!(text_field_content(&tui), "acb");
+
+        press(&mut tui, KeyCode::Backspace);
+        press(&mut tui, KeyCode::Delete);
+        assert_eq!(text_field_content(&tui), "a");
+
+        press(&mut tui, KeyCode::Home);
+        press(&mut tui, KeyCode::Char('b'));
+        press(&mut tui, KeyCode::End);
+        press(&mut tui, KeyCode::Char('c'));
+        assert_eq!(text_field_content(&tui), "bac");
+        assert!(tui.warning.is_none());
+    }
+
+    #[test]
+    fn focus_movement() {
+        let mut tui = Tui::new(Root::new(AnyView::new(FormView::default())));
+        tui.update();
+        assert!(matches!(focused_control(&tui), Some(Control::TextField { .. })));
+
+        press(&mut tui, KeyCode::Tab);
+        assert!(matches!(focused_control(&tui), Some(Control::Button { label, .. }) if label == "Reset"));
+        press(&mut tui, KeyCode::Down);
+        assert!(matches!(focused_control(&tui), Some(Control::NavigationLink { label, .. }) if label == "Next"));
+        press(&mut tui, KeyCode::Tab);
+        assert!(matches!(focused_control(&tui), Some(Control::TextField { .. })));
+        press(&mut tui, KeyCode::BackTab);
+        assert!(matches!(focused_control(&tui), Some(Control::NavigationLink { .. })));
+
+        // The cursor is reset when the focus moves to another text field
+        press(&mut tui, KeyCode::Up);
+        press(&mut tui, KeyCode::Up);
+        press(&mut tui, KeyCode::Char('a'));
+        press(&mut tui, KeyCode::Left);
+        press(&mut tui, KeyCode::Tab);
+        press(&mut tui, KeyCode::BackTab);
+        press(&mut tui, KeyCode::Char('b'));
+        assert_eq!(text_field_content(&tui), "ab");
+    }
+
+    #[test]
+    fn navigate_back() {
+        let mut tui = Tui::new(Root::new(AnyView::new(FormView::default())));
+        tui.update();
+
+        press(&mut tui, KeyCode::BackTab);
+        press(&mut tui, KeyCode::Enter);
+        assert!(matches!(&tui.element, Element::Page { can_go_back: true, child, .. } if **child == Element::Text { content: "Page 1".to_owned(), style: ratatui::style::Style::new() }));
+        assert_eq!(tui.stacks[0].depth, 1);
+        assert!(tui.focus.is_none());
+
+        press(&mut tui, KeyCode::Esc);
+        assert!(matches!(&tui.element, Element::Page { can_go_back: false, .. }));
+        assert_eq!(tui.stacks[0].depth, 0);
+        assert!(matches!(focused_control(&tui), Some(Control::TextField { .. })));
+
+        // Going back on the root page does nothing
+        press(&mut tui, KeyCode::Esc);
+        assert_eq!(tui.stacks[0].depth, 0);
+        assert!(tui.warning.is_none());
+    }
+
+    #[derive(Bind)]
+    struct RowView {
+        index: usize,
+        appearances: Rc<Cell<i32>>,
+    }
+
+    impl View for RowView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let appearances = self.appearances.clone();
+            Button::with_text(format!("Row {}", self.index), || {})
+                .on_appear(clone!(appearances => move || appearances.set(appearances.get() + 1)))
+                .on_disappear(clone!(appearances => move || appearances.set(appearances.get() - 1)))
+        }
+    }
+
+    #[test]
+    fn lazy_rows() {
+        let appearances = Rc::new(Cell::new(0));
+        let root = Root::new(AnyView::new(LazyList::new(100, clone!(appearances => move |index| RowView {
+            index,
+            appearances: appearances.clone(),
+        }))));
+        let mut tui = Tui::new(root);
+        let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+        let mut draw = |tui: &mut Tui| {
+            tui.update();
+            terminal.draw(|frame| tui.draw(frame)).unwrap();
+            tui.update();
+        };
+
+        // The status line leaves 5 visible rows, and one more is requested so
+        // that the focus can move into it
+        draw(&mut tui);
+        assert_eq!(tui.lazy_rows.len(), 6);
+        assert_eq!(appearances.get(), 6);
+
+        // Moving the focus down scrolls and thereby requests more rows
+        for _ in 0..10 {
+            tui.handle_key(KeyEvent::from(KeyCode::Down));
+            draw(&mut tui);
+        }
+        assert!(matches!(focused_control(&tui), Some(Control::Button { label, .. }) if label == "Row 10"));
+        let scrolled_count = tui.lazy_rows.len();
+        assert!(scrolled_count > 10 && scrolled_count < 20);
+        assert_eq!(appearances.get(), i32::try_from(scrolled_count).unwrap());
+
+        // Rows that can no longer be visible are unbound
+        for _ in 0..10 {
+            tui.handle_key(KeyEvent::from(KeyCode::Up));
+            draw(&mut tui);
+        }
+        assert!(matches!(focused_control(&tui), Some(Control::Button { label, .. }) if label == "Row 0"));
+        assert!(tui.lazy_rows.len() < scrolled_count);
+        assert_eq!(appearances.get(), i32::try_from(tui.lazy_rows.len()).unwrap());
+        assert!(tui.warning.is_none());
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        let mut tui = Tui::new(Root::new(AnyView::new(FormView::default())));
+        tui.update();
+        let focus = FocusKey::new(IdPathBuf::default());
+
+        // Adjusting controls with invalid ranges must not panic
+        for (lower_bound, upper_bound) in [(1.0, 0.0), (f64::NAN, 1.0), (0.0, f64::NAN)] {
+            tui.handle_control_key(&focus, Control::Slider { value: 0.5, lower_bound, upper_bound, step: None }, KeyCode::Right);
+            assert!(tui.warning.take().is_some_and(|warning| warning.contains("invalid range")));
+        }
+        tui.handle_control_key(&focus, Control::Slider { value: 0.5, lower_bound: 0.0, upper_bound: 1.0, step: Some(f64::NAN) }, KeyCode::Left);
+        assert!(tui.warning.take().is_some_and(|warning| warning.contains("not a number")));
+        tui.handle_control_key(&focus, Control::Stepper { title: String::new(), value: 0, lower_bound: 1, upper_bound: 0, step: 1 }, KeyCode::Right);
+        assert!(tui.warning.take().is_some_and(|warning| warning.contains("invalid range")));
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg_attr(test, feature(impl_trait_in_assoc_type))]
+
+mod message;
+mod server;
+mod session;
+
+use std::{env, sync::mpsc::{self, Sender}};
+
+use nuit_core::{App, OpenWindow};
+use serde_json::Value;
+use session::{Session, Signal};
+
+#[cfg(unix)]
+use signal_hook::{consts::{SIGINT, SIGTERM}, iterator::Signals};
+
+// Make nuit-derive's derive macros (which refer to `::nuit`) usable in our own
+// tests, analogous to the alias in nuit-core.
+#[cfg(test)]
+extern crate nuit_core as nuit;
+
+/// The address served on unless overridden via [`ADDRESS_VARIABLE`].
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+/// The environment variable specifying the address to serve on.
+const ADDRESS_VARIABLE: &str = "NUIT_WEB_ADDRESS";
+
+/// Runs the given app by serving it to web browsers. The launch scene is
+/// rendered on the server and every connected browser presents it.
+///
+/// # Panics
+///
+/// Panics if the launch scene cannot be opened or the address cannot be
+/// bound.
+#[allow(clippy::needless_pass_by_value)]
+pub fn run_app(app: App) {
+    let Some(scene) = app.launch_scene() else {
+        eprintln!("Warning: The app has no scene to open on launch");
+        return;
+    };
+
+    let open_window = OpenWindow::new(|id, _value| {
+        eprintln!("Warning: Cannot open a window for scene {id}, since the web backend only presents the launch scene");
+    });
+    let root = app.make_root(scene.info().id(), Value::Null, open_window)
+        .unwrap_or_else(|error| panic!("Could not open launch scene: {error}"));
+
+    let address = env::var(ADDRESS_VARIABLE).unwrap_or_else(|_| DEFAULT_ADDRESS.to_owned());
+    let (signal_sender, signal_receiver) = mpsc::channel();
+    let local_address = server::listen(&address, signal_sender.clone())
+        .unwrap_or_else(|error| panic!("Could not listen on {address}: {error}"));
+    println!("Serving {} at http://{local_address}", scene.info().title().unwrap_or(app.id()));
+
+    forward_termination(signal_sender.clone());
+    Session::new(root, &signal_sender).run(&signal_receiver);
+    // The server runs until it is terminated, at which point persisted state
+    // may not have been written yet
+    app.flush_persisted_state();
+}
+
+/// Asks the session to stop once the process is asked to terminate (e.g. via
+/// Ctrl-C), so that the app's state can be saved before exiting.
+#[cfg(unix)]
+fn forward_termination(signals: Sender<Signal>) {
+    match Signals::new([SIGINT, SIGTERM]) {
+        Ok(mut terminations) => {
+            std::thread::spawn(move || {
+                if terminations.forever().next().is_some() {
+                    let _ = signals.send(Signal::Terminate);
+                }
+            });
+        },
+        Err(error) => eprintln!("Warning: Could not handle termination signals: {error}"),
+    }
+}
+
+/// Termination signals are only handled on Unix, elsewhere the process exits
+/// right away.
+#[cfg(not(unix))]
+fn forward_termination(_signals: Sender<Signal>) {}
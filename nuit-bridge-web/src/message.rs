@@ -0,0 +1,22 @@
+use nuit_core::Node;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A message sent from the server to the browser.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ServerMessage<'a> {
+    /// The current node tree, which the browser reconciles with the DOM.
+    Render { node: &'a Node },
+    /// The response to an event that the browser requested one for.
+    Response { request_id: u64, response: Value },
+}
+
+/// A message sent from the browser to the server.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ClientMessage {
+    /// An event to fire at the given id path. The id path and the event are
+    /// forwarded as raw JSON.
+    Event { id_path: Value, event: Value, request_id: Option<u64> },
+}
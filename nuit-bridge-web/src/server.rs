@@ -0,0 +1,331 @@
+use std::{io::{self, BufRead, BufReader, ErrorKind, Read, Write}, net::{Shutdown, SocketAddr, TcpListener, TcpStream}, sync::{mpsc::{self, Receiver, Sender}, Arc, Mutex, MutexGuard}, thread};
+
+use tungstenite::{handshake::derive_accept_key, protocol::Role, Message, WebSocket};
+
+use crate::session::{ClientId, Signal};
+
+/// The maximum length of the request line or a header line in bytes.
+const MAX_LINE_LENGTH: usize = 8 * 1024;
+/// The maximum total size of the request headers in bytes.
+const MAX_HEADERS_SIZE: usize = 64 * 1024;
+
+const INDEX_HTML: &str = include_str!("../static/index.html");
+const RENDERER_JS: &str = include_str!("../static/renderer.js");
+
+/// Starts serving the renderer and WebSocket connections on a background
+/// thread, forwarding connection events to the given sender. Returns the
+/// address actually bound, e.g. if the given one uses port 0.
+pub fn listen(address: &str, signals: Sender<Signal>) -> io::Result<SocketAddr> {
+    let listener = TcpListener::bind(address)?;
+    let local_address = listener.local_addr()?;
+    thread::spawn(move || {
+        for (client, stream) in listener.incoming().enumerate() {
+            match stream {
+                Ok(stream) => {
+                    let signals = signals.clone();
+                    thread::spawn(move || {
+                        if let Err(error) = handle_connection(client, stream, &signals) {
+                            eprintln!("Warning: Connection failed: {error}");
+                        }
+                    });
+                },
+                Err(error) => eprintln!("Warning: Could not accept connection: {error}"),
+            }
+        }
+    });
+    Ok(local_address)
+}
+
+/// Serves a static file or, if the request asks for an upgrade, exchanges
+/// messages over a WebSocket until the connection is closed.
+fn handle_connection(client: ClientId, stream: TcpStream, signals: &Sender<Signal>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let request = Request::read(&mut reader)?;
+    // The browser only sends frames after the handshake, but we hand over
+    // anything already buffered to be safe
+    let buffered = reader.buffer().to_vec();
+    let mut stream = reader.into_inner();
+
+    if !request.is_upgrade() {
+        return serve_file(&mut stream, &request.path);
+    }
+    match request.websocket_key() {
+        Ok(key) => {
+            write!(
+                stream,
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                derive_accept_key(key.as_bytes()),
+            )?;
+            serve_socket(client, stream, buffered, signals)
+        },
+        Err(status) => write_response(&mut stream, status, "text/plain", status),
+    }
+}
+
+/// Responds with the renderer file at the given path.
+fn serve_file(stream: &mut TcpStream, path: &str) -> io::Result<()> {
+    let path = path.split('?').next().unwrap_or_default();
+    let (status, content_type, body) = match path {
+        "/" | "/index.html" => ("200 OK", "text/html", INDEX_HTML),
+        "/renderer.js" => ("200 OK", "text/javascript", RENDERER_JS),
+        _ => ("404 Not Found", "text/plain", "Not found"),
+    };
+    write_response(stream, status, content_type, body)
+}
+
+/// Writes a complete response with the given status and body.
+fn write_response(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len(),
+    )?;
+    stream.flush()
+}
+
+/// Registers the client with the session and forwards messages in both
+/// directions until the connection is closed. Outgoing messages are sent by a
+/// separate thread, so that neither direction has to poll the other.
+fn serve_socket(client: ClientId, stream: TcpStream, buffered: Vec<u8>, signals: &Sender<Signal>) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    if signals.send(Signal::Connected { client, sender }).is_err() {
+        return Ok(());
+    }
+
+    let writer = Arc::new(Mutex::new(stream.try_clone()?));
+    let mut reading_socket = WebSocket::from_partially_read(SharedStream::new(stream.try_clone()?, writer.clone()), buffered, Role::Server, None);
+    let mut writing_socket = WebSocket::from_raw_socket(SharedStream::new(stream.try_clone()?, writer), Role::Server, None);
+    let writer_thread = thread::spawn(move || {
+        let result = send_messages(&mut writing_socket, &receiver);
+        // Unblock the reader if the connection broke while writing
+        let _ = stream.shutdown(Shutdown::Both);
+        result
+    });
+
+    let result = receive_messages(client, &mut reading_socket, signals);
+    // The session drops the sender once it learns about the disconnect,
+    // which ends the writer thread
+    let _ = signals.send(Signal::Disconnected { client });
+    let write_result = writer_thread.join().unwrap_or_else(|_| Err(tungstenite::Error::ConnectionClosed));
+    result.and(write_result).map_err(io::Error::other)
+}
+
+/// Sends the messages from the given receiver until the session drops the
+/// sender.
+fn send_messages(socket: &mut WebSocket<SharedStream>, receiver: &Receiver<String>) -> tungstenite::Result<()> {
+    for text in receiver {
+        match socket.send(Message::text(text)) {
+            Ok(()) => {},
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => return Ok(()),
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// Forwards received messages to the session until the connection is closed.
+fn receive_messages(client: ClientId, socket: &mut WebSocket<SharedStream>, signals: &Sender<Signal>) -> tungstenite::Result<()> {
+    loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                if signals.send(Signal::Received { client, text: text.to_string() }).is_err() {
+                    return Ok(());
+                }
+            },
+            Ok(_) => {},
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => return Ok(()),
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// A stream that reads from its own handle to the connection and writes
+/// through a lock shared by both sockets of the connection. Each write
+/// contains whole frames, so the frames of the reading socket (e.g. pongs)
+/// and the writing one never interleave.
+struct SharedStream {
+    reader: TcpStream,
+    writer: Arc<Mutex<TcpStream>>,
+}
+
+impl SharedStream {
+    const fn new(reader: TcpStream, writer: Arc<Mutex<TcpStream>>) -> Self {
+        Self { reader, writer }
+    }
+
+    fn lock_writer(&self) -> io::Result<MutexGuard<'_, TcpStream>> {
+        self.writer.lock().map_err(|_| io::Error::other("Writer lock poisoned"))
+    }
+}
+
+impl Read for SharedStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl Write for SharedStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.lock_writer()?.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock_writer()?.flush()
+    }
+}
+
+/// The relevant parts of an HTTP request.
+struct Request {
+    path: String,
+    /// The headers with lowercased names.
+    headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// Reads the request line and headers from the given reader.
+    fn read(reader: &mut impl BufRead) -> io::Result<Self> {
+        let mut line = String::new();
+        read_line(reader, &mut line)?;
+        let path = line.split_whitespace().nth(1)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, format!("Invalid request line: {}", line.trim_end())))?
+            .to_owned();
+
+        let mut headers = Vec::new();
+        let mut headers_size = 0;
+        loop {
+            line.clear();
+            let length = read_line(reader, &mut line)?;
+            if length == 0 {
+                break;
+            }
+            headers_size += length;
+            if headers_size > MAX_HEADERS_SIZE {
+                return Err(io::Error::new(ErrorKind::InvalidData, "Request headers too large"));
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_lowercase(), value.trim().to_owned()));
+            }
+        }
+
+        Ok(Self { path, headers })
+    }
+
+    /// Whether the request asks for a WebSocket upgrade, even if it does so
+    /// incorrectly.
+    fn is_upgrade(&self) -> bool {
+        self.header("upgrade").is_some() || self.header("sec-websocket-key").is_some()
+    }
+
+    /// The key of a valid WebSocket upgrade request, or the status to reject
+    /// it with. Upgrades are only accepted from pages served by us (or from
+    /// clients that don't send an origin, i.e. aren't browsers), since any
+    /// page could otherwise connect to the app.
+    fn websocket_key(&self) -> Result<&str, &'static str> {
+        let is_websocket = self.header("upgrade").is_some_and(|upgrade| upgrade.eq_ignore_ascii_case("websocket"));
+        let is_supported_version = self.header("sec-websocket-version") == Some("13");
+        let Some(key) = self.header("sec-websocket-key").filter(|_| is_websocket && is_supported_version) else {
+            return Err("400 Bad Request");
+        };
+        if let Some(origin) = self.header("origin") {
+            let origin_host = origin.split_once("://").map_or(origin, |(_, host)| host);
+            if !self.header("host").is_some_and(|host| host.eq_ignore_ascii_case(origin_host)) {
+                return Err("403 Forbidden");
+            }
+        }
+        Ok(key)
+    }
+
+    /// The value of the header with the given (lowercase) name.
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(header_name, _)| header_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Reads a line (including the line break) into the given string, failing if
+/// it exceeds [`MAX_LINE_LENGTH`]. Returns the number of bytes read, which is
+/// 0 at the end of the input.
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> io::Result<usize> {
+    let limit = MAX_LINE_LENGTH as u64 + 1;
+    let length = reader.take(limit).read_line(line)?;
+    if length > MAX_LINE_LENGTH {
+        return Err(io::Error::new(ErrorKind::InvalidData, "Request line too long"));
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use super::{Request, MAX_HEADERS_SIZE, MAX_LINE_LENGTH};
+
+    fn request(headers: &[(&str, &str)]) -> Request {
+        Request {
+            path: "/".to_owned(),
+            headers: headers.iter().map(|&(name, value)| (name.to_owned(), value.to_owned())).collect(),
+        }
+    }
+
+    fn upgrade(extra_headers: &[(&str, &str)]) -> Request {
+        let mut headers = vec![
+            ("host", "localhost:8080"),
+            ("upgrade", "websocket"),
+            ("sec-websocket-version", "13"),
+            ("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="),
+        ];
+        headers.extend_from_slice(extra_headers);
+        request(&headers)
+    }
+
+    #[test]
+    fn read() {
+        let mut input = "GET /renderer.js?v=1 HTTP/1.1\r\nHost: localhost:8080\r\nSec-WebSocket-Key:  abc \r\n\r\nbody".as_bytes();
+        let request = Request::read(&mut input).unwrap();
+        assert_eq!(request.path, "/renderer.js?v=1");
+        assert_eq!(request.header("host"), Some("localhost:8080"));
+        assert_eq!(request.header("sec-websocket-key"), Some("abc"));
+        assert_eq!(request.header("origin"), None);
+        assert_eq!(input, b"body");
+
+        let error = Request::read(&mut "\r\n".as_bytes()).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_limits() {
+        let long_line = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_LENGTH));
+        let error = Request::read(&mut long_line.as_bytes()).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let header = format!("X-Padding: {}\r\n", "a".repeat(1000));
+        let many_headers = format!("GET / HTTP/1.1\r\n{}\r\n", header.repeat(MAX_HEADERS_SIZE / header.len() + 1));
+        let error = Request::read(&mut many_headers.as_bytes()).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn websocket_key() {
+        assert!(!request(&[("host", "localhost:8080")]).is_upgrade());
+        assert!(upgrade(&[]).is_upgrade());
+        assert_eq!(upgrade(&[]).websocket_key(), Ok("dGhlIHNhbXBsZSBub25jZQ=="));
+        assert_eq!(upgrade(&[("origin", "http://LOCALHOST:8080")]).websocket_key(), Ok("dGhlIHNhbXBsZSBub25jZQ=="));
+
+        assert_eq!(upgrade(&[("origin", "https://example.com")]).websocket_key(), Err("403 Forbidden"));
+        assert_eq!(upgrade(&[("origin", "null")]).websocket_key(), Err("403 Forbidden"));
+
+        let missing_key = request(&[("upgrade", "websocket"), ("sec-websocket-version", "13")]);
+        assert!(missing_key.is_upgrade());
+        assert_eq!(missing_key.websocket_key(), Err("400 Bad Request"));
+        let wrong_upgrade = request(&[("upgrade", "h2c"), ("sec-websocket-version", "13"), ("sec-websocket-key", "abc")]);
+        assert_eq!(wrong_upgrade.websocket_key(), Err("400 Bad Request"));
+        let wrong_version = request(&[("upgrade", "websocket"), ("sec-websocket-version", "8"), ("sec-websocket-key", "abc")]);
+        assert_eq!(wrong_version.websocket_key(), Err("400 Bad Request"));
+    }
+}
@@ -0,0 +1,237 @@
+use std::{collections::HashMap, iter, sync::mpsc::{Receiver, Sender}};
+
+use nuit_core::{AnyView, EventResponse, Node, Root};
+
+use crate::message::{ClientMessage, ServerMessage};
+
+/// Identifies a browser connection.
+pub type ClientId = usize;
+
+/// Something the session has to react to, sent from the connection threads
+/// or the root's callbacks.
+pub enum Signal {
+    /// A browser connected and receives messages via the given sender.
+    Connected { client: ClientId, sender: Sender<String> },
+    Disconnected { client: ClientId },
+    /// A browser sent the given (JSON-serialized) message.
+    Received { client: ClientId, text: String },
+    /// The state changed, so the view has to be rendered again.
+    Update,
+    /// Tasks are ready to make progress.
+    Wake,
+    /// The app is about to exit, so the session should stop.
+    Terminate,
+}
+
+/// Owns the root and presents it to all connected browsers, which therefore
+/// share the app's state.
+pub struct Session {
+    root: Root<AnyView>,
+    clients: HashMap<ClientId, Sender<String>>,
+    node: Node,
+}
+
+impl Session {
+    pub fn new(root: Root<AnyView>, signals: &Sender<Signal>) -> Self {
+        let update_signals = signals.clone();
+        root.set_update_callback(move |_update| {
+            let _ = update_signals.send(Signal::Update);
+        });
+        let wake_signals = signals.clone();
+        root.set_wake_callback(move || {
+            let _ = wake_signals.send(Signal::Wake);
+        });
+
+        let node = root.render();
+        Self { root, clients: HashMap::new(), node }
+    }
+
+    /// Handles signals until all senders are gone.
+    pub fn run(mut self, signals: &Receiver<Signal>) {
+        self.root.run_tasks();
+        while let Ok(signal) = signals.recv() {
+            let mut needs_render = false;
+            // Events often cause several updates, which we coalesce into a
+            // single render
+            for signal in iter::once(signal).chain(signals.try_iter()) {
+                if let Signal::Terminate = signal {
+                    return;
+                }
+                needs_render |= self.handle(signal);
+            }
+            if needs_render {
+                self.render();
+            }
+        }
+    }
+
+    /// Handles the given signal, returning whether the view has to be
+    /// rendered again.
+    fn handle(&mut self, signal: Signal) -> bool {
+        match signal {
+            Signal::Connected { client, sender } => {
+                let _ = sender.send(encode(&ServerMessage::Render { node: &self.node }));
+                self.clients.insert(client, sender);
+            },
+            Signal::Disconnected { client } => {
+                self.clients.remove(&client);
+            },
+            Signal::Received { client, text } => self.receive(client, &text),
+            Signal::Update => return true,
+            Signal::Wake => self.root.run_tasks(),
+            // Handled by the run loop
+            Signal::Terminate => {},
+        }
+        false
+    }
+
+    /// Fires the event in the given message and sends back the response, if
+    /// requested.
+    fn receive(&self, client: ClientId, text: &str) {
+        let message: ClientMessage = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(error) => {
+                eprintln!("Warning: Could not deserialize client message: {error}");
+                return;
+            },
+        };
+        match message {
+            ClientMessage::Event { id_path, event, request_id } => {
+                // Events may be stale (e.g. if fired by an element that the
+                // browser hasn't removed yet), which we don't consider fatal,
+                // but we still answer requests so the browser doesn't wait
+                let response = match self.root.fire_event_json(&id_path.to_string(), &event.to_string()) {
+                    Ok(response) => serde_json::from_str(&response).expect("Could not deserialize event response"),
+                    Err(error) => {
+                        eprintln!("Warning: {error}");
+                        serde_json::to_value(EventResponse::Error { message: error.to_string() }).expect("Could not serialize event response")
+                    },
+                };
+                if let (Some(request_id), Some(sender)) = (request_id, self.clients.get(&client)) {
+                    let _ = sender.send(encode(&ServerMessage::Response { request_id, response }));
+                }
+            },
+        }
+    }
+
+    /// Renders the view and pushes the node tree to all clients. Since the
+    /// rows of lazy views may have changed without showing up in the node
+    /// tree, we push it even if it didn't change.
+    fn render(&mut self) {
+        self.node = self.root.render();
+        let json = encode(&ServerMessage::Render { node: &self.node });
+        for sender in self.clients.values() {
+            // Sending fails only if the client disconnected, which we learn
+            // about separately
+            let _ = sender.send(json.clone());
+        }
+    }
+}
+
+/// Serializes the given message to JSON.
+fn encode(message: &ServerMessage) -> String {
+    serde_json::to_string(message).expect("Could not serialize message")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{self, Receiver};
+
+    use nuit_core::{Access, AnyView, Button, Root, State, Text, View, VStack};
+    use nuit_derive::Bind;
+    use serde_json::{json, Value};
+
+    use super::{Session, Signal};
+
+    #[derive(Bind, Default)]
+    struct CounterView {
+        count: State<i32>,
+    }
+
+    impl View for CounterView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let count = self.count.clone();
+            VStack::from((
+                Text::new(format!("{}", count.get())),
+                Button::with_text("Increment", move || {
+                    count.set(count.get() + 1);
+                }),
+            ))
+        }
+    }
+
+    fn connected_session() -> (Session, Receiver<Signal>, Receiver<String>) {
+        let (signal_sender, signal_receiver) = mpsc::channel();
+        let mut session = Session::new(Root::new(AnyView::new(CounterView::default())), &signal_sender);
+        let (sender, receiver) = mpsc::channel();
+        session.handle(Signal::Connected { client: 0, sender });
+        (session, signal_receiver, receiver)
+    }
+
+    fn next_message(receiver: &Receiver<String>) -> Value {
+        serde_json::from_str(&receiver.try_recv().expect("No message sent")).unwrap()
+    }
+
+    fn receive(session: &mut Session, message: &Value) {
+        session.handle(Signal::Received { client: 0, text: message.to_string() });
+    }
+
+    #[test]
+    fn response() {
+        let (mut session, _signals, messages) = connected_session();
+        assert_eq!(next_message(&messages)["type"], "render");
+
+        receive(&mut session, &json!({ "type": "event", "idPath": [0, 1], "event": { "buttonTap": {} }, "requestId": 3 }));
+        assert_eq!(next_message(&messages), json!({ "type": "response", "requestId": 3, "response": { "empty": {} } }));
+
+        receive(&mut session, &json!({ "type": "event", "idPath": [0, 5], "event": { "buttonTap": {} }, "requestId": 4 }));
+        let message = next_message(&messages);
+        assert_eq!(message["requestId"], 4);
+        assert!(message["response"]["error"]["message"].is_string());
+
+        // Events without a request id don't get a response, even if they fail
+        receive(&mut session, &json!({ "type": "event", "idPath": [0, 5], "event": { "buttonTap": {} } }));
+        assert!(messages.try_recv().is_err());
+    }
+
+    #[test]
+    fn broadcast() {
+        let (mut session, signals, messages) = connected_session();
+        let (other_sender, other_messages) = mpsc::channel();
+        session.handle(Signal::Connected { client: 1, sender: other_sender });
+        next_message(&messages);
+        next_message(&other_messages);
+
+        receive(&mut session, &json!({ "type": "event", "idPath": [0, 1], "event": { "buttonTap": {} } }));
+        let mut needs_render = false;
+        while let Ok(signal) = signals.try_recv() {
+            needs_render |= session.handle(signal);
+        }
+        assert!(needs_render);
+        session.render();
+
+        for messages in [&messages, &other_messages] {
+            let message = next_message(messages);
+            assert_eq!(message["type"], "render");
+            assert!(message["node"].to_string().contains("\"1\""));
+        }
+
+        session.handle(Signal::Disconnected { client: 1 });
+        session.render();
+        next_message(&messages);
+        assert!(other_messages.try_recv().is_err());
+    }
+
+    #[test]
+    fn termination() {
+        let (signal_sender, signal_receiver) = mpsc::channel();
+        let session = Session::new(Root::new(AnyView::new(CounterView::default())), &signal_sender);
+        signal_sender.send(Signal::Wake).unwrap();
+        signal_sender.send(Signal::Terminate).unwrap();
+
+        // Returns instead of waiting for further signals
+        session.run(&signal_receiver);
+    }
+}
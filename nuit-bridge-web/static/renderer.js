@@ -0,0 +1,775 @@
+// Renders the node trees pushed by the server into the DOM and sends DOM
+// events back to the server as Nuit events.
+
+'use strict';
+
+const SVG_NAMESPACE = 'http://www.w3.org/2000/svg';
+
+const HORIZONTAL_ALIGNMENTS = {
+  leading: 'flex-start',
+  center: 'center',
+  trailing: 'flex-end',
+};
+
+const VERTICAL_ALIGNMENTS = {
+  top: 'flex-start',
+  center: 'center',
+  bottom: 'flex-end',
+  firstTextBaseline: 'baseline',
+  lastTextBaseline: 'last baseline',
+};
+
+const SEMANTIC_COLORS = {
+  foreground: 'currentColor',
+  background: 'Canvas',
+  selection: 'Highlight',
+  separator: 'GrayText',
+  tint: 'AccentColor',
+  placeholder: 'GrayText',
+  link: 'LinkText',
+  fill: 'ButtonFace',
+  windowBackground: 'Canvas',
+};
+
+/** The opacities of the primary, secondary, ... hierarchical styles. */
+const HIERARCHICAL_OPACITIES = [1, 0.55, 0.3, 0.2, 0.1];
+
+const rootElement = document.getElementById('root');
+const socket = new WebSocket(`ws://${location.host}/socket`);
+const pendingRequests = new Map();
+let nextRequestId = 0;
+
+socket.addEventListener('message', ({ data }) => {
+  const message = JSON.parse(data);
+  switch (message.type) {
+  case 'render':
+    reconcile(rootElement, [[[], message.node]]);
+    break;
+  case 'response':
+    pendingRequests.get(message.requestId)?.(message.response);
+    pendingRequests.delete(message.requestId);
+    break;
+  }
+});
+
+socket.addEventListener('close', () => {
+  document.body.classList.add('disconnected');
+});
+
+/** Fires the given event at the given id path. */
+function fire(idPath, event) {
+  socket.send(JSON.stringify({ type: 'event', idPath, event }));
+}
+
+/** Fires the given event at the given id path, resolving to the response. */
+function request(idPath, event) {
+  return new Promise(resolve => {
+    const requestId = nextRequestId++;
+    pendingRequests.set(requestId, resolve);
+    socket.send(JSON.stringify({ type: 'event', idPath, event, requestId }));
+  });
+}
+
+// Node trees
+
+/** The variant name and fields of the given (externally tagged) value. */
+function unpack(value) {
+  return Object.entries(value)[0];
+}
+
+/** Whether the given serialized value is an identified node. */
+function isIdentified(value) {
+  return value !== null && typeof value === 'object' && !Array.isArray(value)
+    && Object.keys(value).length === 2 && 'id' in value && 'value' in value;
+}
+
+/** The identified nodes held by the given serialized field. */
+function identifiedChildren(value) {
+  if (isIdentified(value)) {
+    return [value];
+  }
+  return Array.isArray(value) ? value.filter(isIdentified) : [];
+}
+
+/** The id path and node of the given child of the node at the given path. */
+function child(path, { id, value }) {
+  return [[...path, id], value];
+}
+
+/**
+ * The id paths and nodes of the given child with groups resolved, mirroring
+ * `Node::children_from`.
+ */
+function flatten(path, identified) {
+  const [childPath, node] = child(path, identified);
+  const [kind, fields] = unpack(node);
+  switch (kind) {
+  case 'group':
+    return fields.children.flatMap(grandchild => flatten(childPath, grandchild));
+  case 'editable':
+    return flatten(childPath, fields.wrapped);
+  default:
+    return [[childPath, node]];
+  }
+}
+
+/** The content of the first text in the given node, if any. */
+function firstText(node) {
+  const [kind, fields] = unpack(node);
+  if (kind === 'text') {
+    return fields.content;
+  }
+  for (const value of Object.values(fields)) {
+    for (const identified of identifiedChildren(value)) {
+      const text = firstText(identified.value);
+      if (text !== undefined) {
+        return text;
+      }
+    }
+  }
+  return undefined;
+}
+
+// Reconciliation
+
+/**
+ * Updates the children of the given container to present the given nodes,
+ * reusing the elements that present a node of the same kind at the same id
+ * path. Reusing elements keeps e.g. the focus of text fields intact.
+ */
+function reconcile(container, entries) {
+  const existing = new Map([...container.children].map(element => [element.dataset.key, element]));
+  entries.forEach(([path, node], index) => {
+    const [kind, fields] = unpack(node);
+    const renderer = RENDERERS[kind] ?? {};
+    const key = `${kind}:${JSON.stringify(path)}`;
+    let element = existing.get(key);
+    if (element) {
+      existing.delete(key);
+    } else {
+      element = (renderer.create ?? createContainer)(path, fields);
+      element.classList.add(`nuit-${kind}`);
+      element.dataset.key = key;
+      element.idPath = path;
+    }
+    (renderer.update ?? updateGeneric)(element, fields, path);
+    const current = container.children[index];
+    if (current !== element) {
+      container.insertBefore(element, current ?? null);
+    }
+  });
+  for (const element of existing.values()) {
+    element.remove();
+  }
+}
+
+function createContainer() {
+  return document.createElement('div');
+}
+
+/**
+ * Presents the wrapped child of an unsupported node (so e.g. the content of a
+ * sheet stays hidden) or otherwise all of its children.
+ */
+function updateGeneric(element, fields, path) {
+  const children = 'wrapped' in fields ? [fields.wrapped] : Object.values(fields).flatMap(identifiedChildren);
+  reconcile(element, children.flatMap(identified => flatten(path, identified)));
+}
+
+function setText(element, text) {
+  if (element.textContent !== text) {
+    element.textContent = text;
+  }
+}
+
+/** Creates a label containing the given elements. */
+function createLabel(...elements) {
+  const label = document.createElement('label');
+  label.append(...elements);
+  return label;
+}
+
+function stackUpdater(alignments) {
+  return (element, { alignment, spacing, wrapped }, path) => {
+    element.style.alignItems = alignments[alignment];
+    element.style.gap = `${spacing}px`;
+    reconcile(element, flatten(path, wrapped));
+  };
+}
+
+/** Sets the CSS alignment of the children of a grid-based element. */
+function setPlaceItems(element, { horizontal, vertical }) {
+  element.style.placeItems = `${VERTICAL_ALIGNMENTS[vertical]} ${HORIZONTAL_ALIGNMENTS[horizontal]}`;
+}
+
+/** The CSS track size of the given lazy grid item. */
+function gridTrack({ size }) {
+  const [kind, fields] = unpack(size);
+  const maximum = fields.maximum == null ? '1fr' : `${fields.maximum}px`;
+  switch (kind) {
+  case 'fixed':
+    return `${fields.size}px`;
+  case 'adaptive':
+    return `repeat(auto-fill, minmax(${fields.minimum}px, ${maximum}))`;
+  default:
+    return `minmax(${fields.minimum}px, ${maximum})`;
+  }
+}
+
+/**
+ * Creates the renderer of a lazy view, which lays out the element and then
+ * requests all of its rows. Since browsers handle long lists well, we don't
+ * request rows on demand. Rows are not part of the node tree, so we notify
+ * them when they are added and removed.
+ */
+function lazyRenderer(layout) {
+  return {
+    update: (element, fields, path) => {
+      layout(element, fields);
+      const rowRequest = (element.rowRequest ?? 0) + 1;
+      element.rowRequest = rowRequest;
+      request(path, { getRows: { start: 0, end: fields.count } }).then(response => {
+        // A newer request may have superseded this one, and stale requests
+        // fail, in which case the element is about to be removed anyway
+        if (element.rowRequest === rowRequest && !response.error) {
+          const rows = (response.nodes?.nodes ?? []).map(row => child(path, row));
+          reconcile(element, rows);
+
+          const boundRows = element.boundRows ?? new Map();
+          const rowPaths = new Map(rows.map(([rowPath]) => [JSON.stringify(rowPath), rowPath]));
+          for (const [key, rowPath] of boundRows) {
+            if (!rowPaths.has(key)) {
+              fire(rowPath, { disappear: {} });
+            }
+          }
+          for (const [key, rowPath] of rowPaths) {
+            if (!boundRows.has(key)) {
+              fire(rowPath, { appear: {} });
+            }
+          }
+          element.boundRows = rowPaths;
+        }
+      });
+    },
+  };
+}
+
+/** The rows of the given list, including those within sections. */
+function listRows(list) {
+  return [...list.children].flatMap(element => element.classList.contains('nuit-section')
+    ? [...element.querySelector(':scope > .nuit-section-content').children]
+    : [element]);
+}
+
+// Styles and shapes
+
+/** The CSS color of the given style. */
+function cssColor(style) {
+  const [kind, fields] = unpack(style);
+  switch (kind) {
+  case 'color': {
+    const { red, green, blue, alpha } = fields.color;
+    return `rgb(${red * 255} ${green * 255} ${blue * 255} / ${alpha})`;
+  }
+  case 'hierarchical': {
+    const opacity = HIERARCHICAL_OPACITIES[Math.min(fields.level, HIERARCHICAL_OPACITIES.length - 1)];
+    return `color-mix(in srgb, currentColor ${opacity * 100}%, transparent)`;
+  }
+  case 'semantic':
+    return SEMANTIC_COLORS[fields.style] ?? 'currentColor';
+  case 'material':
+    // Materials are approximated without blur
+    return 'color-mix(in srgb, Canvas 80%, transparent)';
+  case 'opacity':
+    return `color-mix(in srgb, ${cssColor(fields.wrapped)} ${fields.opacity * 100}%, transparent)`;
+  default:
+    // Blend modes and shadows are ignored
+    return cssColor(fields.wrapped);
+  }
+}
+
+function createSvgElement(name, attributes) {
+  const element = document.createElementNS(SVG_NAMESPACE, name);
+  for (const [attribute, value] of Object.entries(attributes)) {
+    element.setAttribute(attribute, value);
+  }
+  return element;
+}
+
+/** The SVG path of the given sector, with angles measured clockwise. */
+function sectorPath(cx, cy, radius, innerRadius, startAngle, endAngle) {
+  const point = (r, angle) => `${cx + r * Math.cos(angle)} ${cy + r * Math.sin(angle)}`;
+  // Arcs with identical endpoints aren't drawn, so full circles are drawn as
+  // slightly smaller sectors
+  const end = startAngle + Math.min(endAngle - startAngle, 2 * Math.PI - 1e-6);
+  const largeArc = end - startAngle > Math.PI ? 1 : 0;
+  return `M ${point(radius, startAngle)} A ${radius} ${radius} 0 ${largeArc} 1 ${point(radius, end)} `
+    + `L ${point(innerRadius, end)} A ${innerRadius} ${innerRadius} 0 ${largeArc} 0 ${point(innerRadius, startAngle)} Z`;
+}
+
+/** Creates the SVG element for the given primitive shape of the given size. */
+function createPrimitiveShape(kind, fields, width, height) {
+  const radius = Math.min(width, height) / 2;
+  switch (kind) {
+  case 'capsule':
+    return createSvgElement('rect', { width, height, rx: radius, ry: radius });
+  case 'circle':
+    return createSvgElement('circle', { cx: width / 2, cy: height / 2, r: radius });
+  case 'ellipse':
+    return createSvgElement('ellipse', { cx: width / 2, cy: height / 2, rx: width / 2, ry: height / 2 });
+  case 'roundedRectangle':
+    return createSvgElement('rect', {
+      width,
+      height,
+      rx: Math.min(fields.cornerSize.x, width / 2),
+      ry: Math.min(fields.cornerSize.y, height / 2),
+    });
+  case 'sector':
+    return createSvgElement('path', {
+      d: sectorPath(width / 2, height / 2, radius, radius * fields.innerRadiusFraction, fields.startAngle.radians, fields.endAngle.radians),
+    });
+  default:
+    return createSvgElement('rect', { width, height });
+  }
+}
+
+/** Draws the shape of the given SVG element at its current size. */
+function drawShape(svg) {
+  const { width, height } = svg.getBoundingClientRect();
+  let [kind, fields] = unpack(svg.shape);
+  let fill;
+  let stroke;
+  while (kind === 'fill' || kind === 'stroke') {
+    if (kind === 'fill') {
+      fill ??= cssColor(fields.style);
+    } else {
+      stroke ??= cssColor(fields.style);
+    }
+    [kind, fields] = unpack(fields.wrapped);
+  }
+
+  const element = createPrimitiveShape(kind, fields, width, height);
+  // Like in SwiftUI, unstyled shapes are filled with the foreground color
+  element.style.fill = fill ?? (stroke ? 'none' : 'currentColor');
+  element.style.stroke = stroke ?? 'none';
+  svg.replaceChildren(element);
+}
+
+// Modifiers
+
+/** Resets the styles and attributes that modifiers may have set. */
+function resetModifier(element) {
+  element.style.cssText = '';
+  for (const attribute of ['title', 'aria-label', 'aria-hidden']) {
+    element.removeAttribute(attribute);
+  }
+  element.inert = false;
+  element.classList.remove('nuit-disabled');
+}
+
+function applyModifier(element, kind, fields) {
+  const { style } = element;
+  switch (kind) {
+  case 'padding': {
+    const { top, leading, bottom, trailing } = fields.insets;
+    style.padding = `${top}px ${trailing}px ${bottom}px ${leading}px`;
+    break;
+  }
+  case 'offset':
+    style.transform = `translate(${fields.delta.x}px, ${fields.delta.y}px)`;
+    break;
+  case 'opacity':
+    style.opacity = fields.opacity;
+    break;
+  case 'frame': {
+    const [frameKind, frame] = unpack(fields.frame);
+    const px = value => (value == null ? '' : `${value}px`);
+    if (frameKind === 'exact') {
+      style.width = px(frame.width);
+      style.height = px(frame.height);
+    } else {
+      style.minWidth = px(frame.minWidth);
+      style.width = px(frame.idealWidth);
+      style.maxWidth = px(frame.maxWidth);
+      style.minHeight = px(frame.minHeight);
+      style.height = px(frame.idealHeight);
+      style.maxHeight = px(frame.maxHeight);
+    }
+    style.alignItems = HORIZONTAL_ALIGNMENTS[fields.alignment.horizontal];
+    style.justifyContent = VERTICAL_ALIGNMENTS[fields.alignment.vertical];
+    break;
+  }
+  case 'foregroundStyle':
+    style.color = cssColor(fields.style);
+    break;
+  case 'background':
+    style.background = cssColor(fields.style);
+    break;
+  case 'scaleEffect':
+    style.transform = `scale(${fields.factor})`;
+    style.transformOrigin = `${fields.anchor.value.x * 100}% ${fields.anchor.value.y * 100}%`;
+    break;
+  case 'rotationEffect':
+    style.transform = `rotate(${fields.angle.radians}rad)`;
+    style.transformOrigin = `${fields.anchor.value.x * 100}% ${fields.anchor.value.y * 100}%`;
+    break;
+  case 'help':
+    element.title = fields.text;
+    break;
+  case 'border':
+    style.border = `${fields.width}px solid ${cssColor(fields.style)}`;
+    break;
+  case 'shadow':
+    style.filter = `drop-shadow(${fields.offset.x}px ${fields.offset.y}px ${fields.radius}px ${cssColor(fields.color)})`;
+    break;
+  case 'blur':
+    style.filter = `blur(${fields.radius}px)`;
+    break;
+  case 'cornerRadius':
+    style.borderRadius = `${fields.radius}px`;
+    style.overflow = 'hidden';
+    break;
+  case 'zIndex':
+    style.zIndex = fields.zIndex;
+    break;
+  case 'hidden':
+    style.visibility = fields.isHidden ? 'hidden' : '';
+    break;
+  case 'disabled':
+    element.inert = fields.isDisabled;
+    element.classList.toggle('nuit-disabled', fields.isDisabled);
+    break;
+  case 'grayscale':
+    style.filter = `grayscale(${fields.intensity})`;
+    break;
+  case 'brightness':
+    style.filter = `brightness(${1 + fields.amount})`;
+    break;
+  case 'contrast':
+    style.filter = `contrast(${fields.amount})`;
+    break;
+  case 'saturation':
+    style.filter = `saturate(${fields.amount})`;
+    break;
+  case 'hueRotation':
+    style.filter = `hue-rotate(${fields.angle.radians}rad)`;
+    break;
+  case 'clipped':
+    style.overflow = 'hidden';
+    break;
+  case 'gridCellColumns':
+    style.gridColumnEnd = `span ${fields.count}`;
+    break;
+  case 'accessibilityLabel':
+    element.setAttribute('aria-label', fields.label);
+    break;
+  case 'accessibilityHidden':
+    element.setAttribute('aria-hidden', fields.isHidden);
+    break;
+  default:
+    // Other modifiers are not supported yet
+    break;
+  }
+}
+
+// Renderers
+
+/**
+ * Creates and updates the elements presenting the nodes of each kind. Nodes
+ * without a renderer are presented by a generic container.
+ */
+const RENDERERS = {
+  empty: {
+    create: () => document.createElement('span'),
+    update: () => {},
+  },
+  text: {
+    create: () => document.createElement('span'),
+    update: (element, { content }) => setText(element, content),
+  },
+  textField: {
+    create: (path, { kind }) => {
+      const input = document.createElement(kind === 'multiline' ? 'textarea' : 'input');
+      if (kind === 'secure') {
+        input.type = 'password';
+      }
+      // The values sent while focused, which we don't reset the input to when
+      // renders lag behind the typing
+      input.sentValues = new Set();
+      input.addEventListener('input', () => {
+        input.sentValues.add(input.value);
+        fire(path, { updateText: { content: input.value } });
+      });
+      input.addEventListener('focus', () => fire(path, { updateFocus: { isFocused: true } }));
+      input.addEventListener('blur', () => {
+        input.sentValues.clear();
+        fire(path, { updateFocus: { isFocused: false } });
+      });
+      input.addEventListener('keydown', event => {
+        if (event.key === 'Enter' && kind !== 'multiline') {
+          fire(path, { submit: {} });
+        }
+      });
+      return input;
+    },
+    update: (input, { content, prompt, isAutocorrectionDisabled }) => {
+      if (input.value !== content && !input.sentValues.has(content)) {
+        input.value = content;
+      }
+      input.placeholder = prompt ?? '';
+      input.spellcheck = !isAutocorrectionDisabled;
+    },
+  },
+  button: {
+    create: path => {
+      const button = document.createElement('button');
+      button.addEventListener('click', () => fire(path, { buttonTap: {} }));
+      return button;
+    },
+    update: (button, { label, role }, path) => {
+      button.classList.toggle('destructive', role === 'destructive');
+      reconcile(button, [child(path, label)]);
+    },
+  },
+  picker: {
+    create: path => {
+      const select = document.createElement('select');
+      select.addEventListener('change', () => fire(path, { updatePickerSelection: { id: JSON.parse(select.value) } }));
+      return createLabel(document.createElement('span'), select);
+    },
+    update: (element, { title, selection, content }, path) => {
+      const [titleElement, select] = element.children;
+      setText(titleElement, title);
+      // Like in SwiftUI, the options are identified by their ids
+      const options = flatten(path, content).map(([optionPath, option]) => [JSON.stringify(optionPath.at(-1)), firstText(option) ?? '']);
+      const signature = JSON.stringify(options);
+      if (select.dataset.options !== signature) {
+        select.replaceChildren(...options.map(([value, label]) => new Option(label, value)));
+        select.dataset.options = signature;
+      }
+      select.value = JSON.stringify(selection);
+    },
+  },
+  slider: {
+    create: path => {
+      const input = document.createElement('input');
+      input.type = 'range';
+      input.addEventListener('input', () => fire(path, { updateSliderValue: { value: input.valueAsNumber } }));
+      return input;
+    },
+    update: (input, { value, lowerBound, upperBound, step }) => {
+      input.min = lowerBound;
+      input.max = upperBound;
+      input.step = step ?? 'any';
+      if (input.valueAsNumber !== value) {
+        input.value = value;
+      }
+    },
+  },
+  toggle: {
+    create: path => {
+      const checkbox = document.createElement('input');
+      checkbox.type = 'checkbox';
+      checkbox.addEventListener('change', () => fire(path, { updateToggle: { isOn: checkbox.checked } }));
+      return createLabel(checkbox, document.createElement('span'));
+    },
+    update: (element, { title, isOn }) => {
+      const [checkbox, titleElement] = element.children;
+      checkbox.checked = isOn;
+      setText(titleElement, title);
+    },
+  },
+  stepper: {
+    create: path => {
+      const input = document.createElement('input');
+      input.type = 'number';
+      input.addEventListener('change', () => {
+        if (!Number.isNaN(input.valueAsNumber)) {
+          fire(path, { updateStepperValue: { value: Math.round(input.valueAsNumber) } });
+        }
+      });
+      return createLabel(document.createElement('span'), input);
+    },
+    update: (element, { title, value, lowerBound, upperBound, step }) => {
+      const [titleElement, input] = element.children;
+      setText(titleElement, title);
+      input.min = lowerBound;
+      input.max = upperBound;
+      input.step = step;
+      if (input.valueAsNumber !== value) {
+        input.value = value;
+      }
+    },
+  },
+  progressView: {
+    create: () => document.createElement('progress'),
+    update: (progress, { value }) => {
+      if (value == null) {
+        progress.removeAttribute('value');
+      } else {
+        progress.value = value;
+      }
+    },
+  },
+  group: {
+    update: (element, { children }, path) => {
+      reconcile(element, children.flatMap(identified => flatten(path, identified)));
+    },
+  },
+  vStack: {
+    update: stackUpdater(HORIZONTAL_ALIGNMENTS),
+  },
+  hStack: {
+    update: stackUpdater(VERTICAL_ALIGNMENTS),
+  },
+  zStack: {
+    update: (element, { alignment, wrapped }, path) => {
+      setPlaceItems(element, alignment);
+      reconcile(element, flatten(path, wrapped));
+    },
+  },
+  overlay: {
+    update: (element, { wrapped, alignment, overlayed }, path) => {
+      setPlaceItems(element, alignment);
+      reconcile(element, [child(path, wrapped), child(path, overlayed)]);
+    },
+  },
+  list: {
+    create: path => {
+      const list = document.createElement('div');
+      list.addEventListener('click', event => {
+        const row = event.target.closest('[data-row-id]');
+        if (list.selectionMode === 'none' || !row || row.closest('.nuit-list') !== list) {
+          return;
+        }
+        const rowId = JSON.parse(row.dataset.rowId);
+        const isSelected = row.classList.contains('selected');
+        const selection = list.selectionMode === 'multiple'
+          ? (isSelected ? list.selection.filter(id => JSON.stringify(id) !== row.dataset.rowId) : [...list.selection, rowId])
+          : [rowId];
+        fire(path, { updateSelection: { selection } });
+      });
+      return list;
+    },
+    update: (list, { wrapped, selectionMode, selection }, path) => {
+      list.selectionMode = selectionMode;
+      list.selection = selection;
+      list.classList.toggle('selectable', selectionMode !== 'none');
+      reconcile(list, flatten(path, wrapped));
+
+      // Like in SwiftUI, rows are identified by their ids
+      const selectedIds = new Set(selection.map(id => JSON.stringify(id)));
+      for (const row of listRows(list)) {
+        row.dataset.rowId = JSON.stringify(row.idPath.at(-1));
+        row.classList.toggle('selected', selectedIds.has(row.dataset.rowId));
+      }
+    },
+  },
+  section: {
+    create: () => {
+      const section = document.createElement('div');
+      for (const part of ['header', 'content', 'footer']) {
+        const element = document.createElement('div');
+        element.className = `nuit-section-${part}`;
+        section.append(element);
+      }
+      return section;
+    },
+    update: (section, { header, content, footer }, path) => {
+      const [headerElement, contentElement, footerElement] = section.children;
+      reconcile(headerElement, flatten(path, header));
+      reconcile(contentElement, flatten(path, content));
+      reconcile(footerElement, flatten(path, footer));
+    },
+  },
+  grid: {
+    update: (element, { alignment, horizontalSpacing, verticalSpacing, wrapped }, path) => {
+      const rows = flatten(path, wrapped);
+      const columnCount = Math.max(1, ...rows.map(([rowPath, row]) => {
+        const [kind, fields] = unpack(row);
+        return kind === 'gridRow' ? flatten(rowPath, fields.wrapped).length : 1;
+      }));
+      element.style.gridTemplateColumns = `repeat(${columnCount}, auto)`;
+      element.style.columnGap = `${horizontalSpacing}px`;
+      element.style.rowGap = `${verticalSpacing}px`;
+      setPlaceItems(element, alignment);
+      reconcile(element, rows);
+    },
+  },
+  gridRow: {
+    update: (element, { alignment, wrapped }, path) => {
+      reconcile(element, flatten(path, wrapped));
+      for (const cell of element.children) {
+        cell.style.alignSelf = alignment == null ? '' : VERTICAL_ALIGNMENTS[alignment];
+      }
+    },
+  },
+  lazyVStack: lazyRenderer((element, { alignment, spacing }) => {
+    element.style.alignItems = HORIZONTAL_ALIGNMENTS[alignment];
+    element.style.gap = `${spacing}px`;
+  }),
+  lazyHStack: lazyRenderer((element, { alignment, spacing }) => {
+    element.style.alignItems = VERTICAL_ALIGNMENTS[alignment];
+    element.style.gap = `${spacing}px`;
+  }),
+  lazyList: lazyRenderer(() => {}),
+  lazyVGrid: lazyRenderer((element, { columns, alignment, spacing }) => {
+    element.style.gridTemplateColumns = columns.map(gridTrack).join(' ');
+    element.style.justifyItems = HORIZONTAL_ALIGNMENTS[alignment];
+    element.style.gap = `${spacing}px`;
+  }),
+  lazyHGrid: lazyRenderer((element, { rows, alignment, spacing }) => {
+    element.style.gridTemplateRows = rows.map(gridTrack).join(' ');
+    element.style.gridAutoFlow = 'column';
+    element.style.alignItems = VERTICAL_ALIGNMENTS[alignment];
+    element.style.gap = `${spacing}px`;
+  }),
+  image: {
+    create: () => document.createElement('img'),
+    update: (img, { source }) => {
+      const [kind, fields] = unpack(source);
+      if (kind === 'bytes') {
+        // The encoded image data is sent as a base64 string
+        img.src = `data:;base64,${fields.data}`;
+      } else {
+        img.removeAttribute('src');
+        img.alt = kind === 'symbol' ? fields.name : '';
+      }
+    },
+  },
+  shape: {
+    create: () => {
+      const svg = document.createElementNS(SVG_NAMESPACE, 'svg');
+      new ResizeObserver(() => drawShape(svg)).observe(svg);
+      return svg;
+    },
+    update: (svg, { shape }) => {
+      svg.shape = shape;
+      drawShape(svg);
+    },
+  },
+  gestured: {
+    create: () => {
+      const element = document.createElement('div');
+      element.addEventListener('click', event => {
+        const [gesturePath, gesture] = element.gesture;
+        const [kind, fields] = unpack(gesture);
+        if (kind === 'tap' && event.detail === fields.count) {
+          fire(gesturePath, { gesture: { gesture: { tap: {} } } });
+        }
+      });
+      return element;
+    },
+    update: (element, { wrapped, gesture }, path) => {
+      element.gesture = child(path, gesture);
+      reconcile(element, [child(path, wrapped)]);
+    },
+  },
+  modified: {
+    update: (element, { wrapped, modifier }, path) => {
+      resetModifier(element);
+      applyModifier(element, ...unpack(modifier));
+      reconcile(element, [child(path, wrapped)]);
+    },
+  },
+};
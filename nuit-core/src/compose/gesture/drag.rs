@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{Context, DragEvent, EventResponse, GestureEvent, GestureNode, IdPath};
+use crate::{Context, DragEvent, EventResponse, FireError, GestureEvent, GestureNode, IdPath};
 
 use super::Gesture;
 
@@ -25,14 +25,16 @@ impl<F> DragGesture<F> where F: Fn(&DragEvent) {
 }
 
 impl<F> Gesture for DragGesture<F> where F: Fn(&DragEvent) {
-    fn fire(&self, event: &GestureEvent, event_path: &IdPath, _context: &Context) -> EventResponse {
-        assert!(event_path.is_root());
+    fn fire(&self, event: &GestureEvent, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
         if let GestureEvent::Drag { drag } = event {
             (self.action)(drag);
+            Ok(EventResponse::default())
         } else {
-            eprintln!("Warning: Ignoring non-drag gesture event {event:?} targeted to DragGesture at {event_path:?}");
+            Err(FireError::TypeMismatch { event: format!("{event:?}"), view: "DragGesture" })
         }
-        EventResponse::default()
     }
 
     fn render(&self, _context: &Context) -> GestureNode {
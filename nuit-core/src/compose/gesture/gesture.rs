@@ -1,4 +1,4 @@
-use crate::{Bind, Context, EventResponse, GestureEvent, GestureNode, IdPath};
+use crate::{Bind, Context, EventResponse, FireError, GestureEvent, GestureNode, IdPath};
 
 /// A composable gesture.
 pub trait Gesture: Bind {
@@ -8,7 +8,13 @@ pub trait Gesture: Bind {
         panic!("Gesture does not have a body!")
     }
 
-    fn fire(&self, event: &GestureEvent, event_path: &IdPath, context: &Context) -> EventResponse {
+    /// Fires the given gesture event at the given path (relative to this view).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the path doesn't point to a view that can handle the event,
+    /// e.g. since the event is stale.
+    fn fire(&self, event: &GestureEvent, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         self.bind(context);
         self.body().fire(event, event_path, context)
     }
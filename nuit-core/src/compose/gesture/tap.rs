@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{Context, EventResponse, Gesture, GestureEvent, GestureNode, IdPath};
+use crate::{Context, EventResponse, FireError, Gesture, GestureEvent, GestureNode, IdPath};
 
 /// A gesture recognizing a tap.
 #[derive(Bind)]
@@ -24,14 +24,16 @@ impl<F> TapGesture<F> where F: Fn() {
 }
 
 impl<F> Gesture for TapGesture<F> where F: Fn() {
-    fn fire(&self, event: &GestureEvent, event_path: &IdPath, _context: &Context) -> EventResponse {
-        assert!(event_path.is_root());
+    fn fire(&self, event: &GestureEvent, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
         if let GestureEvent::Tap {} = event {
             (self.action)();
+            Ok(EventResponse::default())
         } else {
-            eprintln!("Warning: Ignoring non-tap gesture event {event:?} targeted to TapGesture at {event_path:?}");
+            Err(FireError::TypeMismatch { event: format!("{event:?}"), view: "TapGesture" })
         }
-        EventResponse::default()
     }
 
     fn render(&self, _context: &Context) -> GestureNode {
@@ -1,4 +1,4 @@
-use crate::{Bind, Context, Event, EventResponse, IdPath, Node, ShapeNode, View};
+use crate::{Bind, Context, Event, EventResponse, FireError, IdPath, Node, ShapeNode, View};
 
 /// A composable shape component.
 pub trait Shape {
@@ -21,8 +21,8 @@ impl Shape for NeverShape {}
 impl<T> Bind for T where T: Shape {}
 
 impl<T> View for T where T: Shape {
-    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> EventResponse {
-        EventResponse::default()
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
     }
 
     fn render(&self, _context: &Context) -> Node {
@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, ForEach, Id, IdPath, IdentifyExt, Node, View};
+
+type DeleteAction = Rc<dyn Fn(Vec<usize>)>;
+type MoveAction = Rc<dyn Fn(Vec<usize>, usize)>;
+
+/// A view that lets the user delete and reorder the rows of a wrapped
+/// [`ForEach`] inside a list. Rows are referred to by their indices.
+#[derive(Clone, Bind)]
+pub struct Editable<T> {
+    wrapped: T,
+    on_delete: Option<DeleteAction>,
+    on_move: Option<MoveAction>,
+}
+
+impl<T> Editable<T> {
+    #[must_use]
+    pub const fn new(wrapped: T) -> Self {
+        Self {
+            wrapped,
+            on_delete: None,
+            on_move: None,
+        }
+    }
+
+    /// Lets the user delete rows, invoking the given action with the indices
+    /// of the deleted rows.
+    #[must_use]
+    pub fn on_delete(mut self, action: impl Fn(Vec<usize>) + 'static) -> Self {
+        self.on_delete = Some(Rc::new(action));
+        self
+    }
+
+    /// Lets the user reorder rows, invoking the given action with the indices
+    /// of the moved rows and the index to move them before. The destination
+    /// refers to the indices before the move, i.e. moving the first of three
+    /// rows to the end uses the destination 3.
+    #[must_use]
+    pub fn on_move(mut self, action: impl Fn(Vec<usize>, usize) + 'static) -> Self {
+        self.on_move = Some(Rc::new(action));
+        self
+    }
+}
+
+impl<V> ForEach<V> where V: View {
+    /// Lets the user delete rows, see [`Editable::on_delete`].
+    #[must_use]
+    pub fn on_delete(self, action: impl Fn(Vec<usize>) + 'static) -> Editable<Self> {
+        Editable::new(self).on_delete(action)
+    }
+
+    /// Lets the user reorder rows, see [`Editable::on_move`].
+    #[must_use]
+    pub fn on_move(self, action: impl Fn(Vec<usize>, usize) + 'static) -> Editable<Self> {
+        Editable::new(self).on_move(action)
+    }
+}
+
+impl<T> View for Editable<T> where T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "Editable" }),
+            }
+        } else {
+            match event {
+                Event::DeleteRows { indices } => match &self.on_delete {
+                    Some(on_delete) => on_delete(indices.clone()),
+                    None => eprintln!("Warning: Ignoring row deletion for rows that cannot be deleted"),
+                },
+                Event::MoveRows { indices, destination } => match &self.on_move {
+                    Some(on_move) => on_move(indices.clone(), *destination),
+                    None => eprintln!("Warning: Ignoring row move for rows that cannot be moved"),
+                },
+                _ => {},
+            }
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Editable {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            can_delete: self.on_delete.is_some(),
+            can_move: self.on_move.is_some(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, Event, ForEach, Id, IdPathBuf, List, Node, Root, Section, SelectionMode, State, Text, View, ViewExt};
+
+    #[derive(Bind)]
+    struct GroceryView {
+        items: State<Vec<String>>,
+        selection: State<HashSet<Id>>,
+        flagged: State<Option<String>>,
+    }
+
+    impl View for GroceryView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let items = self.items.clone();
+            let flagged = self.flagged.clone();
+            List::new(
+                Section::new(
+                    ForEach::new(items.get(), |item| {
+                        Text::new(item.clone())
+                            .context_menu(Button::with_text("Flag", clone!(flagged => move || {
+                                flagged.set(Some(item.clone()));
+                            })))
+                    })
+                    .on_delete(clone!(items => move |indices| {
+                        let mut new_items = items.get();
+                        for i in indices.into_iter().rev() {
+                            new_items.remove(i);
+                        }
+                        items.set(new_items);
+                    }))
+                    .on_move(clone!(items => move |indices, destination| {
+                        let mut new_items = items.get();
+                        let moved: Vec<_> = indices.iter().rev().map(|&i| new_items.remove(i)).collect();
+                        let destination = destination - indices.iter().filter(|&&i| i < destination).count();
+                        new_items.splice(destination..destination, moved.into_iter().rev());
+                        items.set(new_items);
+                    }))
+                )
+                .header(Text::new(self.flagged.get().map_or_else(|| "Groceries".to_owned(), |item| format!("Groceries ({item})"))))
+            )
+            .multi_selection(self.selection.binding())
+        }
+    }
+
+    #[test]
+    fn list_editing() {
+        let root = Root::new(GroceryView {
+            items: State::new(["Milk", "Eggs", "Bread", "Apples"].map(str::to_owned).to_vec()),
+            selection: State::default(),
+            flagged: State::default(),
+        });
+        let list_path = IdPathBuf::root();
+        let rows_path = list_path.child(0).child(1);
+        let row_ids = || match root.render().descendant(&rows_path) {
+            Some(Node::Editable { wrapped, can_delete: true, can_move: true }) => match wrapped.value() {
+                Node::Group { children } => children.iter().map(|row| row.id().clone()).collect::<Vec<_>>(),
+                node => panic!("Expected group, got {node:?}"),
+            },
+            node => panic!("Expected editable, got {node:?}"),
+        };
+        let header = || match root.render().descendant(&list_path.child(0).child(0)) {
+            Some(Node::Text { content }) => content.clone(),
+            node => panic!("Expected text, got {node:?}"),
+        };
+
+        assert_eq!(header(), "Groceries");
+        assert_eq!(row_ids(), vec![Id::string("Milk"), Id::string("Eggs"), Id::string("Bread"), Id::string("Apples")]);
+
+        root.fire_event(&list_path, &Event::UpdateSelection { selection: vec![Id::string("Eggs"), Id::string("Apples")] }).unwrap();
+        assert!(matches!(root.render(), Node::List { selection_mode: SelectionMode::Multiple, selection, .. }
+            if selection == vec![Id::string("Apples"), Id::string("Eggs")]));
+
+        root.fire_event(&rows_path, &Event::DeleteRows { indices: vec![1] }).unwrap();
+        assert_eq!(row_ids(), vec![Id::string("Milk"), Id::string("Bread"), Id::string("Apples")]);
+
+        // Moving the first row down by one uses the index after the next row
+        root.fire_event(&rows_path, &Event::MoveRows { indices: vec![0], destination: 2 }).unwrap();
+        assert_eq!(row_ids(), vec![Id::string("Bread"), Id::string("Milk"), Id::string("Apples")]);
+
+        root.fire_event(&rows_path.child(0).child("Apples").child(1), &Event::ButtonTap {}).unwrap();
+        assert_eq!(header(), "Groceries (Apples)");
+    }
+}
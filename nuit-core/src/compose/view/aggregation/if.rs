@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{View, Node, Context, Event, EventResponse, IdPath, Id, IdentifyExt};
+use crate::{View, Node, Context, Event, EventResponse, FireError, IdPath, Id, IdentifyExt};
 
 /// A conditional view that can take on one of at most two branches, depending
 /// on a boolean condition.
@@ -41,23 +41,18 @@ impl<T, F> If<T, F> {
 }
 
 impl<T, F> View for If<T, F> where T: View, F: View {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
-            match head {
-                Id::Index(0) => {
-                    if let Some(ref then_view) = self.then_view {
-                        return then_view.fire(event, event_path.tail(), &context.child(0));
-                    }
-                },
-                Id::Index(1) => {
-                    if let Some(ref else_view) = self.else_view {
-                        return else_view.fire(event, event_path.tail(), &context.child(1));
-                    }
-                },
-                i => panic!("Cannot fire event for child id {i} on HStack which only has two childs"),
+            match (head, &self.then_view, &self.else_view) {
+                (Id::Index(0), Some(then_view), _) => then_view.fire(event, event_path.tail(), &context.child(0)),
+                (Id::Index(1), _, Some(else_view)) => else_view.fire(event, event_path.tail(), &context.child(1)),
+                // The branch is not taken (anymore)
+                (Id::Index(0 | 1), _, _) => Err(FireError::StalePath { path: event_path.to_owned() }),
+                (id, _, _) => Err(FireError::UnknownChild { id, view: "If" }),
             }
+        } else {
+            Ok(EventResponse::default())
         }
-        EventResponse::default()
     }
 
     fn render(&self, context: &Context) -> Node {
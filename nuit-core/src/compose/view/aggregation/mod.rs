@@ -1,5 +1,7 @@
+mod editable;
 mod for_each;
 mod r#if;
 
+pub use editable::*;
 pub use for_each::*;
 pub use r#if::*;
@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{View, Node, Context, Event, EventResponse, IdPath};
+use crate::{View, Node, Context, Event, EventResponse, FireError, IdPath};
 
 /// A view that handles events using a provided closure.
 #[derive(Debug, Clone, Bind, PartialEq, Eq)]
@@ -19,7 +19,7 @@ impl<T, F> Handler<T, F> {
 }
 
 impl<T, F> View for Handler<T, F> where T: View, F: Fn(Event) {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if event_path.is_root() {
             (self.handle_event)(event.clone());
         }
@@ -1,3 +1,5 @@
 mod handler;
+mod task;
 
 pub use handler::*;
+pub use task::*;
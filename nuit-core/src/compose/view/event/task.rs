@@ -0,0 +1,163 @@
+use std::{cell::RefCell, future::Future};
+
+use crate::{Bind, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A view that runs an asynchronous task while its wrapped view is shown, i.e.
+/// starts it once the view is first rendered into the node tree and cancels it
+/// when the view (or any of its ancestors) disappears.
+///
+/// Since views are usually recreated when rendering, the task will be started
+/// again if the view reappears later. Views that are only resolved through
+/// events (e.g. navigation destinations) are not part of the node tree, so
+/// their tasks don't run.
+pub struct Task<T, F> {
+    wrapped: T,
+    future: RefCell<Option<F>>,
+}
+
+impl<T, F> Task<T, F> {
+    pub const fn new(wrapped: T, future: F) -> Self {
+        Self {
+            wrapped,
+            future: RefCell::new(Some(future)),
+        }
+    }
+}
+
+impl<T, F> Bind for Task<T, F> {}
+
+impl<T, F> View for Task<T, F> where T: View, F: Future<Output = ()> + 'static {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if event_path.is_root() && matches!(event, Event::Disappear {}) {
+            context.storage().cancel_tasks(context.id_path());
+        }
+        self.wrapped.fire(event, event_path, context)
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        // The task is started by the root once the view turns out to be shown
+        if let Some(future) = self.future.take() {
+            context.storage().register_task(context.id_path(), future);
+        }
+        self.wrapped.render(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::{Cell, RefCell}, future::Future, pin::Pin, rc::Rc, task::{Context, Poll, Waker}};
+
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, Event, If, Node, Root, State, Text, View, ViewExt, VStack};
+
+    /// A future that completes once it is signalled.
+    #[derive(Clone, Default)]
+    struct Signal {
+        is_signalled: Rc<Cell<bool>>,
+        waker: Rc<RefCell<Option<Waker>>>,
+    }
+
+    impl Signal {
+        fn signal(&self) {
+            self.is_signalled.set(true);
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    impl Future for Signal {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.is_signalled.get() {
+                Poll::Ready(())
+            } else {
+                *self.waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[derive(Bind)]
+    struct LoadingView {
+        is_shown: State<bool>,
+        status: State<String>,
+        signal: Signal,
+        completions: Rc<Cell<usize>>,
+    }
+
+    impl LoadingView {
+        fn new(signal: Signal, completions: Rc<Cell<usize>>) -> Self {
+            Self { is_shown: State::new(true), status: State::new("Idle"), signal, completions }
+        }
+    }
+
+    impl View for LoadingView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let is_shown = self.is_shown.clone();
+            let status = self.status.clone();
+            let signal = self.signal.clone();
+            let completions = self.completions.clone();
+            VStack::from((
+                If::new(is_shown.get(), || {
+                    Text::new(format!("Status: {}", status.get()))
+                        .task(async move {
+                            status.set("Loading");
+                            signal.await;
+                            status.set("Loaded");
+                            completions.set(completions.get() + 1);
+                        })
+                }),
+                Button::with_text("Toggle", clone!(is_shown => move || is_shown.set(!is_shown.get()))),
+            ))
+        }
+    }
+
+    /// Renders the root and runs its tasks until the state settles, returning
+    /// the status rendered (if any).
+    fn settle<T>(root: &Root<T>) -> Option<String> where T: View {
+        root.render();
+        root.run_tasks();
+        root.render()
+            .descendants()
+            .into_iter()
+            .find_map(|(_, node)| match node {
+                Node::Text { content } if content.starts_with("Status: ") => Some(content.clone()),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn completion() {
+        let signal = Signal::default();
+        let completions = Rc::new(Cell::new(0));
+        let root = Root::new(LoadingView::new(signal.clone(), completions.clone()));
+        assert_eq!(settle(&root).as_deref(), Some("Status: Loading"));
+
+        signal.signal();
+        assert_eq!(settle(&root).as_deref(), Some("Status: Loaded"));
+        assert_eq!(completions.get(), 1);
+    }
+
+    #[test]
+    fn cancellation() {
+        // Hiding the view before the task completes cancels it
+        let signal = Signal::default();
+        let completions = Rc::new(Cell::new(0));
+        let root = Root::new(LoadingView::new(signal.clone(), completions.clone()));
+        settle(&root);
+        let (toggle_path, _) = root.render()
+            .descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Button { .. }))
+            .unwrap();
+        root.fire_event(&toggle_path, &Event::ButtonTap {}).unwrap();
+        signal.signal();
+        assert_eq!(settle(&root), None);
+        assert_eq!(completions.get(), 0);
+    }
+}
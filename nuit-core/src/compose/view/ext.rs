@@ -1,8 +1,10 @@
+use std::future::Future;
+
 use serde::de::DeserializeOwned;
 
-use crate::{Alignment, Angle, DragEvent, DragGesture, EdgeSet, Event, Font, Frame, Gesture, Handler, Insets, Modified, ModifierNode, NavigationTitleDisplayMode, Style, TapGesture, UnitPoint, Vec2, View};
+use crate::{AccessibilityHeadingLevel, AccessibilityRole, Alignment, Angle, Binding, ContentMode, DragEvent, DragGesture, EdgeSet, EnvironmentKey, Event, EventModifiers, Font, Frame, Gesture, Handler, HorizontalAlignment, Insets, Interpolation, Key, KeyboardShortcut, Modified, ModifierNode, NavigationTitleDisplayMode, Style, TapGesture, Task, UnitPoint, Vec2, View};
 
-use super::{Gestured, NavigationDestination, Overlay};
+use super::{Alert, ConfirmationDialog, ContextMenu, Focused, Gestured, NavigationDestination, Overlay, Popover, Sheet, SwipeActions, Toolbar, WithAccessibilityAction, WithEnvironment};
 
 /// An extension trait with various convenience methods for views.
 pub trait ViewExt: Sized {
@@ -30,6 +32,10 @@ pub trait ViewExt: Sized {
         self.gesture(DragGesture::new_default(action))
     }
 
+    fn environment<K>(self, _key: K, value: impl Into<K::Value>) -> WithEnvironment<Self, K> where K: EnvironmentKey {
+        WithEnvironment::new(self, value.into())
+    }
+
     fn overlay_at<O>(self, alignment: Alignment, overlayed: O) -> Overlay<Self, O> where O: View {
         Overlay::new(self, alignment, overlayed)
     }
@@ -119,9 +125,65 @@ pub trait ViewExt: Sized {
         NavigationDestination::new(self, destination_func)
     }
 
+    fn toolbar<I>(self, items: I) -> Toolbar<Self, I> where I: View {
+        Toolbar::new(self, items)
+    }
+
+    fn sheet<C>(self, is_presented: Binding<bool>, content: C) -> Sheet<Self, C> where C: View {
+        Sheet::new(self, is_presented, content)
+    }
+
+    fn popover<C>(self, is_presented: Binding<bool>, content: C) -> Popover<Self, C> where C: View {
+        Popover::new(self, is_presented, content)
+    }
+
+    fn alert<A>(self, title: impl Into<String>, is_presented: Binding<bool>, actions: A) -> Alert<Self, A> where A: View {
+        Alert::new(self, title, is_presented, actions)
+    }
+
+    fn alert_with_message<A>(self, title: impl Into<String>, message: impl Into<String>, is_presented: Binding<bool>, actions: A) -> Alert<Self, A> where A: View {
+        Alert::new(self, title, is_presented, actions).message(message)
+    }
+
+    fn confirmation_dialog<A>(self, title: impl Into<String>, is_presented: Binding<bool>, actions: A) -> ConfirmationDialog<Self, A> where A: View {
+        ConfirmationDialog::new(self, title, is_presented, actions)
+    }
+
+    fn confirmation_dialog_with_message<A>(self, title: impl Into<String>, message: impl Into<String>, is_presented: Binding<bool>, actions: A) -> ConfirmationDialog<Self, A> where A: View {
+        ConfirmationDialog::new(self, title, is_presented, actions).message(message)
+    }
+
+    fn context_menu<A>(self, actions: A) -> ContextMenu<Self, A> where A: View {
+        ContextMenu::new(self, actions)
+    }
+
+    fn swipe_actions<A>(self, actions: A) -> SwipeActions<Self, A> where A: View {
+        SwipeActions::new(self, actions)
+    }
+
+    fn on_submit(self, action: impl Fn() + 'static) -> Handler<Self, impl Fn(Event)> {
+        Handler::new(self, move |e| {
+            if let Event::Submit {} = e {
+                action();
+            }
+        })
+    }
+
+    fn on_focus_change(self, action: impl Fn(bool) + 'static) -> Handler<Self, impl Fn(Event)> {
+        Handler::new(self, move |e| {
+            if let Event::UpdateFocus { is_focused } = e {
+                action(is_focused);
+            }
+        })
+    }
+
+    fn focused<V>(self, binding: Binding<Option<V>>, equals: V) -> Focused<Self, V> where V: 'static + Clone + PartialEq {
+        Focused::new(self, binding, equals)
+    }
+
     fn on_appear(self, action: impl Fn() + 'static) -> Handler<Self, impl Fn(Event)> {
         Handler::new(self, move |e| {
-            if let Event::Appear = e {
+            if let Event::Appear {} = e {
                 action();
             }
         })
@@ -129,12 +191,16 @@ pub trait ViewExt: Sized {
 
     fn on_disappear(self, action: impl Fn() + 'static) -> Handler<Self, impl Fn(Event)> {
         Handler::new(self, move |e| {
-            if let Event::Disappear = e {
+            if let Event::Disappear {} = e {
                 action();
             }
         })
     }
 
+    fn task<F>(self, future: F) -> Task<Self, F> where F: Future<Output = ()> + 'static {
+        Task::new(self, future)
+    }
+
     fn border(self, style: impl Into<Style>, width: impl Into<f64>) -> Modified<Self> {
         self.modifier(ModifierNode::Border { style: style.into(), width: width.into() })
     }
@@ -190,6 +256,62 @@ pub trait ViewExt: Sized {
     fn clipped(self) -> Modified<Self> {
         self.modifier(ModifierNode::Clipped {})
     }
+
+    fn resizable(self) -> Modified<Self> {
+        self.modifier(ModifierNode::Resizable {})
+    }
+
+    fn aspect_ratio(self, content_mode: impl Into<ContentMode>) -> Modified<Self> {
+        self.modifier(ModifierNode::AspectRatio { ratio: None, content_mode: content_mode.into() })
+    }
+
+    fn aspect_ratio_with(self, ratio: impl Into<f64>, content_mode: impl Into<ContentMode>) -> Modified<Self> {
+        self.modifier(ModifierNode::AspectRatio { ratio: Some(ratio.into()), content_mode: content_mode.into() })
+    }
+
+    fn interpolation(self, interpolation: impl Into<Interpolation>) -> Modified<Self> {
+        self.modifier(ModifierNode::Interpolation { interpolation: interpolation.into() })
+    }
+
+    fn keyboard_shortcut(self, key: impl Into<Key>, modifiers: EventModifiers) -> Modified<Self> {
+        self.modifier(ModifierNode::KeyboardShortcut { shortcut: KeyboardShortcut::new(key.into(), modifiers) })
+    }
+
+    fn accessibility_label(self, label: impl Into<String>) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityLabel { label: label.into() })
+    }
+
+    fn accessibility_hint(self, hint: impl Into<String>) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityHint { hint: hint.into() })
+    }
+
+    fn accessibility_value(self, value: impl Into<String>) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityValue { value: value.into() })
+    }
+
+    fn accessibility_role(self, role: AccessibilityRole) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityRole { role })
+    }
+
+    fn accessibility_heading(self, level: AccessibilityHeadingLevel) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityHeading { level })
+    }
+
+    fn accessibility_hidden(self, is_hidden: bool) -> Modified<Self> {
+        self.modifier(ModifierNode::AccessibilityHidden { is_hidden })
+    }
+
+    fn accessibility_action<F>(self, name: impl Into<String>, action: F) -> WithAccessibilityAction<Self, F> where F: Fn() {
+        WithAccessibilityAction::new(self, name, action)
+    }
+
+    fn grid_cell_columns(self, count: usize) -> Modified<Self> {
+        self.modifier(ModifierNode::GridCellColumns { count })
+    }
+
+    fn grid_column_alignment(self, alignment: impl Into<HorizontalAlignment>) -> Modified<Self> {
+        self.modifier(ModifierNode::GridColumnAlignment { alignment: alignment.into() })
+    }
 }
 
 impl<T> ViewExt for T where T: View {}
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use nuit_derive::Bind;
 
-use crate::{Context, Event, EventResponse, Geometry, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Context, Event, EventResponse, FireError, Geometry, Id, IdPath, IdentifyExt, Node, View};
 
 #[derive(Debug, Clone, PartialEq, Eq, Bind)]
 pub struct GeometryReader<F, T> {
@@ -29,12 +29,12 @@ where
     F: Fn(Geometry) -> T,
     T: View,
 {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
-                Id::Index(i) => panic!("Cannot fire event for child id {i} on GeometryReader"),
+                id @ Id::Index(_) => Err(FireError::UnknownChild { id, view: "GeometryReader" }),
                 Id::String(geometry_json) => {
-                    let geometry = serde_json::from_str(&geometry_json).expect("Could not deserialize geometry reader id");
+                    let geometry = serde_json::from_str(&geometry_json).map_err(|e| FireError::deserialization("geometry reader id", e))?;
                     let view = (self.view_func)(geometry);
                     view.fire(event, event_path.tail(), &context.child(geometry_json))
                 },
@@ -45,9 +45,9 @@ where
 
             let view = (self.view_func)(*geometry);
             let node = view.render(&context.child(id.clone())).identify(id);
-            EventResponse::Node { node }
+            Ok(EventResponse::Node { node })
         } else {
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
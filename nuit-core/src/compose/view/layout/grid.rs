@@ -0,0 +1,183 @@
+use nuit_derive::Bind;
+
+use crate::{Alignment, Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, VerticalAlignment, View, DEFAULT_SPACING};
+
+/// A view that arranges its [`GridRow`]s in rows and their children in
+/// columns. Children that aren't grid rows take up an entire row.
+#[derive(Debug, Clone, PartialEq, Bind)]
+pub struct Grid<T> {
+    alignment: Alignment,
+    horizontal_spacing: f64,
+    vertical_spacing: f64,
+    wrapped: T,
+}
+
+impl<T> Grid<T> {
+    #[must_use]
+    pub fn new(wrapped: T) -> Self {
+        Self {
+            alignment: Alignment::default(),
+            horizontal_spacing: DEFAULT_SPACING,
+            vertical_spacing: DEFAULT_SPACING,
+            wrapped,
+        }
+    }
+
+    /// Sets the alignment of the cells, which may be overridden for rows and
+    /// columns.
+    #[must_use]
+    pub fn alignment(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.alignment = alignment.into();
+        self
+    }
+
+    /// Sets the spacing between columns.
+    #[must_use]
+    pub fn horizontal_spacing(mut self, spacing: impl Into<f64>) -> Self {
+        self.horizontal_spacing = spacing.into();
+        self
+    }
+
+    /// Sets the spacing between rows.
+    #[must_use]
+    pub fn vertical_spacing(mut self, spacing: impl Into<f64>) -> Self {
+        self.vertical_spacing = spacing.into();
+        self
+    }
+}
+
+impl<T> From<T> for Grid<T> {
+    fn from(wrapped: T) -> Self {
+        Self::new(wrapped)
+    }
+}
+
+impl<T> View for Grid<T> where T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "Grid" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Grid {
+            alignment: self.alignment,
+            horizontal_spacing: self.horizontal_spacing,
+            vertical_spacing: self.vertical_spacing,
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+        }
+    }
+}
+
+/// A row of a [`Grid`], whose children are placed in consecutive columns.
+#[derive(Debug, Clone, PartialEq, Bind)]
+pub struct GridRow<T> {
+    alignment: Option<VerticalAlignment>,
+    wrapped: T,
+}
+
+impl<T> GridRow<T> {
+    #[must_use]
+    pub const fn new(wrapped: T) -> Self {
+        Self {
+            alignment: None,
+            wrapped,
+        }
+    }
+
+    /// Overrides the grid's vertical alignment for the cells in this row.
+    #[must_use]
+    pub fn alignment(mut self, alignment: impl Into<VerticalAlignment>) -> Self {
+        self.alignment = Some(alignment.into());
+        self
+    }
+}
+
+impl<T> From<T> for GridRow<T> {
+    fn from(wrapped: T) -> Self {
+        Self::new(wrapped)
+    }
+}
+
+impl<T> View for GridRow<T> where T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "GridRow" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::GridRow {
+            alignment: self.alignment,
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{Event, EventResponse, Grid, GridItem, GridItemSize, GridRow, HorizontalAlignment, Id, IdPathBuf, LazyVGrid, ModifierNode, Node, Root, Text, VerticalAlignment, View, ViewExt, VStack};
+
+    #[derive(Bind)]
+    struct DashboardView;
+
+    impl View for DashboardView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            VStack::from((
+                Grid::new((
+                    GridRow::new((Text::new("CPU"), Text::new("42%"))).alignment(VerticalAlignment::Top),
+                    GridRow::new((Text::new("Uptime: 3 days").grid_cell_columns(2),)),
+                ))
+                .horizontal_spacing(4),
+                LazyVGrid::new([GridItem::adaptive(80), GridItem::fixed(40).with_spacing(2)], 100, |i| Text::new(format!("Photo {i}"))),
+            ))
+        }
+    }
+
+    #[test]
+    fn grids() {
+        let root = Root::new(DashboardView);
+        let node = root.render();
+        let path = |i: usize| IdPathBuf::from(Id::index(0)).child(i);
+
+        let Some(Node::Grid { horizontal_spacing, vertical_spacing, wrapped, .. }) = node.descendant(&path(0)) else {
+            panic!("Expected grid");
+        };
+        assert_eq!((*horizontal_spacing, *vertical_spacing), (4.0, 10.0));
+        let rows = wrapped.value().children_from(&IdPathBuf::from(wrapped.id().clone()));
+        assert!(matches!(rows[0].1, Node::GridRow { alignment: Some(VerticalAlignment::Top), .. }));
+        assert!(matches!(
+            node.descendant(&path(0).child(0).child(1).child(0).child(0)),
+            Some(Node::Modified { modifier: ModifierNode::GridCellColumns { count: 2 }, .. })
+        ));
+
+        assert_eq!(node.descendant(&path(1)), Some(&Node::LazyVGrid {
+            columns: vec![
+                GridItem { size: GridItemSize::Adaptive { minimum: 80.0, maximum: None }, spacing: None },
+                GridItem { size: GridItemSize::Fixed { size: 40.0 }, spacing: Some(2.0) },
+            ],
+            alignment: HorizontalAlignment::Center,
+            spacing: 10.0,
+            count: 100,
+        }));
+        let Ok(EventResponse::Nodes { nodes: cells }) = root.fire_event(&path(1), &Event::GetRows { start: 98, end: 120 }) else {
+            panic!("Expected cells");
+        };
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[1].value(), &Node::Text { content: "Photo 99".to_owned() });
+    }
+}
@@ -0,0 +1,47 @@
+use std::marker::PhantomData;
+
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+use super::fire_lazy_rows;
+
+/// A virtualized list whose rows are only rendered once the backend needs
+/// them, e.g. because they are scrolled into view. This makes it suitable for
+/// large collections.
+///
+/// Since rows are requested through events, they are not part of the rendered
+/// node tree. They can't be found by searching the tree (e.g. in tests or
+/// snapshots), aren't exposed to accessibility through the tree and changes
+/// to them produce no diffs. Instead, backends re-request the rows they show
+/// whenever the view updates. Rows only appear (and run their tasks) while a
+/// backend binds them.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct LazyList<F, T> {
+    count: usize,
+    view_func: F,
+    phantom_view: PhantomData<T>,
+}
+
+impl<F, T> LazyList<F, T> where F: Fn(usize) -> T, T: View {
+    /// Creates a new list with the given number of rows, each created by the
+    /// given function from its index.
+    #[must_use]
+    pub const fn new(count: usize, view_func: F) -> Self {
+        Self {
+            count,
+            view_func,
+            phantom_view: PhantomData,
+        }
+    }
+}
+
+impl<F, T> View for LazyList<F, T> where F: Fn(usize) -> T, T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        fire_lazy_rows(self.count, &self.view_func, event, event_path, context, "LazyList")
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::LazyList { count: self.count }
+    }
+}
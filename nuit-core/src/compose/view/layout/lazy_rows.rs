@@ -0,0 +1,218 @@
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, View};
+
+/// Dispatches an event to a lazily rendered view with the given number of
+/// rows. Rows are identified by their index and only created when an event
+/// targets them or the backend requests them via [`Event::GetRows`].
+///
+/// Since rows are rendered outside of the root's render pass, backends fire
+/// [`Event::Appear`] and [`Event::Disappear`] at the rows they bind and
+/// unbind, which starts and cancels the tasks of the row along with notifying
+/// it.
+pub(crate) fn fire_lazy_rows<F, T>(
+    count: usize,
+    view_func: &F,
+    event: &Event,
+    event_path: &IdPath,
+    context: &Context,
+    view: &'static str,
+) -> Result<EventResponse, FireError>
+where
+    F: Fn(usize) -> T,
+    T: View,
+{
+    if let Some(head) = event_path.head() {
+        let is_row_event = event_path.tail().is_root();
+        let index = match head {
+            Id::Index(i) => usize::try_from(i).ok().filter(|&i| i < count),
+            Id::String(_) => None,
+        };
+        let Some(index) = index else {
+            if is_row_event && let Event::Disappear {} = event {
+                // Rows may have been removed by the time the backend unbinds them
+                context.storage().cancel_tasks(context.child(head).id_path());
+                return Ok(EventResponse::default());
+            }
+            return Err(FireError::UnknownChild { id: head, view });
+        };
+
+        let row = view_func(index);
+        let row_context = context.child(index);
+        let response = row.fire(event, event_path.tail(), &row_context)?;
+        if is_row_event {
+            match event {
+                Event::Appear {} => {
+                    // The tasks registered when the row was requested may
+                    // have been discarded by a render since, so we render the
+                    // row again to register them
+                    let _ = row.render(&row_context);
+                    context.storage().start_pending_tasks(row_context.id_path());
+                },
+                Event::Disappear {} => context.storage().cancel_tasks(row_context.id_path()),
+                _ => {},
+            }
+        }
+        Ok(response)
+    } else if let Event::GetRows { start, end } = event {
+        let nodes = (*start..(*end).min(count))
+            .map(|i| view_func(i).render(&context.child(i)).identify(i))
+            .collect();
+        Ok(EventResponse::Nodes { nodes })
+    } else {
+        Ok(EventResponse::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, future, rc::Rc};
+
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, Event, EventResponse, FireError, HorizontalAlignment, Id, IdPath, IdPathBuf, Identified, LazyList, LazyVStack, List, Node, Root, State, Text, View, ViewExt, VStack};
+
+    #[derive(Bind)]
+    struct RowView {
+        taps: State<i32>,
+        appearances: Rc<Cell<i32>>,
+        started_tasks: Rc<Cell<i32>>,
+    }
+
+    impl View for RowView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let taps = self.taps.clone();
+            let appearances = self.appearances.clone();
+            let started_tasks = self.started_tasks.clone();
+            Button::with_text(format!("Taps: {}", taps.get()), clone!(taps => move || taps.set(taps.get() + 1)))
+                .on_appear(clone!(appearances => move || appearances.set(appearances.get() + 1)))
+                .on_disappear(clone!(appearances => move || appearances.set(appearances.get() - 1)))
+                .task(async move {
+                    started_tasks.set(started_tasks.get() + 1);
+                    future::pending::<()>().await;
+                })
+        }
+    }
+
+    fn rows<T>(root: &Root<T>) -> Vec<Identified<Node>> where T: View {
+        match root.fire_event(IdPath::root(), &Event::GetRows { start: 0, end: 2 }) {
+            Ok(EventResponse::Nodes { nodes }) => nodes,
+            response => panic!("Unexpected response {response:?}"),
+        }
+    }
+
+    fn label(row: &Identified<Node>) -> (IdPathBuf, String) {
+        row.value().descendants_from(&IdPathBuf::from(row.id().clone()))
+            .into_iter()
+            .find_map(|(path, node)| match node {
+                Node::Button { label, .. } => match label.value() {
+                    Node::Text { content } => Some((path, content.clone())),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .expect("Row has no button")
+    }
+
+    #[test]
+    fn row_state() {
+        let appearances = Rc::new(Cell::new(0));
+        let started_tasks = Rc::new(Cell::new(0));
+        let root = Root::new(LazyList::new(2, clone!(appearances, started_tasks => move |_index| RowView {
+            taps: State::new(0),
+            appearances: appearances.clone(),
+            started_tasks: started_tasks.clone(),
+        })));
+        assert_eq!(root.render(), Node::LazyList { count: 2 });
+
+        // Each row keeps its own state, which is reflected once re-requested
+        let (button_path, _) = label(&rows(&root)[1]);
+        root.fire_event(&button_path, &Event::ButtonTap {}).unwrap();
+        root.render();
+        let labels: Vec<_> = rows(&root).iter().map(|row| label(row).1).collect();
+        assert_eq!(labels, vec!["Taps: 0".to_owned(), "Taps: 1".to_owned()]);
+
+        // Rows appear and run their tasks once bound, even if rendering the
+        // root in between discarded the tasks registered by requesting them
+        root.run_tasks();
+        assert_eq!(started_tasks.get(), 0);
+        root.render();
+        let row_path = IdPathBuf::from(Id::index(1));
+        root.fire_event(&row_path, &Event::Appear {}).unwrap();
+        root.run_tasks();
+        assert_eq!((appearances.get(), started_tasks.get()), (1, 1));
+
+        root.fire_event(&row_path, &Event::Disappear {}).unwrap();
+        assert_eq!(appearances.get(), 0);
+
+        // Rows that have been removed in the meantime can still be unbound
+        assert!(root.fire_event(&IdPathBuf::from(Id::index(5)), &Event::Disappear {}).is_ok());
+        assert!(root.fire_event(&IdPathBuf::from(Id::index(5)), &Event::Appear {}).is_err());
+    }
+
+    #[derive(Bind)]
+    struct LogView {
+        lines: Vec<String>,
+        rendered_rows: Rc<Cell<usize>>,
+        taps: State<usize>,
+    }
+
+    impl View for LogView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let lines = self.lines.clone();
+            let rendered_rows = self.rendered_rows.clone();
+            let taps = self.taps.clone();
+            VStack::from((
+                Text::new(format!("Taps: {}", taps.get())),
+                List::lazy(lines.len(), move |i| {
+                    rendered_rows.set(rendered_rows.get() + 1);
+                    Button::with_text(lines[i].clone(), clone!(taps => move || {
+                        taps.set(taps.get() + 1);
+                    }))
+                }),
+                LazyVStack::new(3, |i| Text::new(format!("Row {i}"))).spacing(2.0),
+            ))
+        }
+    }
+
+    fn rows_in<T>(root: &Root<T>, id_path: &IdPath, start: usize, end: usize) -> Vec<Identified<Node>> where T: View {
+        match root.fire_event(id_path, &Event::GetRows { start, end }) {
+            Ok(EventResponse::Nodes { nodes }) => nodes,
+            response => panic!("Unexpected response {response:?}"),
+        }
+    }
+
+    #[test]
+    fn requested_rows() {
+        let rendered_rows = Rc::new(Cell::new(0));
+        let root = Root::new(LogView {
+            lines: (0..50_000).map(|i| format!("Line {i}")).collect(),
+            rendered_rows: rendered_rows.clone(),
+            taps: State::default(),
+        });
+        let path = |i: usize| IdPathBuf::from(Id::index(0)).child(i);
+
+        // Rendering the view doesn't render any rows
+        let node = root.render();
+        assert_eq!(node.descendant(&path(1)), Some(&Node::LazyList { count: 50_000 }));
+        assert_eq!(rendered_rows.get(), 0);
+
+        let rows = rows_in(&root, &path(1), 100, 103);
+        assert_eq!(rows.iter().map(|row| row.id().clone()).collect::<Vec<_>>(), vec![Id::index(100), Id::index(101), Id::index(102)]);
+        assert_eq!(label(&rows[1]).1, "Line 101");
+        assert_eq!(rendered_rows.get(), 3);
+
+        // Events are routed to rows that aren't rendered as part of the tree
+        root.fire_event(&path(1).child(101), &Event::ButtonTap {}).unwrap();
+        assert_eq!(root.render().descendant(&path(0)), Some(&Node::Text { content: "Taps: 1".to_owned() }));
+        assert!(matches!(root.fire_event(&path(1).child(50_000), &Event::ButtonTap {}), Err(FireError::UnknownChild { .. })));
+
+        // Requested ranges are clamped to the number of rows
+        let rows = rows_in(&root, &path(2), 1, 10);
+        assert_eq!(root.render().descendant(&path(2)), Some(&Node::LazyVStack { alignment: HorizontalAlignment::Center, spacing: 2.0, count: 3 }));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].value(), &Node::Text { content: "Row 2".to_owned() });
+    }
+}
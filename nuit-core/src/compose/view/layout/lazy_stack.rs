@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, HorizontalAlignment, IdPath, Node, VerticalAlignment, View, DEFAULT_SPACING};
+
+use super::fire_lazy_rows;
+
+macro_rules! impl_lazy_stack {
+    ($(#[doc = $doc:expr])* $name:ident, $alignment:ident) => {
+        $(#[doc = $doc])*
+        #[derive(Debug, Clone, PartialEq, Bind)]
+        pub struct $name<F, T> {
+            alignment: $alignment,
+            spacing: f64,
+            count: usize,
+            view_func: F,
+            phantom_view: PhantomData<T>,
+        }
+
+        impl<F, T> $name<F, T> where F: Fn(usize) -> T, T: View {
+            #[doc = concat!("Creates a new ", stringify!($name), " with the given number of rows, each created by the given function from its index.")]
+            #[must_use]
+            pub fn new(count: usize, view_func: F) -> Self {
+                Self {
+                    alignment: Default::default(),
+                    spacing: DEFAULT_SPACING,
+                    count,
+                    view_func,
+                    phantom_view: PhantomData,
+                }
+            }
+
+            /// Sets the alignment of the rows.
+            #[must_use]
+            pub fn alignment(mut self, alignment: impl Into<$alignment>) -> Self {
+                self.alignment = alignment.into();
+                self
+            }
+
+            /// Sets the spacing between the rows.
+            #[must_use]
+            pub fn spacing(mut self, spacing: impl Into<f64>) -> Self {
+                self.spacing = spacing.into();
+                self
+            }
+        }
+
+        impl<F, T> View for $name<F, T> where F: Fn(usize) -> T, T: View {
+            fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+                fire_lazy_rows(self.count, &self.view_func, event, event_path, context, stringify!($name))
+            }
+
+            fn render(&self, _context: &Context) -> Node {
+                Node::$name {
+                    alignment: self.alignment,
+                    spacing: self.spacing,
+                    count: self.count,
+                }
+            }
+        }
+    };
+}
+
+impl_lazy_stack! {
+    /// A vertical stack whose rows are only rendered once scrolled into view.
+    /// Like for [`LazyList`](crate::LazyList), the rows are not part of the
+    /// rendered node tree.
+    LazyVStack, HorizontalAlignment
+}
+
+impl_lazy_stack! {
+    /// A horizontal stack whose rows are only rendered once scrolled into view.
+    /// Like for [`LazyList`](crate::LazyList), the rows are not part of the
+    /// rendered node tree.
+    LazyHStack, VerticalAlignment
+}
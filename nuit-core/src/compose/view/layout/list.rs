@@ -1,20 +1,70 @@
+use std::{collections::HashSet, rc::Rc};
+
 use nuit_derive::Bind;
 
-use crate::{Context, Event, EventResponse, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Access, Binding, Context, LazyList, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, SelectionMode, View};
 
 /// A view that arranges its children in a stylized list.
 #[derive(Debug, Clone, PartialEq, Eq, Bind)]
 pub struct List<T> {
     wrapped: T,
+    selection: ListSelection,
+}
+
+/// The binding a list reports selected rows to. Selections are equal if they
+/// use the same binding (or clones of it), since bindings cannot be compared
+/// by value.
+#[derive(Debug, Clone)]
+enum ListSelection {
+    None,
+    Single(Binding<Option<Id>>),
+    Multiple(Binding<HashSet<Id>>),
+}
+
+impl PartialEq for ListSelection {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::None, Self::None) => true,
+            (Self::Single(lhs), Self::Single(rhs)) => Rc::ptr_eq(&lhs.get, &rhs.get),
+            (Self::Multiple(lhs), Self::Multiple(rhs)) => Rc::ptr_eq(&lhs.get, &rhs.get),
+            _ => false,
+        }
+    }
 }
 
+impl Eq for ListSelection {}
+
 impl<T> List<T> {
     #[must_use]
     pub const fn new(wrapped: T) -> Self {
         Self {
-            wrapped
+            wrapped,
+            selection: ListSelection::None,
         }
     }
+
+    /// Lets the user select a single row, identified by its id.
+    #[must_use]
+    pub fn selection(mut self, selection: Binding<Option<Id>>) -> Self {
+        self.selection = ListSelection::Single(selection);
+        self
+    }
+
+    /// Lets the user select any number of rows, identified by their ids.
+    #[must_use]
+    pub fn multi_selection(mut self, selection: Binding<HashSet<Id>>) -> Self {
+        self.selection = ListSelection::Multiple(selection);
+        self
+    }
+}
+
+impl<F, T> List<LazyList<F, T>> where F: Fn(usize) -> T, T: View {
+    /// Creates a virtualized list with the given number of rows, see
+    /// [`LazyList`].
+    #[must_use]
+    pub const fn lazy(count: usize, view_func: F) -> LazyList<F, T> {
+        LazyList::new(count, view_func)
+    }
 }
 
 impl<T> From<T> for List<T> {
@@ -24,18 +74,51 @@ impl<T> From<T> for List<T> {
 }
 
 impl<T> View for List<T> where T: View {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
-                i => panic!("Cannot fire event for child id {i} on List which only has one child"),
+                id => Err(FireError::UnknownChild { id, view: "List" }),
             }
         } else {
-            EventResponse::default()
+            if let Event::UpdateSelection { selection } = event {
+                match &self.selection {
+                    ListSelection::None => eprintln!("Warning: Ignoring selection update for a list without selection"),
+                    ListSelection::Single(binding) => binding.set(selection.first().cloned()),
+                    ListSelection::Multiple(binding) => binding.set(selection.iter().cloned().collect::<HashSet<_>>()),
+                }
+            }
+            Ok(EventResponse::default())
         }
     }
 
     fn render(&self, context: &Context) -> Node {
-        Node::List { wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)) }
+        let (selection_mode, mut selection): (_, Vec<Id>) = match &self.selection {
+            ListSelection::None => (SelectionMode::None, Vec::new()),
+            ListSelection::Single(binding) => (SelectionMode::Single, binding.get().into_iter().collect()),
+            ListSelection::Multiple(binding) => (SelectionMode::Multiple, binding.get().into_iter().collect()),
+        };
+        // Sets are unordered, so we sort the ids to keep the node deterministic
+        selection.sort();
+        Node::List {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            selection_mode,
+            selection,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Binding, List, Text};
+
+    #[test]
+    fn equality() {
+        let selection = Binding::constant(None);
+        let list = List::new(Text::new("Row")).selection(selection.clone());
+        assert_eq!(list, List::new(Text::new("Row")).selection(selection));
+        assert_ne!(list, List::new(Text::new("Row")).selection(Binding::constant(None)));
+        assert_ne!(list, List::new(Text::new("Row")));
+        assert_eq!(List::new(Text::new("Row")), List::new(Text::new("Row")));
     }
 }
@@ -1,9 +1,21 @@
 mod geometry_reader;
+mod grid;
+mod lazy_grid;
+mod lazy_list;
+mod lazy_rows;
+mod lazy_stack;
 mod list;
 mod overlay;
+mod section;
 mod stack;
 
 pub use geometry_reader::*;
+pub use grid::*;
+pub use lazy_grid::*;
+pub use lazy_list::*;
+pub(crate) use lazy_rows::*;
+pub use lazy_stack::*;
 pub use list::*;
 pub use overlay::*;
+pub use section::*;
 pub use stack::*;
@@ -0,0 +1,67 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
+
+/// A view that groups rows of a list under an optional header and footer.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct Section<H, C, F> {
+    header: H,
+    content: C,
+    footer: F,
+}
+
+impl<C> Section<(), C, ()> {
+    #[must_use]
+    pub const fn new(content: C) -> Self {
+        Self {
+            header: (),
+            content,
+            footer: (),
+        }
+    }
+}
+
+impl<H, C, F> Section<H, C, F> {
+    /// Adds a header shown above the rows.
+    #[must_use]
+    pub fn header<H2>(self, header: H2) -> Section<H2, C, F> {
+        Section {
+            header,
+            content: self.content,
+            footer: self.footer,
+        }
+    }
+
+    /// Adds a footer shown below the rows.
+    #[must_use]
+    pub fn footer<F2>(self, footer: F2) -> Section<H, C, F2> {
+        Section {
+            header: self.header,
+            content: self.content,
+            footer,
+        }
+    }
+}
+
+impl<H, C, F> View for Section<H, C, F> where H: View, C: View, F: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.header.fire(event, event_path.tail(), &context.child(0)),
+                Id::Index(1) => self.content.fire(event, event_path.tail(), &context.child(1)),
+                Id::Index(2) => self.footer.fire(event, event_path.tail(), &context.child(2)),
+                id => Err(FireError::UnknownChild { id, view: "Section" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Section {
+            header: Box::new(self.header.render(&context.child(0)).identify(0)),
+            content: Box::new(self.content.render(&context.child(1)).identify(1)),
+            footer: Box::new(self.footer.render(&context.child(2)).identify(2)),
+        }
+    }
+}
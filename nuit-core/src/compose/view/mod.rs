@@ -3,6 +3,8 @@ mod event;
 mod ext;
 mod layout;
 mod navigation;
+mod presentation;
+mod toolbar;
 mod view;
 mod widget;
 mod wrapper;
@@ -12,6 +14,8 @@ pub use event::*;
 pub use ext::*;
 pub use layout::*;
 pub use navigation::*;
+pub use presentation::*;
+pub use toolbar::*;
 pub use view::*;
 pub use widget::*;
 pub use wrapper::*;
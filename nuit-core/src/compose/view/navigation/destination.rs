@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use nuit_derive::Bind;
 use serde::de::DeserializeOwned;
 
-use crate::{Context, Event, EventResponse, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
 
 #[derive(Debug, Clone, PartialEq, Eq, Bind)]
 pub struct NavigationDestination<T, F, V, D> {
@@ -32,13 +32,13 @@ where
     V: DeserializeOwned,
     D: View,
 {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
-                Id::Index(i) => panic!("Cannot fire event for child id {i} on NavigationDestination"),
+                id @ Id::Index(_) => Err(FireError::UnknownChild { id, view: "NavigationDestination" }),
                 Id::String(value_json) => {
-                    let value = serde_json::from_str(&value_json).expect("Could not deserialize navigation destination id");
+                    let value = serde_json::from_str(&value_json).map_err(|e| FireError::deserialization("navigation destination id", e))?;
                     let destination = (self.destination_func)(value);
                     destination.fire(event, event_path.tail(), &context.child(value_json))
                 },
@@ -47,13 +47,13 @@ where
             let value_json = serde_json::to_string(value).expect("Could not serialize navigation destination id");
             let id = Id::string(value_json);
 
-            let value = serde_json::from_value(value.clone()).expect("Could not deserialize navigation destination value");
+            let value = serde_json::from_value(value.clone()).map_err(|e| FireError::deserialization("navigation destination value", e))?;
             let destination = (self.destination_func)(value);
 
             let node = destination.render(&context.child(id.clone())).identify(id);
-            EventResponse::Node { node }
+            Ok(EventResponse::Node { node })
         } else {
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
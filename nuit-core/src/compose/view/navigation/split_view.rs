@@ -1,5 +1,5 @@
 use nuit_derive::Bind;
-use crate::{Context, Event, EventResponse, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
 
 #[derive(Debug, Bind)]
 pub struct NavigationSplitView<S, C, D> {
@@ -53,16 +53,16 @@ impl<T> From<T> for NavigationSplitView<T, (), ()> {
 }
 
 impl<S, C, D> View for NavigationSplitView<S, C, D> where S: View, C: View, D: View {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.sidebar.fire(event, event_path.tail(), &context.child(0)),
                 Id::Index(1) => self.content.fire(event, event_path.tail(), &context.child(1)),
                 Id::Index(2) => self.detail.fire(event, event_path.tail(), &context.child(2)),
-                i => panic!("Cannot fire event for child id {i} on NavigationSplitView which only has three children"),
+                id => Err(FireError::UnknownChild { id, view: "NavigationSplitView" }),
             }
         } else {
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
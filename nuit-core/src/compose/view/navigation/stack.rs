@@ -1,7 +1,7 @@
 use nuit_derive::Bind;
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::{Access, Binding, Context, Event, EventResponse, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
 
 #[derive(Debug, Clone, Bind)]
 pub struct NavigationStack<T, I> {
@@ -32,11 +32,11 @@ impl<T> From<T> for NavigationStack<T, ()> {
 }
 
 impl<T, I> View for NavigationStack<T, I> where T: View, I: Serialize + DeserializeOwned + 'static {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
-                i => panic!("Cannot fire event for child id {i} on NavigationStack which only has one child"),
+                id => Err(FireError::UnknownChild { id, view: "NavigationStack" }),
             }
         } else {
             if let Event::UpdateNavigationPath { path } = event {
@@ -44,13 +44,13 @@ impl<T, I> View for NavigationStack<T, I> where T: View, I: Serialize + Deserial
                     let path = path.iter()
                         .map(|item| serde_json::from_value(item.clone()))
                         .collect::<Result<Vec<I>, _>>()
-                        .expect("Could not deserialize navigation path");
+                        .map_err(|e| FireError::deserialization("navigation path", e))?;
                     path_binding.set(path);
                 } else {
                     eprintln!("Warning: Ignoring navigation path update since no path binding it set.");
                 }
             }
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
@@ -0,0 +1,51 @@
+use nuit_derive::Bind;
+
+use crate::{Binding, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+use super::Presented;
+
+/// A view that presents a set of choices related to the wrapped view while the
+/// given binding is true, e.g. to confirm a destructive action. The actions
+/// are usually buttons, tapping any of them dismisses the dialog and resets
+/// the binding.
+#[derive(Debug, Clone, Bind)]
+pub struct ConfirmationDialog<T, A> {
+    presented: Presented<T, A>,
+    title: String,
+    message: Option<String>,
+}
+
+impl<T, A> ConfirmationDialog<T, A> {
+    #[must_use]
+    pub fn new(wrapped: T, title: impl Into<String>, is_presented: Binding<bool>, actions: A) -> Self {
+        Self {
+            presented: Presented::new(wrapped, is_presented, actions, true),
+            title: title.into(),
+            message: None,
+        }
+    }
+
+    /// Adds a message shown below the title.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+impl<T, A> View for ConfirmationDialog<T, A> where T: View, A: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.presented.fire(event, event_path, context, "ConfirmationDialog")
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        let (wrapped, is_presented, actions) = self.presented.render(context);
+        Node::ConfirmationDialog {
+            wrapped,
+            title: self.title.clone(),
+            message: self.message.clone(),
+            is_presented,
+            actions,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
+
+/// A view that offers the given actions in a menu, usually shown on a secondary
+/// click or long press on the wrapped view. The actions are usually buttons.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct ContextMenu<T, A> {
+    wrapped: T,
+    actions: A,
+}
+
+impl<T, A> ContextMenu<T, A> {
+    #[must_use]
+    pub const fn new(wrapped: T, actions: A) -> Self {
+        Self {
+            wrapped,
+            actions,
+        }
+    }
+}
+
+impl<T, A> View for ContextMenu<T, A> where T: View, A: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                Id::Index(1) => self.actions.fire(event, event_path.tail(), &context.child(1)),
+                id => Err(FireError::UnknownChild { id, view: "ContextMenu" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::ContextMenu {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            actions: Box::new(self.actions.render(&context.child(1)).identify(1)),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod alert;
+mod confirmation_dialog;
+mod context_menu;
+mod popover;
+mod presented;
+mod sheet;
+mod swipe_actions;
+
+pub use alert::*;
+pub use confirmation_dialog::*;
+pub use context_menu::*;
+pub use popover::*;
+pub(crate) use presented::*;
+pub use sheet::*;
+pub use swipe_actions::*;
@@ -0,0 +1,149 @@
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, Id, IdPath, Identified, IdentifyExt, Node, View};
+
+/// Content that is presented over a wrapped view while a binding is true, as
+/// shared by sheets, popovers, alerts and confirmation dialogs. The wrapped
+/// view is identified by 0, the content by 1.
+#[derive(Debug, Clone)]
+pub(crate) struct Presented<T, C> {
+    wrapped: T,
+    /// Whether the content is presented, which is reset once it is dismissed.
+    presentation: Binding<bool>,
+    content: C,
+    /// Whether tapping a button in the content dismisses it, as for the
+    /// actions of alerts.
+    dismisses_on_tap: bool,
+}
+
+impl<T, C> Presented<T, C> {
+    pub(crate) const fn new(wrapped: T, is_presented: Binding<bool>, content: C, dismisses_on_tap: bool) -> Self {
+        Self {
+            wrapped,
+            presentation: is_presented,
+            content,
+            dismisses_on_tap,
+        }
+    }
+}
+
+impl<T, C> Presented<T, C> where T: View, C: View {
+    pub(crate) fn fire(&self, event: &Event, event_path: &IdPath, context: &Context, view: &'static str) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                Id::Index(1) => {
+                    let response = self.content.fire(event, event_path.tail(), &context.child(1))?;
+                    if self.dismisses_on_tap && let Event::ButtonTap {} = event {
+                        self.presentation.set(false);
+                    }
+                    Ok(response)
+                },
+                id => Err(FireError::UnknownChild { id, view }),
+            }
+        } else {
+            if let Event::Dismiss {} = event {
+                self.presentation.set(false);
+            }
+            Ok(EventResponse::default())
+        }
+    }
+
+    /// Renders the wrapped view, whether the content is presented and the
+    /// content. The content is only rendered while presented, so it appears
+    /// and disappears along with the presentation.
+    pub(crate) fn render(&self, context: &Context) -> (Box<Identified<Node>>, bool, Box<Identified<Node>>) {
+        let is_presented = self.presentation.get();
+        let rendered_content = if is_presented { self.content.render(&context.child(1)) } else { Node::Empty {} };
+        (
+            Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            is_presented,
+            Box::new(rendered_content.identify(1)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, ButtonRole, Event, IdPathBuf, Node, Root, State, Text, View, ViewExt, VStack};
+
+    #[derive(Bind, Default)]
+    struct PresentingView {
+        is_sheet_presented: State<bool>,
+        is_alert_presented: State<bool>,
+        deleted: State<bool>,
+    }
+
+    impl View for PresentingView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let is_sheet_presented = self.is_sheet_presented.clone();
+            let is_alert_presented = self.is_alert_presented.clone();
+            let deleted = self.deleted.clone();
+            VStack::from((
+                Button::with_text("Show Sheet", clone!(is_sheet_presented => move || is_sheet_presented.set(true))),
+                Button::with_text("Delete", clone!(is_alert_presented => move || is_alert_presented.set(true))),
+                Text::new(format!("Deleted: {}", deleted.get())),
+            ))
+            .sheet(is_sheet_presented.binding(), Text::new("Sheet"))
+            .alert_with_message("Delete?", "This cannot be undone.", is_alert_presented.binding(), (
+                Button::with_text("Cancel", || {}).role(ButtonRole::Cancel),
+                Button::with_text("Confirm", move || deleted.set(true)).role(ButtonRole::Destructive),
+            ))
+        }
+    }
+
+    /// The id path of the button labeled with the given text.
+    fn button_path(node: &Node, label: &str) -> IdPathBuf {
+        let (id_path, _) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Button { label: l, .. } if matches!(l.value(), Node::Text { content } if content == label)))
+            .unwrap_or_else(|| panic!("No button labeled {label:?} in {node:#?}"));
+        id_path
+    }
+
+    #[test]
+    fn sheet() {
+        let root = Root::new(PresentingView::default());
+        let is_presented = |node: &Node| node.descendants().into_iter().any(|(_, node)| matches!(node, Node::Sheet { is_presented: true, .. }));
+        let shows_sheet = |node: &Node| node.descendants().into_iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Sheet"));
+        let node = root.render();
+        assert!(!is_presented(&node));
+        assert!(!shows_sheet(&node));
+
+        root.fire_event(&button_path(&node, "Show Sheet"), &Event::ButtonTap {}).unwrap();
+        let node = root.render();
+        assert!(is_presented(&node));
+        assert!(shows_sheet(&node));
+
+        let (sheet_path, _) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Sheet { .. }))
+            .unwrap();
+        root.fire_event(&sheet_path, &Event::Dismiss {}).unwrap();
+        let node = root.render();
+        assert!(!is_presented(&node));
+        assert!(!shows_sheet(&node));
+    }
+
+    #[test]
+    fn alert() {
+        let root = Root::new(PresentingView::default());
+        root.fire_event(&button_path(&root.render(), "Delete"), &Event::ButtonTap {}).unwrap();
+        let node = root.render();
+        let Some((alert_path, Node::Alert { title, message, is_presented, .. })) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Alert { .. })) else {
+            panic!("No alert in {node:#?}");
+        };
+        assert_eq!(title, "Delete?");
+        assert_eq!(message.as_deref(), Some("This cannot be undone."));
+        assert!(is_presented);
+
+        root.fire_event(&button_path(&node, "Confirm"), &Event::ButtonTap {}).unwrap();
+        let node = root.render();
+        assert!(node.descendants().into_iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Deleted: true")));
+        assert!(matches!(node.descendant(&alert_path), Some(Node::Alert { is_presented: false, .. })));
+    }
+}
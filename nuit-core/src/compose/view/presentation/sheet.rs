@@ -0,0 +1,32 @@
+use nuit_derive::Bind;
+
+use crate::{Binding, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+use super::Presented;
+
+/// A view that presents a modal sheet over the wrapped view while the given
+/// binding is true. The binding is reset once the sheet is dismissed.
+#[derive(Debug, Clone, Bind)]
+pub struct Sheet<T, C> {
+    presented: Presented<T, C>,
+}
+
+impl<T, C> Sheet<T, C> {
+    #[must_use]
+    pub const fn new(wrapped: T, is_presented: Binding<bool>, content: C) -> Self {
+        Self {
+            presented: Presented::new(wrapped, is_presented, content, false),
+        }
+    }
+}
+
+impl<T, C> View for Sheet<T, C> where T: View, C: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.presented.fire(event, event_path, context, "Sheet")
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        let (wrapped, is_presented, presented_content) = self.presented.render(context);
+        Node::Sheet { wrapped, is_presented, content: presented_content }
+    }
+}
@@ -0,0 +1,40 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, ToolbarItemPlacement, View};
+
+/// An item in a toolbar with an explicit placement.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct ToolbarItem<T> {
+    placement: ToolbarItemPlacement,
+    wrapped: T,
+}
+
+impl<T> ToolbarItem<T> {
+    #[must_use]
+    pub const fn new(placement: ToolbarItemPlacement, wrapped: T) -> Self {
+        Self {
+            placement,
+            wrapped,
+        }
+    }
+}
+
+impl<T> View for ToolbarItem<T> where T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "ToolbarItem" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::ToolbarItem {
+            placement: self.placement,
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+        }
+    }
+}
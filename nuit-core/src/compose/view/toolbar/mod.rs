@@ -0,0 +1,5 @@
+mod item;
+mod toolbar;
+
+pub use item::*;
+pub use toolbar::*;
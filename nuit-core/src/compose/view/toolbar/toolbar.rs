@@ -0,0 +1,134 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, Node, View};
+
+/// A view that contributes items to the toolbar of the enclosing window or
+/// navigation view while it is shown. Items that aren't wrapped in a
+/// [`ToolbarItem`](crate::ToolbarItem) are placed automatically.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct Toolbar<T, I> {
+    wrapped: T,
+    items: I,
+}
+
+impl<T, I> Toolbar<T, I> {
+    #[must_use]
+    pub const fn new(wrapped: T, items: I) -> Self {
+        Self {
+            wrapped,
+            items,
+        }
+    }
+}
+
+impl<T, I> View for Toolbar<T, I> where T: View, I: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                Id::Index(1) => self.items.fire(event, event_path.tail(), &context.child(1)),
+                id => Err(FireError::UnknownChild { id, view: "Toolbar" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Toolbar {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            items: Box::new(self.items.render(&context.child(1)).identify(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, Event, EventModifiers, KeyboardShortcut, ModifierNode, Node, Root, State, Text, ToolbarItem, ToolbarItemPlacement, View, ViewExt};
+
+    #[derive(Bind, Default)]
+    struct ToolbarView {
+        count: State<i32>,
+    }
+
+    impl View for ToolbarView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let count = self.count.clone();
+            Text::new(format!("Count: {}", count.get()))
+                .toolbar((
+                    ToolbarItem::new(
+                        ToolbarItemPlacement::PrimaryAction,
+                        Button::with_text("Add", clone!(count => move || count.set(count.get() + 1)))
+                            .keyboard_shortcut('n', EventModifiers::COMMAND),
+                    ),
+                    Button::with_text("Reset", move || count.set(0))
+                        .keyboard_shortcut('r', EventModifiers::COMMAND | EventModifiers::SHIFT),
+                ))
+        }
+    }
+
+    /// Taps the button that the given keyboard shortcut is assigned to, like
+    /// a backend would when it is pressed.
+    fn press_shortcut(root: &Root<ToolbarView>, shortcut: KeyboardShortcut) {
+        let node = root.render();
+        let (shortcut_path, shortcut_node) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(
+                node,
+                Node::Modified { modifier: ModifierNode::KeyboardShortcut { shortcut: s }, .. } if *s == shortcut
+            ))
+            .unwrap();
+        let button_path = shortcut_node
+            .descendants_from(&shortcut_path)
+            .into_iter()
+            .find_map(|(path, node)| matches!(node, Node::Button { .. }).then_some(path))
+            .unwrap();
+        root.fire_event(&button_path, &Event::ButtonTap {}).unwrap();
+    }
+
+    /// Renders the root, returning the count text.
+    fn count_text(root: &Root<ToolbarView>) -> Option<String> {
+        root.render()
+            .descendants()
+            .into_iter()
+            .find_map(|(_, node)| match node {
+                Node::Text { content } if content.starts_with("Count: ") => Some(content.clone()),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn items_and_shortcuts() {
+        let root = Root::new(ToolbarView::default());
+        let placements: Vec<_> = root.render().descendants()
+            .into_iter()
+            .filter_map(|(_, node)| match node {
+                Node::ToolbarItem { placement, .. } => Some(*placement),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(placements, vec![ToolbarItemPlacement::PrimaryAction]);
+
+        // The "Add" button is the only toolbar item
+        let node = root.render();
+        let (item_path, item) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::ToolbarItem { .. }))
+            .unwrap();
+        let (add_path, _) = item
+            .descendants_from(&item_path)
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Button { .. }))
+            .unwrap();
+        root.fire_event(&add_path, &Event::ButtonTap {}).unwrap();
+        assert_eq!(count_text(&root).as_deref(), Some("Count: 1"));
+        press_shortcut(&root, KeyboardShortcut::new('n'.into(), EventModifiers::COMMAND));
+        assert_eq!(count_text(&root).as_deref(), Some("Count: 2"));
+        press_shortcut(&root, KeyboardShortcut::new('r'.into(), EventModifiers::COMMAND | EventModifiers::SHIFT));
+        assert_eq!(count_text(&root).as_deref(), Some("Count: 0"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Node, Bind, Context, Event, EventResponse, IdPath, Id, IdentifyExt};
+use crate::{Node, Bind, Context, Event, EventResponse, FireError, IdPath, Id, IdentifyExt};
 
 /// The primary view trait. Represents a lightweight UI component.
 pub trait View: Bind {
@@ -8,9 +8,13 @@ pub trait View: Bind {
         panic!("View does not have a body!")
     }
 
-    // TODO: Return an error type from fire to avoid panicking on wrong paths?
-
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    /// Fires the given event at the given path (relative to this view).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the path doesn't point to a view that can handle the event,
+    /// e.g. since the event is stale.
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         self.bind(context);
         self.body().fire(event, event_path, context)
     }
@@ -24,14 +28,14 @@ pub trait View: Bind {
 macro_rules! impl_tuple_view {
     ($($tvs:ident),*) => {
         impl<$($tvs),*> View for ($($tvs,)*) where $($tvs: View),* {
-            fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+            fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
                 if let Some(head) = event_path.head() {
                     match head {
                         $(${ignore($tvs)} Id::Index(${index()}) => self.${index()}.fire(event, &event_path.tail(), &context.child(${index()})),)*
-                        i => panic!("Cannot fire event for child id {} on a {}-tuple", i, ${count($tvs, 0)})
+                        id => Err(FireError::UnknownChild { id, view: "tuple" }),
                     }
                 } else {
-                    EventResponse::default()
+                    Ok(EventResponse::default())
                 }
             }
 
@@ -56,8 +60,8 @@ impl View for NeverView {}
 // overhead of using a Group requiring a Vec.
 
 impl View for () {
-    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> EventResponse {
-        EventResponse::default()
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
     }
 
     fn render(&self, _context: &Context) -> Node {
@@ -72,14 +76,14 @@ impl<T> View for (T,) where T: View {
         self.0.body()
     }
 
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.0.fire(event, event_path.tail(), &context.child(0)),
-                i => panic!("Cannot fire event for child id {i} on 1-tuple")
+                id => Err(FireError::UnknownChild { id, view: "tuple" }),
             }
         } else {
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{View, Node, Context, Event, EventResponse, IdPath, Id, IdentifyExt};
+use crate::{ButtonRole, View, Node, Context, Event, EventResponse, FireError, IdPath, Id, IdentifyExt};
 
 use super::Text;
 
@@ -9,6 +9,7 @@ use super::Text;
 pub struct Button<T, F> {
     label: T,
     action: Option<F>,
+    role: Option<ButtonRole>,
 }
 
 impl<T, F> Button<T, F> {
@@ -17,8 +18,16 @@ impl<T, F> Button<T, F> {
         Self {
             label,
             action: Some(action),
+            role: None,
         }
     }
+
+    /// Assigns a role to the button.
+    #[must_use]
+    pub const fn role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
 }
 
 impl<F> Button<Text, F> {
@@ -27,26 +36,30 @@ impl<F> Button<Text, F> {
         Self {
             label: Text::new(label),
             action: Some(action),
+            role: None,
         }
     }
 }
 
 impl<T, F> View for Button<T, F> where T: View, F: Fn() + 'static {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.label.fire(event, event_path.tail(), &context.child(0)),
-                i => panic!("Cannot fire event for child id {i} on Button which only has one child"),
+                id => Err(FireError::UnknownChild { id, view: "Button" }),
             }
         } else {
             if let Event::ButtonTap {} = event && let Some(ref action) = self.action {
                 action();
             }
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
     fn render(&self, context: &Context) -> Node {
-        Node::Button { label: Box::new(self.label.render(&context.child(0)).identify(0)) }
+        Node::Button {
+            label: Box::new(self.label.render(&context.child(0)).identify(0)),
+            role: self.role,
+        }
     }
 }
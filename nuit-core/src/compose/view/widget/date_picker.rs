@@ -0,0 +1,52 @@
+use nuit_derive::Bind;
+
+use crate::{Access, Binding, CalendarDate, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A control for choosing a calendar date.
+#[derive(Debug, Clone, Bind)]
+pub struct DatePicker {
+    title: String,
+    selection: Binding<CalendarDate>,
+}
+
+impl DatePicker {
+    pub fn new(title: impl Into<String>, selection: Binding<CalendarDate>) -> Self {
+        Self { title: title.into(), selection }
+    }
+}
+
+impl View for DatePicker {
+    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
+        if let Event::UpdateDate { date } = event {
+            self.selection.set(*date);
+        }
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::DatePicker {
+            title: self.title.clone(),
+            date: self.selection.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{clone, Binding, CalendarDate, DatePicker, Event, IdPath, Node, Root};
+
+    #[test]
+    fn update() {
+        let date = Rc::new(Cell::new(CalendarDate::new(2024, 2, 28).unwrap()));
+        let root = Root::new(DatePicker::new("Date", Binding::with_get_set(clone!(date => move || date.get()), clone!(date => move |value| date.set(value)))));
+
+        let leap_day = CalendarDate::new(2024, 2, 29).unwrap();
+        root.fire_event(IdPath::root(), &Event::UpdateDate { date: leap_day }).unwrap();
+        assert!(matches!(root.render(), Node::DatePicker { date, .. } if date == leap_day));
+    }
+}
@@ -0,0 +1,94 @@
+use std::{path::PathBuf, sync::Arc};
+
+use nuit_derive::Bind;
+
+use crate::{View, Node, Context, Event, EventResponse, FireError, IdPath, ImageSource};
+
+/// An image, e.g. loaded from a file or provided by the platform as a symbol.
+///
+/// Images are shown at their natural size unless made
+/// [`resizable`](crate::ViewExt::resizable).
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct Image {
+    source: ImageSource,
+}
+
+impl Image {
+    #[must_use]
+    pub const fn new(source: ImageSource) -> Self {
+        Self { source }
+    }
+
+    /// An image loaded from the file at the given path.
+    #[must_use]
+    pub fn from_file(path: impl Into<PathBuf>) -> Self {
+        Self::new(ImageSource::File { path: path.into() })
+    }
+
+    /// An image decoded from the given bytes, e.g. the contents of a PNG or
+    /// JPEG file.
+    #[must_use]
+    pub fn from_bytes(data: impl Into<Arc<[u8]>>) -> Self {
+        Self::new(ImageSource::Bytes { data: data.into() })
+    }
+
+    /// A symbol provided by the platform, i.e. an SF Symbol on Apple platforms
+    /// and an icon from the icon theme on Linux. Since the names differ, they
+    /// usually have to be chosen per platform.
+    #[must_use]
+    pub fn symbol(name: impl Into<String>) -> Self {
+        Self::new(ImageSource::Symbol { name: name.into() })
+    }
+}
+
+impl View for Image {
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::Image { source: self.source.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ContentMode, Image, Interpolation, Node, Root, ViewExt, VStack};
+
+    #[test]
+    fn sources_and_modifiers() {
+        let root = Root::new(VStack::from((
+            Image::symbol("star")
+                .resizable()
+                .aspect_ratio(ContentMode::Fit),
+            Image::from_bytes([0x89, b'P', b'N', b'G'])
+                .interpolation(Interpolation::None),
+        )));
+        let node = root.render();
+
+        let sources: Vec<_> = node.descendants()
+            .into_iter()
+            .filter(|(_, node)| matches!(node, Node::Image { .. }))
+            .map(|(_, node)| serde_json::to_value(node).unwrap())
+            .collect();
+        assert_eq!(sources, vec![
+            json!({ "image": { "source": { "symbol": { "name": "star" } } } }),
+            json!({ "image": { "source": { "bytes": { "data": "iVBORw==" } } } }),
+        ]);
+
+        let modifiers: Vec<_> = node.descendants()
+            .into_iter()
+            .filter_map(|(_, node)| match node {
+                Node::Modified { modifier, .. } => Some(serde_json::to_value(modifier).unwrap()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(modifiers, vec![
+            json!({ "aspectRatio": { "ratio": null, "contentMode": "fit" } }),
+            json!({ "resizable": {} }),
+            json!({ "interpolation": { "interpolation": "none" } }),
+        ]);
+    }
+}
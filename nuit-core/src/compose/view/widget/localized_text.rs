@@ -0,0 +1,122 @@
+use fluent_bundle::{FluentArgs, FluentValue};
+use nuit_derive::Bind;
+
+use crate::{View, Node, Context, Event, EventResponse, FireError, IdPath, LocaleKey, LocalizationKey};
+
+/// A text label whose content is looked up by message id in the localization
+/// from the environment, using the locale from the environment. Arguments can
+/// be used for interpolation and plural or gender selection.
+#[derive(Debug, Clone, PartialEq, Bind)]
+pub struct LocalizedText {
+    id: String,
+    args: Vec<(String, FluentValue<'static>)>,
+}
+
+impl LocalizedText {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Passes an argument (e.g. a string or a number) to the message.
+    #[must_use]
+    pub fn arg(mut self, name: impl Into<String>, value: impl Into<FluentValue<'static>>) -> Self {
+        self.args.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl View for LocalizedText {
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        let localization = context.environment().get::<LocalizationKey>();
+        let locale = context.environment().get::<LocaleKey>();
+        let mut args = FluentArgs::new();
+        for (name, value) in &self.args {
+            args.set(name.as_str(), value.clone());
+        }
+        let formatted = localization.format(&locale, &self.id, Some(&args)).unwrap_or_else(|| {
+            localization.report_missing(&locale, &self.id);
+            self.id.clone()
+        });
+        Node::Text { content: formatted }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{Access, Button, Event, Locale, LocaleKey, Localization, LocalizationKey, LocalizedText, Node, Root, State, View, ViewExt, VStack};
+
+    const EN: &str = "
+unread = { $count ->
+    [one] One unread message
+   *[other] { $count } unread messages
+}
+greeting = { $gender ->
+    [female] Welcome, madam
+   *[other] Welcome
+}
+";
+    const DE: &str = "
+unread = { $count ->
+    [one] Eine ungelesene Nachricht
+   *[other] { $count } ungelesene Nachrichten
+}
+";
+
+    #[derive(Bind)]
+    struct InboxView {
+        locale: State<Locale>,
+    }
+
+    impl View for InboxView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let locale = self.locale.clone();
+            VStack::from((
+                LocalizedText::new("unread").arg("count", 1),
+                LocalizedText::new("unread").arg("count", 3),
+                LocalizedText::new("greeting").arg("gender", "female"),
+                Button::with_text("Deutsch", move || locale.set(Locale::new("de").unwrap())),
+            ))
+            .environment(LocaleKey, self.locale.get())
+        }
+    }
+
+    #[test]
+    fn localization() {
+        // The localization is provided once for the whole root
+        let root = Root::new(InboxView { locale: State::new(Locale::new("en-GB").unwrap()) });
+        root.set_environment::<LocalizationKey>(Localization::new(Locale::new("en").unwrap())
+            .with_resource(Locale::new("en").unwrap(), EN)
+            .with_resource(Locale::new("de").unwrap(), DE));
+        let texts = || -> Vec<String> {
+            root.render()
+                .descendants()
+                .into_iter()
+                .filter_map(|(_, node)| match node {
+                    Node::Text { content } => Some(content.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(texts()[..3], ["One unread message", "3 unread messages", "Welcome, madam"]);
+
+        let node = root.render();
+        let (button_path, _) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::Button { .. }))
+            .unwrap();
+        root.fire_event(&button_path, &Event::ButtonTap {}).unwrap();
+        // Messages missing in German fall back to the default locale
+        assert_eq!(texts()[..3], ["Eine ungelesene Nachricht", "3 ungelesene Nachrichten", "Welcome, madam"]);
+    }
+}
@@ -1,11 +1,27 @@
 mod button;
+mod date_picker;
+mod image;
+mod localized_text;
 mod picker;
+mod progress_view;
+mod secure_field;
 mod slider;
+mod stepper;
+mod text_editor;
 mod text_field;
 mod text;
+mod toggle;
 
 pub use button::*;
+pub use date_picker::*;
+pub use image::*;
+pub use localized_text::*;
 pub use picker::*;
+pub use progress_view::*;
+pub use secure_field::*;
 pub use slider::*;
+pub use stepper::*;
+pub use text_editor::*;
 pub use text_field::*;
 pub use text::*;
+pub use toggle::*;
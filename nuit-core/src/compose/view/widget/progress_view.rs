@@ -0,0 +1,46 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A view indicating the progress of a task, either as the completed fraction
+/// or, if the progress is unknown, as an activity indicator.
+#[derive(Debug, Clone, PartialEq, Bind)]
+pub struct ProgressView {
+    value: Option<f64>,
+}
+
+impl ProgressView {
+    /// Creates a determinate progress view showing the given completed
+    /// fraction, which is clamped to the range from 0 to 1.
+    #[must_use]
+    pub fn new(value: impl Into<f64>) -> Self {
+        Self { value: Some(value.into().clamp(0.0, 1.0)) }
+    }
+
+    /// Creates an indeterminate progress view for tasks of unknown progress.
+    #[must_use]
+    pub const fn indeterminate() -> Self {
+        Self { value: None }
+    }
+}
+
+impl View for ProgressView {
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::ProgressView { value: self.value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Node, ProgressView, Root};
+
+    #[test]
+    fn values() {
+        assert_eq!(Root::new(ProgressView::new(0.4)).render(), Node::ProgressView { value: Some(0.4) });
+        assert_eq!(Root::new(ProgressView::indeterminate()).render(), Node::ProgressView { value: None });
+    }
+}
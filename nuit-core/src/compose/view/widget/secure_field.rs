@@ -0,0 +1,32 @@
+use nuit_derive::Bind;
+
+use crate::{Binding, Context, Event, EventResponse, FireError, IdPath, Node, TextField, TextFieldKind, View};
+
+/// A text field for sensitive text, e.g. passwords, whose content is obscured.
+#[derive(Debug, Clone, Bind)]
+pub struct SecureField {
+    field: TextField,
+}
+
+impl SecureField {
+    #[must_use]
+    pub const fn new(content: Binding<String>) -> Self {
+        Self { field: TextField::with_kind(TextFieldKind::Secure, content) }
+    }
+
+    /// Adds a prompt that is shown while the field is empty.
+    #[must_use]
+    pub fn prompt(self, prompt: impl Into<String>) -> Self {
+        Self { field: self.field.prompt(prompt) }
+    }
+}
+
+impl View for SecureField {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.field.fire(event, event_path, context)
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        self.field.render(context)
+    }
+}
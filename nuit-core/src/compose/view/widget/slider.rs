@@ -2,7 +2,7 @@ use std::ops::RangeInclusive;
 
 use nuit_derive::Bind;
 
-use crate::{Access, Binding, Context, Event, EventResponse, IdPath, Node, View};
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, IdPath, Node, View};
 
 /// A control for selecting numeric values from a bounded range.
 #[derive(Debug, Clone, Bind)]
@@ -25,12 +25,14 @@ impl Slider {
 }
 
 impl View for Slider {
-    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> EventResponse {
-        assert!(event_path.is_root());
+    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
         if let Event::UpdateSliderValue { value } = event {
             self.value.set(*value);
         }
-        EventResponse::default()
+        Ok(EventResponse::default())
     }
 
     fn render(&self, _context: &Context) -> Node {
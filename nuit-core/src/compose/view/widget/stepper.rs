@@ -0,0 +1,95 @@
+use std::ops::RangeInclusive;
+
+use nuit_derive::Bind;
+
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A control for incrementing and decrementing an integer within a bounded
+/// range.
+#[derive(Debug, Clone, Bind)]
+pub struct Stepper {
+    title: String,
+    value: Binding<i64>,
+    range: RangeInclusive<i64>,
+    step: i64,
+}
+
+impl Stepper {
+    /// Creates a stepper for the given range that steps by the given amount.
+    /// The step is only used by the backends to compute the next value,
+    /// values reported by them are clamped to the range but not snapped to
+    /// multiples of the step.
+    ///
+    /// # Panics
+    /// 
+    /// Panics if the range is empty or the step is not positive.
+    pub fn new(title: impl Into<String>, value: Binding<i64>, range: RangeInclusive<i64>, step: i64) -> Self {
+        assert!(!range.is_empty(), "Stepper range {range:?} is empty");
+        assert!(step > 0, "Stepper step {step} is not positive");
+        Self { title: title.into(), value, range, step }
+    }
+
+    /// Creates a stepper that steps by 1.
+    ///
+    /// # Panics
+    /// 
+    /// Panics if the range is empty.
+    pub fn with_default_step(title: impl Into<String>, value: Binding<i64>, range: RangeInclusive<i64>) -> Self {
+        Self::new(title, value, range, 1)
+    }
+}
+
+impl View for Stepper {
+    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
+        if let Event::UpdateStepperValue { value } = event {
+            // The range is validated on construction, so clamping cannot panic
+            self.value.set((*value).clamp(*self.range.start(), *self.range.end()));
+        }
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::Stepper {
+            title: self.title.clone(),
+            value: self.value.get(),
+            lower_bound: *self.range.start(),
+            upper_bound: *self.range.end(),
+            step: self.step,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{clone, Binding, Event, IdPath, Node, Root, Stepper};
+
+    #[test]
+    #[should_panic = "is empty"]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_range() {
+        let _ = Stepper::with_default_step("Count", Binding::constant(0), 10..=0);
+    }
+
+    #[test]
+    #[should_panic = "is not positive"]
+    fn non_positive_step() {
+        let _ = Stepper::new("Count", Binding::constant(0), 0..=10, 0);
+    }
+
+    #[test]
+    fn clamping() {
+        let count = Rc::new(Cell::new(4));
+        let root = Root::new(Stepper::new("Count", Binding::with_get_set(clone!(count => move || count.get()), clone!(count => move |value| count.set(value))), 0..=10, 2));
+
+        root.fire_event(IdPath::root(), &Event::UpdateStepperValue { value: 6 }).unwrap();
+        assert!(matches!(root.render(), Node::Stepper { value: 6, lower_bound: 0, upper_bound: 10, step: 2, .. }));
+        root.fire_event(IdPath::root(), &Event::UpdateStepperValue { value: 12 }).unwrap();
+        assert!(matches!(root.render(), Node::Stepper { value: 10, .. }));
+        assert_eq!(count.get(), 10);
+    }
+}
@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{View, Node, Context, Event, EventResponse, IdPath};
+use crate::{View, Node, Context, Event, EventResponse, FireError, IdPath};
 
 /// A text label.
 #[derive(Debug, Clone, PartialEq, Eq, Bind)]
@@ -17,8 +17,8 @@ impl Text {
 }
 
 impl View for Text {
-    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> EventResponse {
-        EventResponse::default()
+    fn fire(&self, _event: &Event, _id_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        Ok(EventResponse::default())
     }
 
     fn render(&self, _context: &Context) -> Node {
@@ -0,0 +1,32 @@
+use nuit_derive::Bind;
+
+use crate::{Binding, Context, Event, EventResponse, FireError, IdPath, Node, TextField, TextFieldKind, View};
+
+/// A view for editing multiple lines of text.
+#[derive(Debug, Clone, Bind)]
+pub struct TextEditor {
+    field: TextField,
+}
+
+impl TextEditor {
+    #[must_use]
+    pub const fn new(content: Binding<String>) -> Self {
+        Self { field: TextField::with_kind(TextFieldKind::Multiline, content) }
+    }
+
+    /// Disables autocorrection (and spell checking, where applicable).
+    #[must_use]
+    pub fn autocorrection_disabled(self, is_disabled: bool) -> Self {
+        Self { field: self.field.autocorrection_disabled(is_disabled) }
+    }
+}
+
+impl View for TextEditor {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.field.fire(event, event_path, context)
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        self.field.render(context)
+    }
+}
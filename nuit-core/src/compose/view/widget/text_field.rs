@@ -1,30 +1,148 @@
 use nuit_derive::Bind;
 
-use crate::{Access, Binding, Context, Event, EventResponse, IdPath, Node, View};
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, IdPath, KeyboardType, Node, TextFieldKind, View};
 
 /// A user-modifiable text field.
+///
+/// Use [`on_submit`](crate::ViewExt::on_submit) to handle the user pressing
+/// Enter and [`on_focus_change`](crate::ViewExt::on_focus_change) to observe
+/// the field gaining or losing focus.
 #[derive(Debug, Clone, Bind)]
 pub struct TextField {
     content: Binding<String>,
+    prompt: Option<String>,
+    kind: TextFieldKind,
+    keyboard_type: KeyboardType,
+    is_autocorrection_disabled: bool,
 }
 
 impl TextField {
     #[must_use]
     pub const fn new(content: Binding<String>) -> Self {
-        Self { content }
+        Self::with_kind(TextFieldKind::Plain, content)
+    }
+
+    #[must_use]
+    pub(crate) const fn with_kind(kind: TextFieldKind, content: Binding<String>) -> Self {
+        Self {
+            content,
+            prompt: None,
+            kind,
+            keyboard_type: KeyboardType::Default,
+            is_autocorrection_disabled: false,
+        }
+    }
+
+    /// Adds a prompt that is shown while the field is empty.
+    #[must_use]
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Hints at the kind of input the field expects.
+    #[must_use]
+    pub const fn keyboard_type(mut self, keyboard_type: KeyboardType) -> Self {
+        self.keyboard_type = keyboard_type;
+        self
+    }
+
+    /// Disables autocorrection (and spell checking, where applicable).
+    #[must_use]
+    pub const fn autocorrection_disabled(mut self, is_disabled: bool) -> Self {
+        self.is_autocorrection_disabled = is_disabled;
+        self
     }
 }
 
 impl View for TextField {
-    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> EventResponse {
-        assert!(event_path.is_root());
+    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
         if let Event::UpdateText { content } = event {
             self.content.set(content.to_owned());
         }
-        EventResponse::default()
+        Ok(EventResponse::default())
     }
 
     fn render(&self, _context: &Context) -> Node {
-        Node::TextField { content: self.content.get() }
+        Node::TextField {
+            content: self.content.get(),
+            prompt: self.prompt.clone(),
+            kind: self.kind,
+            keyboard_type: self.keyboard_type,
+            is_autocorrection_disabled: self.is_autocorrection_disabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Event, Id, IdPathBuf, KeyboardType, Node, Root, SecureField, State, Text, TextEditor, TextField, TextFieldKind, View, ViewExt, VStack};
+
+    #[derive(Bind, Default)]
+    struct LoginView {
+        email: State<String>,
+        password: State<String>,
+        notes: State<String>,
+        is_email_focused: State<bool>,
+        submissions: State<usize>,
+    }
+
+    impl View for LoginView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let is_email_focused = self.is_email_focused.clone();
+            let submissions = self.submissions.clone();
+            VStack::from((
+                TextField::new(self.email.binding())
+                    .prompt("Email")
+                    .keyboard_type(KeyboardType::EmailAddress)
+                    .autocorrection_disabled(true)
+                    .on_focus_change(clone!(is_email_focused => move |is_focused| {
+                        is_email_focused.set(is_focused);
+                    })),
+                SecureField::new(self.password.binding())
+                    .prompt("Password")
+                    .on_submit(clone!(submissions => move || {
+                        submissions.set(submissions.get() + 1);
+                    })),
+                TextEditor::new(self.notes.binding()),
+                Text::new(format!("{} {}", is_email_focused.get(), submissions.get())),
+            ))
+        }
+    }
+
+    #[test]
+    fn login_form() {
+        let root = Root::new(LoginView::default());
+        let path = |i: usize| IdPathBuf::from(Id::index(0)).child(i);
+        let node_at = |i: usize| root.render().descendant(&path(i)).cloned();
+        let status = || match node_at(3) {
+            Some(Node::Text { content, .. }) => content,
+            node => panic!("Expected text, got {node:?}"),
+        };
+
+        assert!(matches!(node_at(0), Some(Node::TextField {
+            prompt: Some(prompt),
+            kind: TextFieldKind::Plain,
+            keyboard_type: KeyboardType::EmailAddress,
+            is_autocorrection_disabled: true,
+            ..
+        }) if prompt == "Email"));
+        assert!(matches!(node_at(1), Some(Node::TextField { kind: TextFieldKind::Secure, .. })));
+        assert!(matches!(node_at(2), Some(Node::TextField { prompt: None, kind: TextFieldKind::Multiline, .. })));
+
+        root.fire_event(&path(0), &Event::UpdateFocus { is_focused: true }).unwrap();
+        assert_eq!(status(), "true 0");
+        root.fire_event(&path(1), &Event::UpdateText { content: "secret".to_owned() }).unwrap();
+        root.fire_event(&path(1), &Event::Submit {}).unwrap();
+        root.fire_event(&path(0), &Event::UpdateFocus { is_focused: false }).unwrap();
+        assert_eq!(status(), "false 1");
+        assert!(matches!(node_at(1), Some(Node::TextField { content, .. }) if content == "secret"));
     }
 }
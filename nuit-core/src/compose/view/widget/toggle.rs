@@ -0,0 +1,53 @@
+use nuit_derive::Bind;
+
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A control for switching a setting on or off.
+#[derive(Debug, Clone, Bind)]
+pub struct Toggle {
+    title: String,
+    is_on: Binding<bool>,
+}
+
+impl Toggle {
+    pub fn new(title: impl Into<String>, is_on: Binding<bool>) -> Self {
+        Self { title: title.into(), is_on }
+    }
+}
+
+impl View for Toggle {
+    fn fire(&self, event: &Event, event_path: &IdPath, _context: &Context) -> Result<EventResponse, FireError> {
+        if !event_path.is_root() {
+            return Err(FireError::StalePath { path: event_path.to_owned() });
+        }
+        if let Event::UpdateToggle { is_on } = event {
+            self.is_on.set(*is_on);
+        }
+        Ok(EventResponse::default())
+    }
+
+    fn render(&self, _context: &Context) -> Node {
+        Node::Toggle {
+            title: self.title.clone(),
+            is_on: self.is_on.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{clone, Binding, Event, IdPath, Node, Root, Toggle};
+
+    #[test]
+    fn update() {
+        let is_on = Rc::new(Cell::new(false));
+        let root = Root::new(Toggle::new("Enabled", Binding::with_get_set(clone!(is_on => move || is_on.get()), clone!(is_on => move |value| is_on.set(value)))));
+        assert!(matches!(root.render(), Node::Toggle { is_on: false, .. }));
+
+        root.fire_event(IdPath::root(), &Event::UpdateToggle { is_on: true }).unwrap();
+        assert!(is_on.get());
+        assert!(matches!(root.render(), Node::Toggle { is_on: true, .. }));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Bind, Context, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A type-erased view, e.g. for handling views of different types uniformly.
+pub struct AnyView {
+    wrapped: Box<dyn ErasedView>,
+}
+
+/// An object-safe subset of [`View`].
+trait ErasedView {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError>;
+
+    fn render(&self, context: &Context) -> Node;
+}
+
+impl<T> ErasedView for T where T: View {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        View::fire(self, event, event_path, context)
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        View::render(self, context)
+    }
+}
+
+impl AnyView {
+    pub fn new(view: impl View + 'static) -> Self {
+        Self { wrapped: Box::new(view) }
+    }
+}
+
+impl Bind for AnyView {}
+
+impl View for AnyView {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.wrapped.fire(event, event_path, context)
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        self.wrapped.render(context)
+    }
+}
@@ -0,0 +1,62 @@
+use nuit_derive::Bind;
+
+use crate::{Access, Binding, Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, ModifierNode, Node, View};
+
+/// A view that is focused while the given binding equals the given value.
+/// Focus changes within the wrapped view update the binding.
+#[derive(Debug, Clone, Bind)]
+pub struct Focused<T, V> {
+    wrapped: T,
+    binding: Binding<Option<V>>,
+    value: V,
+}
+
+impl<T, V> Focused<T, V> {
+    #[must_use]
+    pub const fn new(wrapped: T, binding: Binding<Option<V>>, value: V) -> Self {
+        Self {
+            wrapped,
+            binding,
+            value,
+        }
+    }
+}
+
+impl<T, V> Focused<T, V> where V: 'static + Clone + PartialEq {
+    fn is_focused(&self) -> bool {
+        self.binding.get().as_ref() == Some(&self.value)
+    }
+
+    fn update_focus(&self, is_focused: bool) {
+        // Focus may move to another view before leaving this one, so we only
+        // reset the binding if it still refers to this view
+        if is_focused != self.is_focused() {
+            self.binding.set(is_focused.then(|| self.value.clone()));
+        }
+    }
+}
+
+impl<T, V> View for Focused<T, V> where T: View, V: 'static + Clone + PartialEq {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        // Backends may report focus changes either for this view or for the
+        // focusable view within it
+        if let Event::UpdateFocus { is_focused } = event {
+            self.update_focus(*is_focused);
+        }
+        if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "Focused" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Modified {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            modifier: ModifierNode::Focused { is_focused: self.is_focused() },
+        }
+    }
+}
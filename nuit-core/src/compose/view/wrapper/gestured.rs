@@ -1,6 +1,6 @@
 use nuit_derive::Bind;
 
-use crate::{Context, Event, EventResponse, Gesture, Id, IdPath, IdentifyExt, Node, View};
+use crate::{Context, Event, EventResponse, FireError, Gesture, Id, IdPath, IdentifyExt, Node, View};
 
 /// A view recognizing a gesture.
 #[derive(Debug, Clone, PartialEq, Bind)]
@@ -16,7 +16,7 @@ impl<T, G> Gestured<T, G> {
 }
 
 impl<T, G> View for Gestured<T, G> where T: View, G: Gesture {
-    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> EventResponse {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
         if let Some(head) = event_path.head() {
             match head {
                 Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
@@ -24,14 +24,13 @@ impl<T, G> View for Gestured<T, G> where T: View, G: Gesture {
                     if let Event::Gesture { gesture } = event {
                         self.gesture.fire(gesture, event_path.tail(), &context.child(1))
                     } else {
-                        eprintln!("Warning: Non-gesture event {event:?} targeted to id path {event_path:?} in a gesture is ignored");
-                        EventResponse::default()
+                        Err(FireError::TypeMismatch { event: format!("{event:?}"), view: "Gestured" })
                     }
                 },
-                i => panic!("Cannot fire event for child id {i} on Gestured, which has two childs"),
+                id => Err(FireError::UnknownChild { id, view: "Gestured" }),
             }
         } else {
-            EventResponse::default()
+            Ok(EventResponse::default())
         }
     }
 
@@ -1,5 +1,13 @@
+mod any_view;
+mod focused;
 mod gestured;
 mod modified;
+mod with_accessibility_action;
+mod with_environment;
 
+pub use any_view::*;
+pub use focused::*;
 pub use gestured::*;
 pub use modified::*;
+pub use with_accessibility_action::*;
+pub use with_environment::*;
@@ -0,0 +1,48 @@
+use nuit_derive::Bind;
+
+use crate::{Context, Event, EventResponse, FireError, Id, IdPath, IdentifyExt, ModifierNode, Node, View};
+
+/// A view that offers a named action to assistive technologies, which
+/// performs the given closure.
+#[derive(Debug, Clone, PartialEq, Eq, Bind)]
+pub struct WithAccessibilityAction<T, F> {
+    wrapped: T,
+    name: String,
+    action: F,
+}
+
+impl<T, F> WithAccessibilityAction<T, F> {
+    #[must_use]
+    pub fn new(wrapped: T, name: impl Into<String>, action: F) -> Self {
+        Self {
+            wrapped,
+            name: name.into(),
+            action,
+        }
+    }
+}
+
+impl<T, F> View for WithAccessibilityAction<T, F> where T: View, F: Fn() {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        // Backends may perform the action either on this view or on the
+        // element within it that assistive technologies see
+        if matches!(event, Event::PerformAccessibilityAction { name } if *name == self.name) {
+            (self.action)();
+            Ok(EventResponse::default())
+        } else if let Some(head) = event_path.head() {
+            match head {
+                Id::Index(0) => self.wrapped.fire(event, event_path.tail(), &context.child(0)),
+                id => Err(FireError::UnknownChild { id, view: "WithAccessibilityAction" }),
+            }
+        } else {
+            Ok(EventResponse::default())
+        }
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        Node::Modified {
+            wrapped: Box::new(self.wrapped.render(&context.child(0)).identify(0)),
+            modifier: ModifierNode::AccessibilityAction { name: self.name.clone() },
+        }
+    }
+}
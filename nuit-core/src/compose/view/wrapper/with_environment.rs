@@ -0,0 +1,32 @@
+use std::marker::PhantomData;
+
+use crate::{Bind, Context, EnvironmentKey, Event, EventResponse, FireError, IdPath, Node, View};
+
+/// A view that sets an environment value for its wrapped view.
+pub struct WithEnvironment<T, K> where K: EnvironmentKey {
+    wrapped: T,
+    value: K::Value,
+    phantom_key: PhantomData<K>,
+}
+
+impl<T, K> WithEnvironment<T, K> where K: EnvironmentKey {
+    pub const fn new(wrapped: T, value: K::Value) -> Self {
+        Self {
+            wrapped,
+            value,
+            phantom_key: PhantomData,
+        }
+    }
+}
+
+impl<T, K> Bind for WithEnvironment<T, K> where K: EnvironmentKey {}
+
+impl<T, K> View for WithEnvironment<T, K> where T: View, K: EnvironmentKey {
+    fn fire(&self, event: &Event, event_path: &IdPath, context: &Context) -> Result<EventResponse, FireError> {
+        self.wrapped.fire(event, event_path, &context.with_environment::<K>(self.value.clone()))
+    }
+
+    fn render(&self, context: &Context) -> Node {
+        self.wrapped.render(&context.with_environment::<K>(self.value.clone()))
+    }
+}
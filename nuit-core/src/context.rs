@@ -1,19 +1,32 @@
 use std::rc::Rc;
 
-use crate::{Storage, IdPath, Id, IdPathBuf};
+use crate::{EnvironmentKey, EnvironmentValues, Storage, IdPath, Id, IdPathBuf};
 
 /// A context used during rendering that tracks the path to the current view and
-/// holds a reference to internal storage.
+/// holds a reference to internal storage and the environment.
 pub struct Context {
     id_path: IdPathBuf,
     storage: Rc<Storage>,
+    environment: EnvironmentValues,
 }
 
 impl Context {
+    #[must_use]
     pub const fn new(storage: Rc<Storage>) -> Self {
         Self {
             id_path: IdPathBuf::root(),
             storage,
+            environment: EnvironmentValues::new(),
+        }
+    }
+
+    /// Creates a root context with the given environment.
+    #[must_use]
+    pub const fn with_environment_values(storage: Rc<Storage>, environment: EnvironmentValues) -> Self {
+        Self {
+            id_path: IdPathBuf::root(),
+            storage,
+            environment,
         }
     }
 
@@ -22,6 +35,11 @@ impl Context {
         &self.storage
     }
 
+    #[must_use]
+    pub const fn environment(&self) -> &EnvironmentValues {
+        &self.environment
+    }
+
     #[must_use]
     pub fn id_path(&self) -> &IdPath {
         &self.id_path
@@ -32,6 +50,18 @@ impl Context {
         Self {
             id_path: self.id_path.child(id),
             storage: self.storage.clone(),
+            environment: self.environment.clone(),
+        }
+    }
+
+    /// Creates a context for the same view with the given environment key set
+    /// to the given value.
+    #[must_use]
+    pub fn with_environment<K>(&self, value: K::Value) -> Self where K: EnvironmentKey {
+        Self {
+            id_path: self.id_path.clone(),
+            storage: self.storage.clone(),
+            environment: self.environment.with::<K>(value),
         }
     }
 }
@@ -0,0 +1,108 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use super::{EnvironmentKey, EnvironmentValues};
+
+/// A view field providing read access to an environment value set by an
+/// ancestor view.
+pub struct Environment<K> where K: EnvironmentKey {
+    value: RefCell<Option<K::Value>>,
+    phantom_key: PhantomData<K>,
+}
+
+impl<K> Environment<K> where K: EnvironmentKey {
+    /// Creates a new (unlinked) environment field.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: RefCell::new(None),
+            phantom_key: PhantomData,
+        }
+    }
+
+    /// Checks whether the environment has been linked. May be useful for
+    /// debugging.
+    pub fn is_linked(&self) -> bool {
+        self.value.borrow().is_some()
+    }
+
+    /// Links the given environment to this field. This is done automatically
+    /// by the derived [`Bind::bind`] implementation, therefore this method
+    /// should usually not be called manually.
+    pub fn link(&self, environment: &EnvironmentValues) {
+        *self.value.borrow_mut() = Some(environment.get::<K>());
+    }
+
+    /// Fetches the value.
+    /// 
+    /// # Panics
+    /// 
+    /// This will panic if the environment has not been linked first. Linking
+    /// is performed by (the usually derived) [`Bind::bind`] implementation
+    /// before [`View::render`] is called, so calling this method in the render
+    /// implementation is generally safe (and encouraged).
+    pub fn get(&self) -> K::Value {
+        self.value.borrow().clone().expect("Environment not linked before calling Environment::get")
+    }
+}
+
+impl<K> Clone for Environment<K> where K: EnvironmentKey {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            phantom_key: PhantomData,
+        }
+    }
+}
+
+impl<K> Default for Environment<K> where K: EnvironmentKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{Environment, EnvironmentKey, Node, Root, Text, View, ViewExt, VStack};
+
+    struct GreetingKey;
+
+    impl EnvironmentKey for GreetingKey {
+        type Value = String;
+
+        fn default_value() -> String {
+            "Hello".to_owned()
+        }
+    }
+
+    #[derive(Bind, Default)]
+    struct GreetingView {
+        greeting: Environment<GreetingKey>,
+    }
+
+    impl View for GreetingView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            Text::new(self.greeting.get())
+        }
+    }
+
+    #[test]
+    fn values() {
+        let root = Root::new(VStack::from((
+            GreetingView::default(),
+            VStack::from((GreetingView::default(),)).environment(GreetingKey, "Hi"),
+        )));
+        let node = root.render();
+        let texts: Vec<&str> = node.descendants()
+            .into_iter()
+            .filter_map(|(_, node)| match node {
+                Node::Text { content } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, vec!["Hello", "Hi"]);
+    }
+}
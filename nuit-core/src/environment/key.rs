@@ -0,0 +1,7 @@
+/// A key for a value that is passed down the view tree implicitly.
+pub trait EnvironmentKey: 'static {
+    type Value: Clone + 'static;
+
+    /// The value to use if no ancestor view has set one.
+    fn default_value() -> Self::Value;
+}
@@ -0,0 +1,7 @@
+mod environment;
+mod key;
+mod values;
+
+pub use environment::*;
+pub use key::*;
+pub use values::*;
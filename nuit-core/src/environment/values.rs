@@ -0,0 +1,36 @@
+use std::{any::{Any, TypeId}, collections::HashMap, rc::Rc};
+
+use super::EnvironmentKey;
+
+/// An immutable collection of environment values, keyed by their key type.
+#[derive(Clone, Default)]
+pub struct EnvironmentValues {
+    /// The values, if any are set. Empty collections don't allocate, which
+    /// lets us create them in const contexts.
+    values: Option<Rc<HashMap<TypeId, Rc<dyn Any>>>>,
+}
+
+impl EnvironmentValues {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { values: None }
+    }
+
+    /// Fetches the value for the given key, or its default value if unset.
+    #[must_use]
+    pub fn get<K>(&self) -> K::Value where K: EnvironmentKey {
+        self.values.as_ref()
+            .and_then(|values| values.get(&TypeId::of::<K>()))
+            .and_then(|value| value.downcast_ref::<K::Value>())
+            .cloned()
+            .unwrap_or_else(K::default_value)
+    }
+
+    /// Creates a copy of these values with the given key set to the given value.
+    #[must_use]
+    pub fn with<K>(&self, value: K::Value) -> Self where K: EnvironmentKey {
+        let mut values = self.values.as_deref().cloned().unwrap_or_default();
+        values.insert(TypeId::of::<K>(), Rc::new(value));
+        Self { values: Some(Rc::new(values)) }
+    }
+}
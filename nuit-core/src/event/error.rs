@@ -0,0 +1,38 @@
+use std::{error::Error, fmt};
+
+use crate::{Id, IdPathBuf};
+
+/// An error that occurred while dispatching an event to a view. These usually
+/// indicate that the event is stale, e.g. since it was fired at a view that
+/// has been re-rendered in the meantime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FireError {
+    /// The event was targeted at a child that the view doesn't have.
+    UnknownChild { id: Id, view: &'static str },
+    /// The event cannot be handled by the view it was targeted at.
+    TypeMismatch { event: String, view: &'static str },
+    /// A value in the event or the id path could not be deserialized.
+    Deserialization { what: &'static str, message: String },
+    /// The remaining id path points below a view without children.
+    StalePath { path: IdPathBuf },
+}
+
+impl FireError {
+    /// Creates a deserialization error from the given underlying error.
+    pub fn deserialization(what: &'static str, error: impl fmt::Display) -> Self {
+        Self::Deserialization { what, message: error.to_string() }
+    }
+}
+
+impl fmt::Display for FireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownChild { id, view } => write!(f, "Cannot fire event for unknown child id {id} on {view}"),
+            Self::TypeMismatch { event, view } => write!(f, "Cannot handle event {event} on {view}"),
+            Self::Deserialization { what, message } => write!(f, "Could not deserialize {what}: {message}"),
+            Self::StalePath { path } => write!(f, "Cannot fire event at stale path {path:?}"),
+        }
+    }
+}
+
+impl Error for FireError {}
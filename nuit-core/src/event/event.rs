@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
 
-use crate::{Geometry, Id};
+use crate::{CalendarDate, Geometry, Id};
 
 use super::GestureEvent;
 
@@ -13,17 +13,34 @@ pub enum Event {
     ButtonTap {},
     Gesture { gesture: GestureEvent },
     UpdateText { content: String },
+    UpdateFocus { is_focused: bool },
+    Submit {},
     UpdatePickerSelection { id: Id },
     UpdateSliderValue { value: f64 },
+    UpdateToggle { is_on: bool },
+    UpdateStepperValue { value: i64 },
+    UpdateDate { date: CalendarDate },
+
+    // Lists
+    UpdateSelection { selection: Vec<Id> },
+    DeleteRows { indices: Vec<usize> },
+    MoveRows { indices: Vec<usize>, destination: usize },
+
+    // Accessibility
+    PerformAccessibilityAction { name: String },
 
     // Navigation
     UpdateNavigationPath { path: Vec<Value> },
     GetNavigationDestination { value: Value },
 
+    // Presentation
+    Dismiss {},
+
     // Layout
     GetGeometryReaderView { geometry: Geometry },
+    GetRows { start: usize, end: usize },
 
     // Lifecycle
-    Appear,
-    Disappear,
+    Appear {},
+    Disappear {},
 }
@@ -12,6 +12,12 @@ pub struct DragEvent {
 }
 
 impl DragEvent {
+    /// Creates a new drag event.
+    #[must_use]
+    pub const fn new(kind: DragEventKind, start_location: Vec2<f64>, location: Vec2<f64>) -> Self {
+        Self { kind, start_location, location }
+    }
+
     /// The kind of the drag event.
     #[must_use]
     pub const fn kind(&self) -> DragEventKind {
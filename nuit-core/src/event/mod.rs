@@ -1,7 +1,9 @@
+mod error;
 mod event;
 mod gesture;
 mod response;
 
+pub use error::*;
 pub use event::*;
 pub use gesture::*;
 pub use response::*;
@@ -8,6 +8,10 @@ use crate::{Identified, Node};
 pub enum EventResponse {
     Empty {},
     Node { node: Identified<Node> },
+    Nodes { nodes: Vec<Identified<Node>> },
+    /// The event could not be handled. Only used to report errors across the
+    /// FFI boundary, Rust callers get a [`FireError`](crate::FireError) instead.
+    Error { message: String },
 }
 
 impl Default for EventResponse {
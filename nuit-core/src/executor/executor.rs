@@ -0,0 +1,15 @@
+use std::{future::Future, pin::Pin, rc::Rc};
+
+/// A facility that runs asynchronous tasks, usually driven by a backend's main
+/// loop. Since tasks may access view state, they run on the main thread and
+/// are therefore not required to be `Send`.
+pub trait Executor {
+    /// Starts running the given future in the background.
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()>>>);
+}
+
+impl<E> Executor for Rc<E> where E: Executor + ?Sized {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()>>>) {
+        (**self).spawn(future);
+    }
+}
@@ -0,0 +1,112 @@
+use std::{cell::{Cell, RefCell}, collections::HashMap, future::Future, mem, pin::Pin, sync::{Arc, Mutex, PoisonError}, task::{Context, Wake, Waker}};
+
+use super::Executor;
+
+/// A simple single-threaded executor that runs tasks when explicitly asked to.
+/// This is used for headless roots and backends without an event loop of
+/// their own that could run tasks.
+pub struct LocalExecutor {
+    #[allow(clippy::type_complexity)]
+    tasks: RefCell<HashMap<usize, Pin<Box<dyn Future<Output = ()>>>>>,
+    next_id: Cell<usize>,
+    queue: Arc<ReadyQueue>,
+}
+
+/// The ids of the tasks that have been woken. Since wakers may be invoked
+/// from any thread, this is shared thread-safely.
+#[derive(Default)]
+struct ReadyQueue {
+    ids: Mutex<Vec<usize>>,
+    #[allow(clippy::type_complexity)]
+    wake_callback: Mutex<Option<Box<dyn Fn() + Send + Sync>>>,
+}
+
+struct TaskWaker {
+    id: usize,
+    queue: Arc<ReadyQueue>,
+}
+
+impl LocalExecutor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tasks: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+            queue: Arc::new(ReadyQueue::default()),
+        }
+    }
+
+    /// Whether there are tasks that have not completed yet.
+    #[must_use]
+    pub fn has_tasks(&self) -> bool {
+        !self.tasks.borrow().is_empty()
+    }
+
+    /// Polls the tasks that have been woken until none of them can make
+    /// progress anymore.
+    pub fn run_until_stalled(&self) {
+        loop {
+            let ids = mem::take(&mut *self.queue.ids.lock().unwrap_or_else(PoisonError::into_inner));
+            if ids.is_empty() {
+                break;
+            }
+            for id in ids {
+                // The task is removed while being polled, so it may spawn new
+                // tasks without conflicting borrows
+                let Some(mut future) = self.tasks.borrow_mut().remove(&id) else { continue };
+                let waker = Waker::from(Arc::new(TaskWaker { id, queue: self.queue.clone() }));
+                if future.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+                    self.tasks.borrow_mut().insert(id, future);
+                }
+            }
+        }
+    }
+
+    /// Sets a callback that is invoked whenever a task is ready to make
+    /// progress, i.e. when [`LocalExecutor::run_until_stalled`] should be
+    /// called. Since tasks may be woken from any thread, so may the callback.
+    pub fn set_wake_callback(&self, wake_callback: impl Fn() + Send + Sync + 'static) {
+        *self.queue.wake_callback.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(wake_callback));
+    }
+}
+
+impl Executor for LocalExecutor {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()>>>) {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.tasks.borrow_mut().insert(id, future);
+        self.queue.push(id);
+    }
+}
+
+impl Default for LocalExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LocalExecutor {
+    fn drop(&mut self) {
+        // Wakers may outlive the executor, but should no longer request runs
+        *self.queue.wake_callback.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+impl ReadyQueue {
+    fn push(&self, id: usize) {
+        self.ids.lock().unwrap_or_else(PoisonError::into_inner).push(id);
+        if let Some(wake_callback) = self.wake_callback.lock().unwrap_or_else(PoisonError::into_inner).as_ref() {
+            wake_callback();
+        }
+    }
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.queue.push(self.id);
+    }
+}
@@ -0,0 +1,7 @@
+mod executor;
+mod local_executor;
+mod task_handle;
+
+pub use executor::*;
+pub use local_executor::*;
+pub use task_handle::*;
@@ -0,0 +1,83 @@
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc, task::{Context, Poll, Waker}};
+
+/// A handle to a spawned task that can be used to cancel it.
+#[derive(Clone)]
+pub struct TaskHandle {
+    inner: Rc<RefCell<TaskInner>>,
+}
+
+/// A future that completes early once the task is cancelled.
+pub(crate) struct CancellableFuture {
+    inner: Rc<RefCell<TaskInner>>,
+}
+
+struct TaskInner {
+    future: Option<Pin<Box<dyn Future<Output = ()>>>>,
+    waker: Option<Waker>,
+    is_finished: bool,
+}
+
+impl TaskHandle {
+    /// Wraps the given future into a cancellable one, returning it along with
+    /// a handle to cancel it.
+    pub(crate) fn new(future: impl Future<Output = ()> + 'static) -> (Self, CancellableFuture) {
+        let inner = Rc::new(RefCell::new(TaskInner {
+            future: Some(Box::pin(future)),
+            waker: None,
+            is_finished: false,
+        }));
+        (Self { inner: inner.clone() }, CancellableFuture { inner })
+    }
+
+    /// Whether the task has completed or been cancelled.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.inner.borrow().is_finished
+    }
+
+    /// Cancels the task, dropping the underlying future. This has no effect if
+    /// the task is already finished.
+    pub fn cancel(&self) {
+        let (future, waker) = {
+            let mut inner = self.inner.borrow_mut();
+            inner.is_finished = true;
+            (inner.future.take(), inner.waker.take())
+        };
+        // The future is dropped outside of the borrow since dropping it may
+        // run arbitrary code
+        drop(future);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for CancellableFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // We take the future out of the cell while polling it, since the task
+        // may (indirectly, e.g. by triggering a render) cancel itself.
+        let mut future = {
+            let mut inner = self.inner.borrow_mut();
+            let Some(future) = inner.future.take() else { return Poll::Ready(()) };
+            inner.waker = Some(cx.waker().clone());
+            future
+        };
+
+        let poll = future.as_mut().poll(cx);
+
+        let mut inner = self.inner.borrow_mut();
+        if poll.is_ready() {
+            inner.is_finished = true;
+        } else if inner.is_finished {
+            // The task was cancelled while being polled
+            drop(inner);
+            drop(future);
+            return Poll::Ready(());
+        } else {
+            inner.future = Some(future);
+        }
+        poll
+    }
+}
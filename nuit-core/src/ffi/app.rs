@@ -0,0 +1,147 @@
+use std::{cell::Cell, ffi::{c_char, c_void, CStr, CString}, ptr, rc::Rc};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{AnyView, App, CRoot, CommandMenuInfo, OpenWindow, Scene, SceneInfo};
+
+/// A C/FFI-compatible wrapper around `App`.
+#[repr(C)]
+pub struct CApp {
+    /// The opaque pointer to the underlying Rust app state.
+    wrapped: *mut c_void,
+    /// Describes the app and its scenes as owned JSON.
+    /// **Callers are responsible for calling [`nuit::nuit_c_string_drop`] on this string!**
+    info_json: extern "C" fn(*const CApp) -> *const c_char,
+    /// Creates the root for a new window of the scene with the given id,
+    /// presenting the given JSON-serialized value. Returns null on failure.
+    /// **Callers are responsible for calling `drop_root` on this root!**
+    create_root: extern "C" fn(*const CApp, *const c_char, *const c_char) -> *mut CRoot,
+    /// Drops a root created by `create_root`.
+    drop_root: extern "C" fn(*const CApp, *mut CRoot),
+    /// Registers a callback that we (the Rust side) can use to open a window
+    /// for the scene with the given id, presenting the given JSON-serialized
+    /// value.
+    set_open_window_callback: extern "C" fn(*const CApp, extern "C" fn(*const c_char, *const c_char)),
+    /// Performs the app-level command at the given item index in the menu at
+    /// the given index.
+    perform_command: extern "C" fn(*const CApp, usize, usize),
+    /// Writes the persisted state that hasn't been written yet, which should
+    /// be called before the app terminates.
+    flush_persisted_state: extern "C" fn(*const CApp),
+}
+
+type OpenWindowCallback = extern "C" fn(*const c_char, *const c_char);
+
+struct AppState {
+    app: App,
+    open_window_callback: Rc<Cell<Option<OpenWindowCallback>>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppInfo<'a> {
+    id: &'a str,
+    launch_scene_id: Option<&'a str>,
+    scenes: Vec<&'a SceneInfo>,
+    commands: Vec<CommandMenuInfo>,
+}
+
+extern "C" fn info_json_impl(c_app: *const CApp) -> *const c_char {
+    unsafe {
+        let app = &(*(*c_app).wrapped.cast::<AppState>()).app;
+        let info = AppInfo {
+            id: app.id(),
+            launch_scene_id: app.launch_scene().map(|scene| scene.info().id()),
+            scenes: app.scenes().iter().map(Scene::info).collect(),
+            commands: app.commands().info(),
+        };
+        let json = serde_json::to_string(&info).expect("Could not serialize app info");
+        CString::new(json).expect("Could not convert JSON to C string").into_raw()
+    }
+}
+
+extern "C" fn create_root_impl(c_app: *const CApp, raw_scene_id: *const c_char, raw_value_json: *const c_char) -> *mut CRoot {
+    unsafe {
+        let state = &*(*c_app).wrapped.cast::<AppState>();
+        let scene_id = CStr::from_ptr(raw_scene_id).to_string_lossy();
+        let value = match serde_json::from_slice::<Value>(CStr::from_ptr(raw_value_json).to_bytes()) {
+            Ok(value) => value,
+            Err(error) => {
+                eprintln!("Warning: Could not decode value for scene '{scene_id}': {error}");
+                return ptr::null_mut();
+            },
+        };
+
+        let open_window_callback = state.open_window_callback.clone();
+        let open_window = OpenWindow::new(move |id, value| {
+            let Some(callback) = open_window_callback.get() else {
+                eprintln!("Warning: Cannot open window '{id}' since no callback is registered");
+                return;
+            };
+            let id_c_string = CString::new(id).expect("Could not convert scene id to C string");
+            let value_c_string = CString::new(value.to_string()).expect("Could not convert value JSON to C string");
+            callback(id_c_string.as_ptr(), value_c_string.as_ptr());
+        });
+
+        match state.app.make_root(&scene_id, value, open_window) {
+            Ok(root) => CRoot::into_raw(root),
+            Err(error) => {
+                // We must not panic across the FFI boundary, so we report the
+                // error to the caller instead.
+                eprintln!("Warning: {error}");
+                ptr::null_mut()
+            },
+        }
+    }
+}
+
+extern "C" fn drop_root_impl(_c_app: *const CApp, c_root: *mut CRoot) {
+    unsafe {
+        CRoot::drop_raw::<AnyView>(c_root);
+    }
+}
+
+extern "C" fn set_open_window_callback_impl(c_app: *const CApp, open_window_callback: OpenWindowCallback) {
+    unsafe {
+        let state = &*(*c_app).wrapped.cast::<AppState>();
+        state.open_window_callback.set(Some(open_window_callback));
+    }
+}
+
+extern "C" fn perform_command_impl(c_app: *const CApp, menu: usize, item: usize) {
+    unsafe {
+        let state = &*(*c_app).wrapped.cast::<AppState>();
+        if let Err(error) = state.app.commands().perform(menu, item) {
+            eprintln!("Warning: {error}");
+        }
+    }
+}
+
+extern "C" fn flush_persisted_state_impl(c_app: *const CApp) {
+    unsafe {
+        let state = &*(*c_app).wrapped.cast::<AppState>();
+        state.app.flush_persisted_state();
+    }
+}
+
+impl CApp {
+    /// Safely uses a [`CApp`] while returning ownership once the method returns.
+    pub fn scope_from<U>(app: App, action: impl FnOnce(&Self) -> U) -> U {
+        let mut state = Box::new(AppState {
+            app,
+            open_window_callback: Rc::new(Cell::new(None)),
+        });
+        let c_app = Self {
+            wrapped: ptr::from_mut(&mut *state).cast::<c_void>(),
+            info_json: info_json_impl,
+            create_root: create_root_impl,
+            drop_root: drop_root_impl,
+            set_open_window_callback: set_open_window_callback_impl,
+            perform_command: perform_command_impl,
+            flush_persisted_state: flush_persisted_state_impl,
+        };
+
+        action(&c_app)
+    }
+}
@@ -1,5 +1,7 @@
+mod app;
 mod root;
 mod string;
 
+pub use app::*;
 pub use root::*;
 pub use string::*;
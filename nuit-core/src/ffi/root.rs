@@ -1,6 +1,6 @@
 use std::{ffi::{c_char, c_void, CStr, CString}, ptr, str};
 
-use crate::{Root, View};
+use crate::{EventResponse, FireError, Root, View};
 
 /// A C/FFI-compatible wrapper around `Root<T>`.
 #[repr(C)]
@@ -11,10 +11,35 @@ pub struct CRoot {
     /// **Callers are responsible for calling [`nuit::nuit_c_string_drop`] on this string!**
     render_json: extern "C" fn(*const CRoot) -> *const c_char,
     /// Fires an to the given JSON-serialized id path with the given JSON-serialized event.
+    /// Errors are encoded as an `error` response with a message.
     /// **Callers are responsible for calling [`nuit::nuit_c_string_drop`] on this string!**
     fire_event_json: extern "C" fn(*const CRoot, *const c_char, *const c_char) -> *const c_char,
     /// Registers a callback that we (the Rust side) can use to trigger UI updates.
-    set_update_callback: extern "C" fn(*const CRoot, extern "C" fn(*const c_char)),
+    /// The given opaque context pointer is passed back to the callback.
+    set_update_callback: extern "C" fn(*const CRoot, *mut c_void, extern "C" fn(*mut c_void, *const c_char)),
+    /// Runs the tasks that are ready to make progress.
+    run_tasks: extern "C" fn(*const CRoot),
+    /// Registers a callback that we (the Rust side) can use to request running
+    /// tasks. The callback may be invoked from any thread. The given opaque
+    /// context pointer is passed back to the callback.
+    set_wake_callback: extern "C" fn(*const CRoot, *mut c_void, extern "C" fn(*mut c_void)),
+}
+
+/// An opaque context pointer that is passed back to callbacks.
+#[derive(Clone, Copy)]
+struct CallbackContext(*mut c_void);
+
+// SAFETY: The context is never dereferenced by us, the callee is responsible
+// for accessing it on the right thread.
+unsafe impl Send for CallbackContext {}
+unsafe impl Sync for CallbackContext {}
+
+impl CallbackContext {
+    // Closures capturing the context should call this rather than accessing
+    // the field, since they'd otherwise capture (only) the raw pointer.
+    const fn get(self) -> *mut c_void {
+        self.0
+    }
 }
 
 extern "C" fn render_json_impl<T>(c_root: *const CRoot) -> *const c_char where T: View {
@@ -29,35 +54,92 @@ extern "C" fn render_json_impl<T>(c_root: *const CRoot) -> *const c_char where T
 extern "C" fn fire_event_json_impl<T>(c_root: *const CRoot, raw_id_path_json: *const c_char, raw_event_json: *const c_char) -> *const c_char where T: View {
     unsafe {
         let root = (*c_root).wrapped as *const Root<T>;
-        let id_path_json = str::from_utf8(CStr::from_ptr(raw_id_path_json).to_bytes()).expect("Could not decode id path JSON");
-        let event_json = str::from_utf8(CStr::from_ptr(raw_event_json).to_bytes()).expect("Could not decode event JSON");
-        let response_json = (*root).fire_event_json(id_path_json, event_json);
-        let c_string = CString::new(response_json).expect("Could not convert event response JSON to C string");
+        // We must not panic across the FFI boundary, so we report errors to
+        // the caller instead.
+        let response_json = decode_and_fire_event_json(&*root, raw_id_path_json, raw_event_json)
+            .unwrap_or_else(|error| error_response_json(error.to_string()));
+        let c_string = CString::new(response_json)
+            .unwrap_or_else(|error| CString::new(error_response_json(error.to_string())).expect("Error response JSON contains no NUL bytes"));
         c_string.into_raw()
     }
 }
 
-extern "C" fn set_update_callback_impl<T>(c_root: *const CRoot, update_callback: extern "C" fn(*const c_char)) where T: View {
+/// Serializes an error response with the given message. JSON escapes control
+/// characters, so the result never contains NUL bytes.
+fn error_response_json(message: String) -> String {
+    serde_json::to_string(&EventResponse::Error { message }).expect("Could not serialize error response")
+}
+
+unsafe fn decode_and_fire_event_json<T>(root: &Root<T>, raw_id_path_json: *const c_char, raw_event_json: *const c_char) -> Result<String, FireError> where T: View {
+    unsafe {
+        let id_path_json = str::from_utf8(CStr::from_ptr(raw_id_path_json).to_bytes()).map_err(|e| FireError::deserialization("id path JSON", e))?;
+        let event_json = str::from_utf8(CStr::from_ptr(raw_event_json).to_bytes()).map_err(|e| FireError::deserialization("event JSON", e))?;
+        root.fire_event_json(id_path_json, event_json)
+    }
+}
+
+extern "C" fn set_update_callback_impl<T>(c_root: *const CRoot, context: *mut c_void, update_callback: extern "C" fn(*mut c_void, *const c_char)) where T: View {
     unsafe {
         let root = (*c_root).wrapped as *const Root<T>;
+        let context = CallbackContext(context);
         (*root).set_update_callback(move |update| {
             let update_json = serde_json::to_string(update).expect("Could not encode update to JSON");
             let update_json_c_string = CString::new(update_json).expect("Could not convert update JSON to C string");
-            update_callback(update_json_c_string.as_ptr());
+            update_callback(context.get(), update_json_c_string.as_ptr());
         });
     }
 }
 
+extern "C" fn run_tasks_impl<T>(c_root: *const CRoot) where T: View {
+    unsafe {
+        let root = (*c_root).wrapped as *const Root<T>;
+        (*root).run_tasks();
+    }
+}
+
+extern "C" fn set_wake_callback_impl<T>(c_root: *const CRoot, context: *mut c_void, wake_callback: extern "C" fn(*mut c_void)) where T: View {
+    unsafe {
+        let root = (*c_root).wrapped as *const Root<T>;
+        let context = CallbackContext(context);
+        (*root).set_wake_callback(move || wake_callback(context.get()));
+    }
+}
+
 impl CRoot {
-    /// Safely uses a [`CRoot`] while returning ownership once the method returns.
-    pub fn scope_from<T, U>(root: &mut Box<Root<T>>, action: impl FnOnce(&Self) -> U) -> U where T: View {
-        let c_root = Self {
-            wrapped: ptr::from_mut(&mut **root).cast::<c_void>(),
+    fn new<T>(root: *mut Root<T>) -> Self where T: View {
+        Self {
+            wrapped: root.cast::<c_void>(),
             render_json: render_json_impl::<T>,
             fire_event_json: fire_event_json_impl::<T>,
             set_update_callback: set_update_callback_impl::<T>,
-        };
+            run_tasks: run_tasks_impl::<T>,
+            set_wake_callback: set_wake_callback_impl::<T>,
+        }
+    }
 
+    /// Safely uses a [`CRoot`] while returning ownership once the method returns.
+    pub fn scope_from<T, U>(root: &mut Box<Root<T>>, action: impl FnOnce(&Self) -> U) -> U where T: View {
+        let c_root = Self::new(ptr::from_mut(&mut **root));
         action(&c_root)
     }
+
+    /// Moves the given root into a heap-allocated [`CRoot`], e.g. to hand out
+    /// ownership to the other side of the FFI boundary.
+    /// **Callers are responsible for calling [`CRoot::drop_raw`] with the same type on this pointer!**
+    pub fn into_raw<T>(root: Root<T>) -> *mut Self where T: View {
+        Box::into_raw(Box::new(Self::new(Box::into_raw(Box::new(root)))))
+    }
+
+    /// Drops a [`CRoot`] created by [`CRoot::into_raw`] along with its root.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been created by [`CRoot::into_raw`] with the same
+    /// type `T` and must not be used afterwards.
+    pub unsafe fn drop_raw<T>(c_root: *mut Self) where T: View {
+        unsafe {
+            let c_root = Box::from_raw(c_root);
+            drop(Box::from_raw(c_root.wrapped.cast::<Root<T>>()));
+        }
+    }
 }
@@ -3,6 +3,7 @@
     let_chains,
     macro_metavar_expr,
 )]
+#![cfg_attr(test, feature(impl_trait_in_assoc_type))]
 
 #![allow(
     clippy::float_cmp,
@@ -15,20 +16,28 @@
 
 mod compose;
 mod context;
+mod environment;
 mod event;
+mod executor;
 mod ffi;
+mod localization;
 mod node;
 mod root;
+mod scene;
 mod state;
 mod update;
 mod utils;
 
 pub use compose::*;
 pub use context::*;
+pub use environment::*;
 pub use event::*;
+pub use executor::*;
 pub use ffi::*;
+pub use localization::*;
 pub use node::*;
 pub use root::*;
+pub use scene::*;
 pub use state::*;
 pub use update::*;
 pub use utils::*;
@@ -0,0 +1,138 @@
+use std::{env, fmt, sync::OnceLock};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use unic_langid::{subtags::Region, LanguageIdentifier};
+
+use crate::EnvironmentKey;
+
+/// The environment variables the preferred locales are read from, in order of
+/// precedence. `LANGUAGE` may contain a colon-separated list of locales.
+const LOCALE_VARIABLES: [&str; 4] = ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// A language, optionally with a script and region, e.g. `en-US`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    identifier: LanguageIdentifier,
+}
+
+impl Locale {
+    /// Parses a locale from a BCP 47 language tag (e.g. `de-AT`) or a POSIX
+    /// locale name (e.g. `de_AT.UTF-8`). Returns none if the tag is invalid or
+    /// names the POSIX default locale.
+    #[must_use]
+    pub fn new(tag: &str) -> Option<Self> {
+        // Strip the codeset and modifier of POSIX locale names
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        if matches!(tag, "" | "C" | "POSIX") {
+            return None;
+        }
+        let identifier = tag.replace('_', "-").parse().ok()?;
+        Some(Self { identifier })
+    }
+
+    /// The locales preferred by the user, as configured by the `LANGUAGE`,
+    /// `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables (in that
+    /// order). The variables are only read once.
+    pub fn preferred() -> &'static [Self] {
+        static PREFERRED: OnceLock<Vec<Locale>> = OnceLock::new();
+        PREFERRED.get_or_init(|| {
+            let mut locales = Vec::new();
+            for variable in LOCALE_VARIABLES {
+                let Ok(value) = env::var(variable) else { continue };
+                for locale in value.split(':').filter_map(Self::new) {
+                    if !locales.contains(&locale) {
+                        locales.push(locale);
+                    }
+                }
+            }
+            locales
+        })
+    }
+
+    /// The user's most preferred locale, falling back to `en-US`.
+    #[must_use]
+    pub fn current() -> Self {
+        Self::preferred().first().cloned().unwrap_or_default()
+    }
+
+    /// The language subtag, e.g. `en`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.identifier.language.as_str()
+    }
+
+    /// The region subtag, e.g. `US`, if any.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        self.identifier.region.as_ref().map(Region::as_str)
+    }
+
+    /// This locale followed by the less specific locales to fall back to,
+    /// e.g. `de-AT` and `de`.
+    #[must_use]
+    pub fn fallbacks(&self) -> Vec<Self> {
+        let mut fallbacks = vec![self.clone()];
+        let language = LanguageIdentifier::from_parts(self.identifier.language, None, None, &[]);
+        if language != self.identifier {
+            fallbacks.push(Self { identifier: language });
+        }
+        fallbacks
+    }
+
+    pub(crate) const fn identifier(&self) -> &LanguageIdentifier {
+        &self.identifier
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new("en-US").expect("en-US is a valid locale")
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier)
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let tag = String::deserialize(deserializer)?;
+        Self::new(&tag).ok_or_else(|| de::Error::custom(format!("Invalid locale '{tag}'")))
+    }
+}
+
+/// The environment key for the locale that localized texts are rendered in.
+/// Defaults to the user's preferred locale.
+pub struct LocaleKey;
+
+impl EnvironmentKey for LocaleKey {
+    type Value = Locale;
+
+    fn default_value() -> Locale {
+        Locale::current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Locale;
+
+    #[test]
+    fn parsing() {
+        let locale = Locale::new("de_AT.UTF-8@euro").unwrap();
+        assert_eq!(locale.to_string(), "de-AT");
+        assert_eq!((locale.language(), locale.region()), ("de", Some("AT")));
+        assert_eq!(locale.fallbacks(), [locale.clone(), Locale::new("de").unwrap()]);
+        assert_eq!(Locale::new("en").unwrap().fallbacks().len(), 1);
+        assert!(Locale::new("C").is_none());
+        assert!(Locale::new("not a locale").is_none());
+    }
+}
@@ -0,0 +1,154 @@
+use std::{cell::RefCell, collections::{HashMap, HashSet}, fs, io, path::Path, rc::Rc};
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+
+use crate::EnvironmentKey;
+
+use super::Locale;
+
+/// A collection of Fluent (`.ftl`) resources, grouped by locale, that
+/// localized texts are resolved against.
+#[derive(Clone)]
+pub struct Localization {
+    default_locale: Locale,
+    bundles: Rc<RefCell<HashMap<Locale, FluentBundle<FluentResource>>>>,
+    /// The ids of missing messages that have already been reported, so each
+    /// one is only reported once rather than on every render.
+    reported_missing_ids: Rc<RefCell<HashSet<String>>>,
+}
+
+impl Localization {
+    /// Creates an empty localization. Messages that are missing in the
+    /// requested locale are looked up in the given default locale last.
+    #[must_use]
+    pub fn new(default_locale: Locale) -> Self {
+        Self {
+            default_locale,
+            bundles: Rc::new(RefCell::new(HashMap::new())),
+            reported_missing_ids: Rc::new(RefCell::new(HashSet::new())),
+        }
+    }
+
+    /// Loads the resources from the given directory, which contains a
+    /// subdirectory with `.ftl` files (or a single `.ftl` file) per locale,
+    /// e.g. `locales/en-US/main.ftl` or `locales/de.ftl`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the directory or one of the files cannot be read.
+    pub fn from_dir(default_locale: Locale, path: impl AsRef<Path>) -> io::Result<Self> {
+        let localization = Self::new(default_locale);
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+            let Some(name) = path.file_stem().and_then(|name| name.to_str()) else { continue };
+            let is_file = path.extension().is_some_and(|extension| extension == "ftl");
+            if !is_file && !path.is_dir() {
+                continue;
+            }
+            let Some(locale) = Locale::new(name) else {
+                eprintln!("Warning: Ignoring localization resources at {} for invalid locale '{name}'", path.display());
+                continue;
+            };
+            if is_file {
+                localization.add_resource(locale, fs::read_to_string(&path)?);
+            } else {
+                for entry in fs::read_dir(&path)? {
+                    let path = entry?.path();
+                    if path.extension().is_some_and(|extension| extension == "ftl") {
+                        localization.add_resource(locale.clone(), fs::read_to_string(&path)?);
+                    }
+                }
+            }
+        }
+        Ok(localization)
+    }
+
+    /// Adds the given Fluent source for the given locale, e.g. one embedded at
+    /// compile time using `include_str!`.
+    #[must_use]
+    pub fn with_resource(self, locale: Locale, source: impl Into<String>) -> Self {
+        self.add_resource(locale, source);
+        self
+    }
+
+    /// Adds the given Fluent source for the given locale. Invalid entries and
+    /// messages that are already defined for the locale are skipped.
+    pub fn add_resource(&self, locale: Locale, source: impl Into<String>) {
+        let resource = FluentResource::try_new(source.into()).unwrap_or_else(|(resource, errors)| {
+            for error in errors {
+                eprintln!("Warning: Skipping invalid Fluent entry for {locale}: {error}");
+            }
+            resource
+        });
+        let mut bundles = self.bundles.borrow_mut();
+        let bundle = bundles.entry(locale).or_insert_with_key(|locale| {
+            let mut bundle = FluentBundle::new(vec![locale.identifier().clone()]);
+            // Isolation marks would end up verbatim in the rendered texts
+            bundle.set_use_isolating(false);
+            bundle
+        });
+        if let Err(errors) = bundle.add_resource(resource) {
+            for error in errors {
+                eprintln!("Warning: Skipping Fluent entry for {}: {error}", bundle.locales[0]);
+            }
+        }
+    }
+
+    /// The locales that resources have been added for.
+    #[must_use]
+    pub fn locales(&self) -> Vec<Locale> {
+        self.bundles.borrow().keys().cloned().collect()
+    }
+
+    /// Formats the message with the given id using the given arguments.
+    /// Messages are looked up in the given locale, its fallbacks and finally
+    /// the default locale. Returns none if no locale defines the message.
+    ///
+    /// The user's preferences are not considered here, they only determine
+    /// the default of [`LocaleKey`](crate::LocaleKey).
+    #[must_use]
+    pub fn format(&self, locale: &Locale, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let bundles = self.bundles.borrow();
+        let candidates = locale.fallbacks()
+            .into_iter()
+            .chain([self.default_locale.clone()]);
+
+        for candidate in candidates {
+            let Some(bundle) = bundles.get(&candidate) else { continue };
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else { continue };
+            let mut errors = Vec::new();
+            let formatted = bundle.format_pattern(pattern, args, &mut errors);
+            for error in errors {
+                eprintln!("Warning: Could not format '{id}' for {candidate}: {error}");
+            }
+            return Some(formatted.into_owned());
+        }
+        None
+    }
+
+    /// Warns that the message with the given id is missing, unless this has
+    /// already been reported.
+    pub(crate) fn report_missing(&self, locale: &Locale, id: &str) {
+        if self.reported_missing_ids.borrow_mut().insert(id.to_owned()) {
+            eprintln!("Warning: No localization for '{id}' in {locale}");
+        }
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new(Locale::default())
+    }
+}
+
+/// The environment key for the localization that localized texts are
+/// resolved against.
+pub struct LocalizationKey;
+
+impl EnvironmentKey for LocalizationKey {
+    type Value = Localization;
+
+    fn default_value() -> Localization {
+        Localization::default()
+    }
+}
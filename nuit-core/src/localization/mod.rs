@@ -0,0 +1,5 @@
+mod locale;
+mod localization;
+
+pub use locale::*;
+pub use localization::*;
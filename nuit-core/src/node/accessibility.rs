@@ -0,0 +1,172 @@
+use crate::{AccessibilityHeadingLevel, AccessibilityRole, IdPath, IdPathBuf, Identified, TextFieldKind};
+
+use super::{ModifierNode, Node};
+
+/// An element of the accessibility tree, i.e. of what an assistive technology
+/// sees of a rendered node tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibilityNode {
+    /// The id path of the node this element originates from.
+    pub id_path: IdPathBuf,
+    pub role: AccessibilityRole,
+    pub label: Option<String>,
+    pub value: Option<String>,
+    pub hint: Option<String>,
+    pub heading_level: AccessibilityHeadingLevel,
+    /// The names of the custom actions offered by this element.
+    pub actions: Vec<String>,
+    pub children: Vec<Self>,
+}
+
+impl AccessibilityNode {
+    fn new(id_path: &IdPath, role: AccessibilityRole) -> Self {
+        Self {
+            id_path: id_path.to_owned(),
+            role,
+            label: None,
+            value: None,
+            hint: None,
+            heading_level: AccessibilityHeadingLevel::default(),
+            actions: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    #[must_use]
+    fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    #[must_use]
+    fn with_children(mut self, children: Vec<Self>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// This element and all of its descendants, in pre-order.
+    #[must_use]
+    pub fn descendants(&self) -> Vec<&Self> {
+        let mut descendants = vec![self];
+        for child in &self.children {
+            descendants.extend(child.descendants());
+        }
+        descendants
+    }
+}
+
+impl Node {
+    /// Computes the accessibility tree of this node, i.e. the elements that an
+    /// assistive technology would see. Layout and most modifiers are
+    /// transparent, hidden subtrees are omitted and accessibility modifiers
+    /// override the properties of the element they are applied to (or of a
+    /// group wrapping the elements, if there are several).
+    #[must_use]
+    pub fn accessibility_tree(&self) -> Vec<AccessibilityNode> {
+        self.accessibility_elements(IdPath::root())
+    }
+
+    fn accessibility_elements(&self, path: &IdPath) -> Vec<AccessibilityNode> {
+        let child_elements = |child: &Identified<Self>| child.value().accessibility_elements(&path.child(child.id().clone()));
+        match self {
+            Self::Text { content } => vec![AccessibilityNode::new(path, AccessibilityRole::StaticText).with_label(content)],
+            Self::TextField { content, prompt, kind, .. } => {
+                let mut element = AccessibilityNode::new(path, AccessibilityRole::TextField);
+                element.label.clone_from(prompt);
+                // Like in SwiftUI, the contents of secure fields aren't read
+                if *kind != TextFieldKind::Secure {
+                    element.value = Some(content.clone());
+                }
+                vec![element]
+            },
+            Self::Button { label, .. } => vec![labeled_element(path, AccessibilityRole::Button, &child_elements(label))],
+            Self::NavigationLink { label, .. } => vec![labeled_element(path, AccessibilityRole::Link, &child_elements(label))],
+            Self::Picker { title, .. } => vec![AccessibilityNode::new(path, AccessibilityRole::Picker).with_label(title)],
+            Self::DatePicker { title, date } => vec![AccessibilityNode::new(path, AccessibilityRole::Picker).with_label(title).with_value(date.to_string())],
+            Self::Slider { value, .. } => vec![AccessibilityNode::new(path, AccessibilityRole::Slider).with_value(value.to_string())],
+            Self::Image { .. } => vec![AccessibilityNode::new(path, AccessibilityRole::Image)],
+            Self::Toggle { title, is_on } => vec![AccessibilityNode::new(path, AccessibilityRole::Toggle).with_label(title).with_value(if *is_on { "On" } else { "Off" })],
+            Self::Stepper { title, value, .. } => vec![AccessibilityNode::new(path, AccessibilityRole::Stepper).with_label(title).with_value(value.to_string())],
+            Self::ProgressView { value } => {
+                let element = AccessibilityNode::new(path, AccessibilityRole::ProgressIndicator);
+                vec![match value {
+                    Some(value) => element.with_value(format!("{}%", (value * 100.0).round())),
+                    None => element,
+                }]
+            },
+            Self::List { wrapped, .. } => vec![AccessibilityNode::new(path, AccessibilityRole::List).with_children(child_elements(wrapped))],
+            // The rows of lazy lists are only rendered on demand
+            Self::LazyList { .. } => vec![AccessibilityNode::new(path, AccessibilityRole::List)],
+            Self::Sheet { wrapped, is_presented, content } | Self::Popover { wrapped, is_presented, content } => {
+                let mut elements = child_elements(wrapped);
+                if *is_presented {
+                    elements.extend(child_elements(content));
+                }
+                elements
+            },
+            Self::Alert { wrapped, title, message, is_presented, actions } | Self::ConfirmationDialog { wrapped, title, message, is_presented, actions } => {
+                let mut elements = child_elements(wrapped);
+                if *is_presented {
+                    let mut dialog = AccessibilityNode::new(path, AccessibilityRole::Group).with_label(title).with_children(child_elements(actions));
+                    dialog.value.clone_from(message);
+                    elements.push(dialog);
+                }
+                elements
+            },
+            // Context menus and swipe actions are only shown on demand
+            Self::ContextMenu { wrapped, .. } | Self::SwipeActions { wrapped, .. } => child_elements(wrapped),
+            Self::Modified { wrapped, modifier } => {
+                let elements = child_elements(wrapped);
+                match modifier {
+                    ModifierNode::Hidden { is_hidden } | ModifierNode::AccessibilityHidden { is_hidden } if *is_hidden => Vec::new(),
+                    ModifierNode::Help { text } => override_elements(path, elements, |element| element.hint = Some(text.clone())),
+                    ModifierNode::AccessibilityLabel { label } => override_elements(path, elements, |element| element.label = Some(label.clone())),
+                    ModifierNode::AccessibilityHint { hint } => override_elements(path, elements, |element| element.hint = Some(hint.clone())),
+                    ModifierNode::AccessibilityValue { value } => override_elements(path, elements, |element| element.value = Some(value.clone())),
+                    ModifierNode::AccessibilityRole { role } => override_elements(path, elements, |element| element.role = *role),
+                    ModifierNode::AccessibilityHeading { level } => override_elements(path, elements, |element| {
+                        element.role = AccessibilityRole::Heading;
+                        element.heading_level = *level;
+                    }),
+                    ModifierNode::AccessibilityAction { name } => override_elements(path, elements, |element| element.actions.push(name.clone())),
+                    _ => elements,
+                }
+            },
+            _ => self.identified_children().into_iter().flat_map(child_elements).collect(),
+        }
+    }
+}
+
+/// Creates a leaf element whose label is composed of the labels of the given
+/// elements, e.g. those of a button's label.
+fn labeled_element(path: &IdPath, role: AccessibilityRole, label_elements: &[AccessibilityNode]) -> AccessibilityNode {
+    let label = label_elements.iter()
+        .flat_map(AccessibilityNode::descendants)
+        .filter_map(|element| element.label.as_deref())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut element = AccessibilityNode::new(path, role);
+    if !label.is_empty() {
+        element.label = Some(label);
+    }
+    element
+}
+
+/// Applies an accessibility override to the single given element or, if there
+/// is none or there are several, to a group containing them.
+fn override_elements(path: &IdPath, mut elements: Vec<AccessibilityNode>, apply: impl FnOnce(&mut AccessibilityNode)) -> Vec<AccessibilityNode> {
+    if elements.len() == 1 {
+        apply(&mut elements[0]);
+        elements
+    } else {
+        let mut group = AccessibilityNode::new(path, AccessibilityRole::Group).with_children(elements);
+        apply(&mut group);
+        vec![group]
+    }
+}
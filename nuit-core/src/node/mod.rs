@@ -1,8 +1,10 @@
+mod accessibility;
 mod gesture;
 mod modifier;
 mod node;
 mod shape;
 
+pub use accessibility::*;
 pub use gesture::*;
 pub use modifier::*;
 pub use node::*;
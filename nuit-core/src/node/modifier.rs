@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-use crate::{Alignment, Angle, EdgeSet, Font, Frame, Insets, NavigationTitleDisplayMode, Style, UnitPoint, Vec2};
+use crate::{AccessibilityHeadingLevel, AccessibilityRole, Alignment, Angle, ContentMode, EdgeSet, Font, Frame, HorizontalAlignment, Insets, Interpolation, KeyboardShortcut, NavigationTitleDisplayMode, Style, UnitPoint, Vec2};
 
 /// A rendered modifier.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -35,4 +35,18 @@ pub enum ModifierNode {
     Saturation { amount: f64 },
     HueRotation { angle: Angle },
     Clipped { },
+    Resizable {},
+    AspectRatio { ratio: Option<f64>, content_mode: ContentMode },
+    Interpolation { interpolation: Interpolation },
+    KeyboardShortcut { shortcut: KeyboardShortcut },
+    Focused { is_focused: bool },
+    GridCellColumns { count: usize },
+    GridColumnAlignment { alignment: HorizontalAlignment },
+    AccessibilityLabel { label: String },
+    AccessibilityHint { hint: String },
+    AccessibilityValue { value: String },
+    AccessibilityRole { role: AccessibilityRole },
+    AccessibilityHeading { level: AccessibilityHeadingLevel },
+    AccessibilityHidden { is_hidden: bool },
+    AccessibilityAction { name: String },
 }
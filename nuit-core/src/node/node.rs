@@ -2,7 +2,7 @@ use nuit_derive::Diff;
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
 
-use crate::{Alignment, HorizontalAlignment, Id, IdPath, IdPathBuf, Identified, VerticalAlignment};
+use crate::{Alignment, ButtonRole, CalendarDate, GridItem, HorizontalAlignment, Id, IdPath, IdPathBuf, Identified, ImageSource, KeyboardType, SelectionMode, TextFieldKind, ToolbarItemPlacement, VerticalAlignment};
 
 use super::{GestureNode, ModifierNode, ShapeNode};
 
@@ -14,21 +14,35 @@ pub enum Node {
 
     // Widget
     Text { content: String },
-    TextField { content: String },
-    Button { label: Box<Identified<Node>> },
+    TextField { content: String, prompt: Option<String>, kind: TextFieldKind, keyboard_type: KeyboardType, is_autocorrection_disabled: bool },
+    Button { label: Box<Identified<Node>>, role: Option<ButtonRole> },
     Picker { title: String, selection: Id, content: Box<Identified<Node>> },
     Slider { value: f64, lower_bound: f64, upper_bound: f64, step: Option<f64> },
+    Image { source: ImageSource },
+    Toggle { title: String, is_on: bool },
+    Stepper { title: String, value: i64, lower_bound: i64, upper_bound: i64, step: i64 },
+    ProgressView { value: Option<f64> },
+    DatePicker { title: String, date: CalendarDate },
 
     // Aggregation
     Child { wrapped: Box<Identified<Node>> },
     Group { children: Vec<Identified<Node>> },
+    Editable { wrapped: Box<Identified<Node>>, can_delete: bool, can_move: bool },
 
     // Layout
     GeometryReader {},
     VStack { alignment: HorizontalAlignment, spacing: f64, wrapped: Box<Identified<Node>> },
     HStack { alignment: VerticalAlignment, spacing: f64, wrapped: Box<Identified<Node>> },
     ZStack { alignment: Alignment, spacing: f64, wrapped: Box<Identified<Node>> },
-    List { wrapped: Box<Identified<Node>> },
+    List { wrapped: Box<Identified<Node>>, selection_mode: SelectionMode, selection: Vec<Id> },
+    Section { header: Box<Identified<Node>>, content: Box<Identified<Node>>, footer: Box<Identified<Node>> },
+    LazyVStack { alignment: HorizontalAlignment, spacing: f64, count: usize },
+    LazyHStack { alignment: VerticalAlignment, spacing: f64, count: usize },
+    LazyList { count: usize },
+    Grid { alignment: Alignment, horizontal_spacing: f64, vertical_spacing: f64, wrapped: Box<Identified<Node>> },
+    GridRow { alignment: Option<VerticalAlignment>, wrapped: Box<Identified<Node>> },
+    LazyVGrid { columns: Vec<GridItem>, alignment: HorizontalAlignment, spacing: f64, count: usize },
+    LazyHGrid { rows: Vec<GridItem>, alignment: VerticalAlignment, spacing: f64, count: usize },
     Overlay { wrapped: Box<Identified<Node>>, alignment: Alignment, overlayed: Box<Identified<Node>> },
 
     // Navigation
@@ -37,6 +51,18 @@ pub enum Node {
     NavigationLink { label: Box<Identified<Node>>, value: Value },
     NavigationDestination { wrapped: Box<Identified<Node>> },
 
+    // Presentation
+    Sheet { wrapped: Box<Identified<Node>>, is_presented: bool, content: Box<Identified<Node>> },
+    Popover { wrapped: Box<Identified<Node>>, is_presented: bool, content: Box<Identified<Node>> },
+    Alert { wrapped: Box<Identified<Node>>, title: String, message: Option<String>, is_presented: bool, actions: Box<Identified<Node>> },
+    ConfirmationDialog { wrapped: Box<Identified<Node>>, title: String, message: Option<String>, is_presented: bool, actions: Box<Identified<Node>> },
+    ContextMenu { wrapped: Box<Identified<Node>>, actions: Box<Identified<Node>> },
+    SwipeActions { wrapped: Box<Identified<Node>>, actions: Box<Identified<Node>> },
+
+    // Toolbar
+    Toolbar { wrapped: Box<Identified<Node>>, items: Box<Identified<Node>> },
+    ToolbarItem { placement: ToolbarItemPlacement, wrapped: Box<Identified<Node>> },
+
     // Wrapper
     Shape { shape: ShapeNode },
     Gestured { wrapped: Box<Identified<Node>>, gesture: Identified<GestureNode>, },
@@ -55,9 +81,93 @@ impl Node {
             Self::Group { children } => children.iter()
                 .flat_map(|c| c.value().children_from(&path.child(c.id().clone())).into_iter())
                 .collect(),
+            // Editable groups only add editing actions to their children
+            Self::Editable { wrapped, .. } => wrapped.value().children_from(&path.child(wrapped.id().clone())),
             _ => vec![(path.to_owned(), self)]
         }
     }
+
+    /// The direct children of this node, i.e. the nodes one id path component
+    /// below it.
+    #[must_use]
+    pub fn identified_children(&self) -> Vec<&Identified<Self>> {
+        match self {
+            Self::Empty {}
+            | Self::Text { .. }
+            | Self::TextField { .. }
+            | Self::Slider { .. }
+            | Self::Image { .. }
+            | Self::Toggle { .. }
+            | Self::Stepper { .. }
+            | Self::ProgressView { .. }
+            | Self::DatePicker { .. }
+            | Self::GeometryReader {}
+            | Self::LazyVStack { .. }
+            | Self::LazyHStack { .. }
+            | Self::LazyList { .. }
+            | Self::LazyVGrid { .. }
+            | Self::LazyHGrid { .. }
+            | Self::Shape { .. } => vec![],
+            Self::Group { children } => children.iter().collect(),
+            Self::Button { label: wrapped, .. }
+            | Self::Picker { content: wrapped, .. }
+            | Self::Child { wrapped }
+            | Self::VStack { wrapped, .. }
+            | Self::HStack { wrapped, .. }
+            | Self::ZStack { wrapped, .. }
+            | Self::Editable { wrapped, .. }
+            | Self::List { wrapped, .. }
+            | Self::Grid { wrapped, .. }
+            | Self::GridRow { wrapped, .. }
+            | Self::NavigationStack { wrapped, .. }
+            | Self::NavigationLink { label: wrapped, .. }
+            | Self::NavigationDestination { wrapped }
+            | Self::ToolbarItem { wrapped, .. }
+            | Self::Gestured { wrapped, .. }
+            | Self::Modified { wrapped, .. } => vec![wrapped],
+            Self::Overlay { wrapped, overlayed, .. } => vec![wrapped, overlayed],
+            Self::Toolbar { wrapped, items } => vec![wrapped, items],
+            Self::Sheet { wrapped, content, .. }
+            | Self::Popover { wrapped, content, .. }
+            | Self::Alert { wrapped, actions: content, .. }
+            | Self::ConfirmationDialog { wrapped, actions: content, .. }
+            | Self::ContextMenu { wrapped, actions: content }
+            | Self::SwipeActions { wrapped, actions: content } => vec![wrapped, content],
+            Self::Section { header, content, footer } => vec![header, content, footer],
+            Self::NavigationSplitView { sidebar, content, detail } => vec![sidebar, content, detail],
+        }
+    }
+
+    /// This node and all of its descendants, paired with their id paths, in
+    /// pre-order.
+    #[must_use]
+    pub fn descendants(&self) -> Vec<(IdPathBuf, &Self)> {
+        self.descendants_from(IdPath::root())
+    }
+
+    /// This node and all of its descendants, paired with their id paths
+    /// (relative to the given one), in pre-order.
+    #[must_use]
+    pub fn descendants_from(&self, path: &IdPath) -> Vec<(IdPathBuf, &Self)> {
+        let mut descendants = vec![(path.to_owned(), self)];
+        for child in self.identified_children() {
+            descendants.extend(child.value().descendants_from(&path.child(child.id().clone())));
+        }
+        descendants
+    }
+
+    /// Looks up the descendant at the given id path, relative to this node.
+    #[must_use]
+    pub fn descendant(&self, path: &IdPath) -> Option<&Self> {
+        if let Some(head) = path.head() {
+            self.identified_children()
+                .into_iter()
+                .find(|child| child.id() == &head)
+                .and_then(|child| child.value().descendant(path.tail()))
+        } else {
+            Some(self)
+        }
+    }
 }
 
 impl Default for Node {
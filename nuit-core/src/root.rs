@@ -1,31 +1,56 @@
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{Context, Diff, Event, EventResponse, IdPath, IdPathBuf, Node, Storage, Update, View};
+use crate::{Context, Diff, EnvironmentKey, EnvironmentValues, Event, EventResponse, Executor, FireError, IdPath, IdPathBuf, LocalExecutor, Node, PersistenceBackend, Storage, Update, View};
 
 /// The central state of a Nuit application.
 pub struct Root<T> {
     view: RefCell<T>,
     storage: Rc<Storage>,
     last_render: RefCell<Node>,
+    /// The environment passed to the root view.
+    environment: RefCell<EnvironmentValues>,
+    /// The executor running tasks unless a backend sets its own.
+    local_executor: Rc<LocalExecutor>,
 }
 
 impl<T> Root<T> {
     pub fn new(view: T) -> Self {
+        let storage = Rc::new(Storage::new());
+        let local_executor = Rc::new(LocalExecutor::new());
+        storage.set_executor(local_executor.clone());
         Self {
             view: RefCell::new(view),
-            storage: Rc::new(Storage::new()),
+            storage,
             last_render: RefCell::new(Node::Empty {}),
+            environment: RefCell::new(EnvironmentValues::new()),
+            local_executor,
         }
     }
 
     pub const fn storage(&self) -> &Rc<Storage> {
         &self.storage
     }
+
+    /// Sets the given environment key to the given value for the root view.
+    pub fn set_environment<K>(&self, value: K::Value) where K: EnvironmentKey {
+        let environment = self.environment.borrow().with::<K>(value);
+        *self.environment.borrow_mut() = environment;
+    }
+
+    /// Replaces the environment of the root view with the given values.
+    pub(crate) fn set_environment_values(&self, environment: EnvironmentValues) {
+        *self.environment.borrow_mut() = environment;
+    }
+
+    fn context(&self) -> Context {
+        Context::with_environment_values(self.storage.clone(), self.environment.borrow().clone())
+    }
 }
 
 impl<T> Root<T> where T: View {
     pub fn render(&self) -> Node {
-        let context = Context::new(self.storage.clone());
+        let context = self.context();
+        self.storage.discard_pending_tasks();
         let new_render = self.storage.with_preapplied_changes(|| {
             self.view.borrow().render(&context)
         });
@@ -35,14 +60,22 @@ impl<T> Root<T> where T: View {
             let diff = new_render.diff(&last_render);
 
             for (id_path, _) in &diff.removed {
-                self.view.borrow().fire(&Event::Disappear, id_path, &context);
+                // Removed views are usually no longer reachable in the new
+                // view tree, so failing to notify them is expected here. We
+                // therefore cancel their tasks directly too.
+                self.storage.cancel_tasks(id_path);
+                let _ = self.view.borrow().fire(&Event::Disappear {}, id_path, &context);
             }
 
             self.storage.apply_changes();
 
             for (id_path, _) in &diff.added {
-                self.view.borrow().fire(&Event::Appear, id_path, &context);
+                if let Err(error) = self.view.borrow().fire(&Event::Appear {}, id_path, &context) {
+                    eprintln!("Warning: Could not fire appearance event: {error}");
+                }
             }
+
+            self.storage.start_pending_tasks(IdPath::root());
         }
 
         *self.last_render.borrow_mut() = new_render.clone();
@@ -62,21 +95,61 @@ impl<T> Root<T> where T: View {
     /// Fires an event at the given path, returning the response, both
     /// represented as raw JSON. Mainly intended for FFI use.
     /// 
+    /// # Errors
+    /// 
+    /// Fails if the id path or the event cannot be deserialized or the event
+    /// cannot be dispatched, e.g. since it is stale.
+    /// 
     /// # Panics
     /// 
-    /// Panics if the view cannot be deserialized from JSON. We consider this a bug.
-    pub fn fire_event_json(&self, id_path_json: &str, event_json: &str) -> String {
-        let id_path: IdPathBuf = serde_json::from_str(id_path_json).expect("Could not deserialize id path");
-        let event: Event = serde_json::from_str(event_json).expect("Could not deserialize event");
-        let response = self.fire_event(&id_path, &event);
-        serde_json::to_string(&response).expect("Could not serialize event response")
+    /// Panics if the response cannot be serialized as JSON. We consider this a bug.
+    pub fn fire_event_json(&self, id_path_json: &str, event_json: &str) -> Result<String, FireError> {
+        let id_path: IdPathBuf = serde_json::from_str(id_path_json).map_err(|e| FireError::deserialization("id path", e))?;
+        let event: Event = serde_json::from_str(event_json).map_err(|e| FireError::deserialization("event", e))?;
+        let response = self.fire_event(&id_path, &event)?;
+        Ok(serde_json::to_string(&response).expect("Could not serialize event response"))
     }
 
-    pub fn fire_event(&self, id_path: &IdPath, event: &Event) -> EventResponse {
-        self.view.borrow().fire(event, id_path, &Context::new(self.storage.clone()))
+    /// Fires an event at the given path, returning the response.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails if the event cannot be dispatched, e.g. since it is stale.
+    pub fn fire_event(&self, id_path: &IdPath, event: &Event) -> Result<EventResponse, FireError> {
+        self.view.borrow().fire(event, id_path, &self.context())
     }
 
     pub fn set_update_callback(&self, update_callback: impl Fn(&Update) + 'static) {
         self.storage.set_update_callback(update_callback);
     }
+
+    /// Runs the tasks that are ready to make progress on the root's local
+    /// executor. Backends that set their own executor don't need to call this.
+    pub fn run_tasks(&self) {
+        self.local_executor.run_until_stalled();
+    }
+
+    /// Sets a callback that is invoked, possibly from another thread, whenever
+    /// [`Root::run_tasks`] should be called.
+    pub fn set_wake_callback(&self, wake_callback: impl Fn() + Send + Sync + 'static) {
+        self.local_executor.set_wake_callback(wake_callback);
+    }
+
+    /// Replaces the local executor, e.g. with one driven by the backend's
+    /// main loop.
+    pub fn set_executor(&self, executor: impl Executor + 'static) {
+        self.storage.set_executor(executor);
+    }
+
+    pub fn set_persistence_backend(&self, persistence_backend: impl PersistenceBackend + 'static) {
+        self.storage.set_persistence_backend(persistence_backend);
+    }
+}
+
+impl<T> Drop for Root<T> {
+    fn drop(&mut self) {
+        // Tasks may keep state (and thereby the storage) alive, so we cancel
+        // them to avoid leaking them, e.g. when a window is closed
+        self.storage.cancel_tasks(IdPath::root());
+    }
 }
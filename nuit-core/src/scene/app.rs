@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use serde_json::Value;
+
+use crate::{AnyView, Commands, EnvironmentKey, EnvironmentValues, OpenWindow, OpenWindowKey, PersistenceBackend, Root, Scene, SceneError, SceneKind, View};
+
+/// An app, consisting of the scenes it can present.
+pub struct App {
+    id: String,
+    scenes: Vec<Scene>,
+    commands: Commands,
+    persistence_backend: Option<Rc<dyn PersistenceBackend>>,
+    environment: EnvironmentValues,
+}
+
+impl App {
+    /// The application id used if none is specified.
+    pub const DEFAULT_ID: &'static str = "com.example.NuitApp";
+
+    /// Creates an app without scenes with the given application id, which
+    /// should be in reverse-DNS notation.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            scenes: Vec::new(),
+            commands: Commands::new(),
+            persistence_backend: None,
+            environment: EnvironmentValues::new(),
+        }
+    }
+
+    /// Adds the given scene. The first scene that is not the settings is
+    /// opened on launch.
+    #[must_use]
+    pub fn scene(mut self, scene: Scene) -> Self {
+        self.scenes.push(scene);
+        self
+    }
+
+    /// Sets the app-level commands, which are shown in the menu bar.
+    #[must_use]
+    pub fn with_commands(mut self, commands: Commands) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Replaces the application id.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Sets the backend that persisted state is restored from and saved to,
+    /// which is shared by all windows.
+    #[must_use]
+    pub fn persistence_backend(mut self, persistence_backend: impl PersistenceBackend + 'static) -> Self {
+        self.persistence_backend = Some(Rc::new(persistence_backend));
+        self
+    }
+
+    /// Sets the given environment key to the given value for the views of
+    /// all windows, e.g. to provide the localization once.
+    #[must_use]
+    pub fn environment<K>(mut self, _key: K, value: impl Into<K::Value>) -> Self where K: EnvironmentKey {
+        self.environment = self.environment.with::<K>(value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn scenes(&self) -> &[Scene] {
+        &self.scenes
+    }
+
+    #[must_use]
+    pub const fn commands(&self) -> &Commands {
+        &self.commands
+    }
+
+    /// The scene with the given id, if any.
+    #[must_use]
+    pub fn find_scene(&self, id: &str) -> Option<&Scene> {
+        self.scenes.iter().find(|scene| scene.info().id() == id)
+    }
+
+    /// The scene to open on launch, if any.
+    #[must_use]
+    pub fn launch_scene(&self) -> Option<&Scene> {
+        self.scenes.iter().find(|scene| scene.info().kind() != SceneKind::Settings)
+    }
+
+    /// Creates the root for a new window of the scene with the given id,
+    /// presenting the given value. Views in the window can open further
+    /// windows with the given action.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the scene doesn't exist or cannot create a view for the value.
+    pub fn make_root(&self, scene_id: &str, value: Value, open_window: OpenWindow) -> Result<Root<AnyView>, SceneError> {
+        let scene = self.find_scene(scene_id).ok_or_else(|| SceneError::UnknownScene { id: scene_id.to_owned() })?;
+        let root = Root::new(scene.make_view(value)?);
+        root.set_environment_values(self.environment.clone());
+        root.set_environment::<OpenWindowKey>(open_window);
+        if let Some(persistence_backend) = &self.persistence_backend {
+            root.set_persistence_backend(persistence_backend.clone());
+        }
+        Ok(root)
+    }
+
+    /// Writes the persisted state that has been saved but not written yet, if
+    /// the app has a persistence backend. Backends call this when the app
+    /// terminates.
+    pub fn flush_persisted_state(&self) {
+        if let Some(persistence_backend) = &self.persistence_backend {
+            persistence_backend.flush();
+        }
+    }
+}
+
+impl<T> From<T> for App where T: View + 'static {
+    fn from(view: T) -> Self {
+        Self::new(Self::DEFAULT_ID).scene(Scene::window("main", view))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nuit_derive::Bind;
+    use serde_json::{json, Value};
+
+    use crate::{clone, App, Button, Environment, Event, Node, OpenWindow, OpenWindowKey, Scene, SceneError, Text, View, VStack};
+
+    #[derive(Bind)]
+    struct DocumentView {
+        name: String,
+        open_window: Environment<OpenWindowKey>,
+    }
+
+    impl View for DocumentView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let open_window = self.open_window.get();
+            let next = format!("{}!", self.name);
+            VStack::from((
+                Text::new(format!("Document {}", self.name)),
+                Button::with_text("Next", clone!(open_window => move || open_window.open_with("document", &next))),
+                Button::with_text("Settings", move || open_window.open("settings")),
+            ))
+        }
+    }
+
+    #[test]
+    fn scenes() {
+        let app = App::new("com.example.Test")
+            .scene(Scene::window_group("document", |name: String| DocumentView { name, open_window: Environment::default() }))
+            .scene(Scene::settings(Text::new("Settings")));
+        assert_eq!(app.launch_scene().map(|scene| scene.info().id()), Some("document"));
+
+        let opened = Rc::new(RefCell::new(Vec::new()));
+        let open_window = OpenWindow::new(clone!(opened => move |id, value| opened.borrow_mut().push((id.to_owned(), value))));
+
+        let first_text = |node: Node| node.descendants()
+            .into_iter()
+            .find_map(|(_, node)| match node {
+                Node::Text { content } => Some(content.clone()),
+                _ => None,
+            });
+
+        let root = app.make_root("document", json!("a"), open_window.clone()).unwrap();
+        let node = root.render();
+        assert_eq!(first_text(node.clone()).as_deref(), Some("Document a"));
+        // Tap "Next", then "Settings"
+        for (id_path, _) in node.descendants().into_iter().filter(|(_, node)| matches!(node, Node::Button { .. })) {
+            root.fire_event(&id_path, &Event::ButtonTap {}).unwrap();
+        }
+        assert_eq!(*opened.borrow(), vec![("document".to_owned(), json!("a!")), ("settings".to_owned(), Value::Null)]);
+
+        // Every window has its own root
+        let other_root = app.make_root("document", json!("a!"), open_window.clone()).unwrap();
+        assert_eq!(first_text(other_root.render()).as_deref(), Some("Document a!"));
+        assert_eq!(first_text(root.render()).as_deref(), Some("Document a"));
+
+        let settings_root = app.make_root("settings", Value::Null, open_window.clone()).unwrap();
+        assert_eq!(first_text(settings_root.render()).as_deref(), Some("Settings"));
+        assert!(matches!(app.make_root("settings", Value::Null, open_window.clone()), Err(SceneError::AlreadyOpen { .. })));
+        assert!(matches!(app.make_root("document", json!(42), open_window), Err(SceneError::Deserialization { .. })));
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{EventModifiers, Key, KeyboardShortcut};
+
+/// The backend-facing description of an item in a command menu.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum CommandItemInfo {
+    Button { title: String, shortcut: Option<KeyboardShortcut> },
+    Divider {},
+}
+
+/// An item in a command menu.
+pub struct CommandItem {
+    info: CommandItemInfo,
+    action: Option<Box<dyn Fn()>>,
+}
+
+impl CommandItem {
+    /// Creates a button performing the given action when chosen.
+    pub fn button(title: impl Into<String>, action: impl Fn() + 'static) -> Self {
+        Self {
+            info: CommandItemInfo::Button { title: title.into(), shortcut: None },
+            action: Some(Box::new(action)),
+        }
+    }
+
+    /// Creates a divider separating groups of buttons.
+    #[must_use]
+    pub const fn divider() -> Self {
+        Self {
+            info: CommandItemInfo::Divider {},
+            action: None,
+        }
+    }
+
+    /// Assigns a keyboard shortcut to this item, if it is a button.
+    #[must_use]
+    pub fn keyboard_shortcut(mut self, key: impl Into<Key>, modifiers: EventModifiers) -> Self {
+        if let CommandItemInfo::Button { ref mut shortcut, .. } = self.info {
+            *shortcut = Some(KeyboardShortcut::new(key.into(), modifiers));
+        }
+        self
+    }
+
+    #[must_use]
+    pub const fn info(&self) -> &CommandItemInfo {
+        &self.info
+    }
+
+    /// Performs the item's action, if any.
+    pub fn perform(&self) {
+        if let Some(action) = &self.action {
+            action();
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{CommandItem, CommandItemInfo};
+
+/// The backend-facing description of a command menu.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMenuInfo {
+    title: String,
+    items: Vec<CommandItemInfo>,
+}
+
+impl CommandMenuInfo {
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[CommandItemInfo] {
+        &self.items
+    }
+}
+
+/// A menu in the app's menu bar (or, on platforms without one, in the primary
+/// menu of every window).
+pub struct CommandMenu {
+    title: String,
+    items: Vec<CommandItem>,
+}
+
+impl CommandMenu {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Appends the given item.
+    #[must_use]
+    pub fn item(mut self, item: CommandItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Appends a button performing the given action.
+    #[must_use]
+    pub fn button(self, title: impl Into<String>, action: impl Fn() + 'static) -> Self {
+        self.item(CommandItem::button(title, action))
+    }
+
+    /// Appends a divider.
+    #[must_use]
+    pub fn divider(self) -> Self {
+        self.item(CommandItem::divider())
+    }
+
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[CommandItem] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn info(&self) -> CommandMenuInfo {
+        CommandMenuInfo {
+            title: self.title.clone(),
+            items: self.items.iter().map(|item| item.info().clone()).collect(),
+        }
+    }
+}
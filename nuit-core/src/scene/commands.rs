@@ -0,0 +1,93 @@
+use crate::{CommandItem, CommandMenu, CommandMenuInfo, SceneError};
+
+/// The app-level commands, organized into menus. Backends present them in the
+/// menu bar and register their keyboard shortcuts for all windows.
+#[derive(Default)]
+pub struct Commands {
+    menus: Vec<CommandMenu>,
+}
+
+impl Commands {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { menus: Vec::new() }
+    }
+
+    /// Appends the given menu.
+    #[must_use]
+    pub fn menu(mut self, menu: CommandMenu) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    #[must_use]
+    pub fn menus(&self) -> &[CommandMenu] {
+        &self.menus
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.menus.is_empty()
+    }
+
+    #[must_use]
+    pub fn info(&self) -> Vec<CommandMenuInfo> {
+        self.menus.iter().map(CommandMenu::info).collect()
+    }
+
+    /// The item at the given index in the menu at the given index, if any.
+    #[must_use]
+    pub fn item(&self, menu: usize, item: usize) -> Option<&CommandItem> {
+        self.menus.get(menu)?.items().get(item)
+    }
+
+    /// Performs the action of the item at the given index in the menu at the
+    /// given index.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no such item.
+    pub fn perform(&self, menu: usize, item: usize) -> Result<(), SceneError> {
+        self.item(menu, item)
+            .ok_or(SceneError::UnknownCommand { menu, item })?
+            .perform();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use serde_json::json;
+
+    use crate::{clone, App, CommandItem, CommandMenu, Commands, EventModifiers, Scene, SceneError, Text};
+
+    #[test]
+    fn menus() {
+        let performed = Rc::new(RefCell::new(Vec::new()));
+        let app = App::new("com.example.Test")
+            .scene(Scene::window("main", Text::new("Main")))
+            .with_commands(Commands::new().menu(CommandMenu::new("File")
+                .item(CommandItem::button("New", clone!(performed => move || performed.borrow_mut().push("new")))
+                    .keyboard_shortcut('n', EventModifiers::COMMAND))
+                .divider()
+                .button("Close", clone!(performed => move || performed.borrow_mut().push("close")))));
+
+        let info = serde_json::to_value(app.commands().info()).unwrap();
+        assert_eq!(info, json!([{
+            "title": "File",
+            "items": [
+                { "button": { "title": "New", "shortcut": { "key": { "character": { "character": "n" } }, "modifiers": { "rawValue": 1 } } } },
+                { "divider": {} },
+                { "button": { "title": "Close", "shortcut": null } },
+            ],
+        }]));
+
+        app.commands().perform(0, 2).unwrap();
+        app.commands().perform(0, 0).unwrap();
+        app.commands().perform(0, 1).unwrap();
+        assert_eq!(*performed.borrow(), vec!["close", "new"]);
+        assert_eq!(app.commands().perform(1, 0), Err(SceneError::UnknownCommand { menu: 1, item: 0 }));
+    }
+}
@@ -0,0 +1,28 @@
+use std::{error::Error, fmt};
+
+/// An error that occurred while opening a window for a scene or performing
+/// an app-level command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SceneError {
+    /// The app has no scene with the given id.
+    UnknownScene { id: String },
+    /// The scene only supports a single window, which has already been opened.
+    AlreadyOpen { id: String },
+    /// The value for a window group could not be deserialized.
+    Deserialization { id: String, message: String },
+    /// The app has no command at the given indices.
+    UnknownCommand { menu: usize, item: usize },
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownScene { id } => write!(f, "Unknown scene '{id}'"),
+            Self::AlreadyOpen { id } => write!(f, "The window for scene '{id}' is already open"),
+            Self::Deserialization { id, message } => write!(f, "Could not deserialize value for scene '{id}': {message}"),
+            Self::UnknownCommand { menu, item } => write!(f, "Unknown command {item} in menu {menu}"),
+        }
+    }
+}
+
+impl Error for SceneError {}
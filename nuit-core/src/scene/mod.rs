@@ -0,0 +1,15 @@
+mod app;
+mod command_item;
+mod command_menu;
+mod commands;
+mod error;
+mod open_window;
+mod scene;
+
+pub use app::*;
+pub use command_item::*;
+pub use command_menu::*;
+pub use commands::*;
+pub use error::*;
+pub use open_window::*;
+pub use scene::*;
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::EnvironmentKey;
+
+/// An action that opens a window for one of the app's scenes. Views can access
+/// it through the [`OpenWindowKey`] environment key.
+#[derive(Clone)]
+pub struct OpenWindow {
+    #[allow(clippy::type_complexity)]
+    action: Rc<dyn Fn(&str, Value)>,
+}
+
+impl OpenWindow {
+    pub fn new(action: impl Fn(&str, Value) + 'static) -> Self {
+        Self { action: Rc::new(action) }
+    }
+
+    /// Opens the window for the scene with the given id, e.g. a single window
+    /// or the settings.
+    pub fn open(&self, id: &str) {
+        (self.action)(id, Value::Null);
+    }
+
+    /// Opens a window presenting the given value in the window group with the
+    /// given id. Windows are identified by their values, i.e. opening a value
+    /// that is already shown brings its window to the front.
+    pub fn open_with(&self, id: &str, value: impl Serialize) {
+        match serde_json::to_value(value) {
+            Ok(value) => (self.action)(id, value),
+            Err(error) => eprintln!("Warning: Could not serialize value for window '{id}': {error}"),
+        }
+    }
+}
+
+/// The environment key for the action opening windows.
+pub struct OpenWindowKey;
+
+impl EnvironmentKey for OpenWindowKey {
+    type Value = OpenWindow;
+
+    fn default_value() -> OpenWindow {
+        OpenWindow::new(|id, _| eprintln!("Warning: Cannot open window '{id}' outside of an app"))
+    }
+}
@@ -0,0 +1,150 @@
+use std::cell::RefCell;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{AnyView, SceneError, Vec2, View};
+
+/// The kind of a scene, determining how its windows are managed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SceneKind {
+    /// A scene with one window per presented value, e.g. per document.
+    WindowGroup,
+    /// A scene with a single window.
+    Window,
+    /// A scene with a single window presenting the app's settings.
+    Settings,
+}
+
+/// The backend-facing description of a scene.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneInfo {
+    id: String,
+    kind: SceneKind,
+    title: Option<String>,
+    default_size: Option<Vec2<f64>>,
+    min_size: Option<Vec2<f64>>,
+}
+
+impl SceneInfo {
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> SceneKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    #[must_use]
+    pub const fn default_size(&self) -> Option<Vec2<f64>> {
+        self.default_size
+    }
+
+    #[must_use]
+    pub const fn min_size(&self) -> Option<Vec2<f64>> {
+        self.min_size
+    }
+}
+
+/// A kind of window the app can show, along with the views presented in it.
+/// Every window gets its own root and therefore its own state.
+pub struct Scene {
+    info: SceneInfo,
+    content: SceneContent,
+}
+
+enum SceneContent {
+    /// A view that is shown in at most one window.
+    Single(RefCell<Option<AnyView>>),
+    /// A function creating a view for every presented value.
+    #[allow(clippy::type_complexity)]
+    Group(Box<dyn Fn(Value) -> serde_json::Result<AnyView>>),
+}
+
+impl Scene {
+    fn new(id: impl Into<String>, kind: SceneKind, content: SceneContent) -> Self {
+        Self {
+            info: SceneInfo {
+                id: id.into(),
+                kind,
+                title: None,
+                default_size: None,
+                min_size: None,
+            },
+            content,
+        }
+    }
+
+    /// Creates a scene presenting a window for every value it is opened with,
+    /// using the given function to create the window's view.
+    pub fn window_group<V, T>(id: impl Into<String>, view_func: impl Fn(V) -> T + 'static) -> Self
+    where
+        V: DeserializeOwned,
+        T: View + 'static,
+    {
+        Self::new(id, SceneKind::WindowGroup, SceneContent::Group(Box::new(move |value| {
+            Ok(AnyView::new(view_func(serde_json::from_value(value)?)))
+        })))
+    }
+
+    /// Creates a scene presenting the given view in a single window.
+    pub fn window(id: impl Into<String>, view: impl View + 'static) -> Self {
+        Self::new(id, SceneKind::Window, SceneContent::Single(RefCell::new(Some(AnyView::new(view)))))
+    }
+
+    /// Creates a scene presenting the given view as the app's settings.
+    pub fn settings(view: impl View + 'static) -> Self {
+        Self::new("settings", SceneKind::Settings, SceneContent::Single(RefCell::new(Some(AnyView::new(view)))))
+    }
+
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.info.title = Some(title.into());
+        self
+    }
+
+    #[must_use]
+    pub fn default_size(mut self, default_size: impl Into<Vec2<f64>>) -> Self {
+        self.info.default_size = Some(default_size.into());
+        self
+    }
+
+    #[must_use]
+    pub fn min_size(mut self, min_size: impl Into<Vec2<f64>>) -> Self {
+        self.info.min_size = Some(min_size.into());
+        self
+    }
+
+    /// The backend-facing description of this scene.
+    #[must_use]
+    pub const fn info(&self) -> &SceneInfo {
+        &self.info
+    }
+
+    /// Creates the view for a new window presenting the given value. Scenes
+    /// with a single window ignore the value and only create their view once,
+    /// backends should therefore keep such windows around once opened.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the single window has already been opened or the value cannot
+    /// be deserialized.
+    pub fn make_view(&self, value: Value) -> Result<AnyView, SceneError> {
+        match &self.content {
+            SceneContent::Single(view) => view.take().ok_or_else(|| SceneError::AlreadyOpen { id: self.info.id.clone() }),
+            SceneContent::Group(view_func) => view_func(value).map_err(|error| SceneError::Deserialization {
+                id: self.info.id.clone(),
+                message: error.to_string(),
+            }),
+        }
+    }
+}
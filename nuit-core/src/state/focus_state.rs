@@ -0,0 +1,145 @@
+use std::rc::Rc;
+
+use crate::{Access, Animation, Binding, State, Storage};
+
+use super::StateKey;
+
+/// The keyboard focus within a view, i.e. which of the views marked with
+/// [`ViewExt::focused`](crate::ViewExt::focused) is focused, if any.
+///
+/// Focus state behaves like a [`State`] holding an `Option<T>`, where `None`
+/// means that none of the marked views is focused. Changing the value moves
+/// the focus, while focus changes made by the user update the value.
+#[derive(Clone)]
+pub struct FocusState<T> {
+    state: State<Option<T>>,
+}
+
+impl<T> FocusState<T> where T: 'static + Clone {
+    /// Creates a new (unlinked) focus state, where no view is focused.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { state: State::new(None) }
+    }
+
+    /// Checks whether an underlying storage has been linked. May be useful for
+    /// debugging.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.state.is_linked()
+    }
+
+    /// Links an underlying storage to this focus state. This is done
+    /// automatically by the derived [`Bind::bind`] implementation, therefore
+    /// this method should usually not be called manually.
+    pub fn link(&self, storage: &Rc<Storage>, key: StateKey) {
+        self.state.link(storage, key);
+    }
+}
+
+impl<T> Access for FocusState<T> where T: 'static + Clone {
+    type Value = Option<T>;
+
+    /// Fetches the currently focused value.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the storage has not been linked first, see
+    /// [`State::get`](State#method.get).
+    fn get(&self) -> Option<T> {
+        self.state.get()
+    }
+
+    /// Moves the focus to the view marked with the given value, or removes
+    /// the focus for `None`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the storage has not been linked first, see
+    /// [`State::change`](State#method.change).
+    fn change(&self, value: impl Into<Option<T>>, animation: Option<Animation>) {
+        self.state.change(value, animation);
+    }
+
+    /// Obtains a [`Binding`] to the focused value.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the storage has not been linked first.
+    fn binding(&self) -> Binding<Option<T>> {
+        self.state.binding()
+    }
+}
+
+impl<T> Default for FocusState<T> where T: 'static + Clone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Event, FocusState, Id, IdPathBuf, ModifierNode, Node, Root, SecureField, State, TextField, View, ViewExt, VStack};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Field {
+        Username,
+        Password,
+    }
+
+    #[derive(Bind, Default)]
+    struct SignUpView {
+        username: State<String>,
+        password: State<String>,
+        focused_field: FocusState<Field>,
+    }
+
+    impl View for SignUpView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let username = self.username.clone();
+            let focused_field = self.focused_field.clone();
+            VStack::from((
+                TextField::new(username.binding())
+                    .focused(focused_field.binding(), Field::Username),
+                SecureField::new(self.password.binding())
+                    .on_submit(clone!(focused_field => move || {
+                        if username.get().is_empty() {
+                            focused_field.set(Field::Username);
+                        }
+                    }))
+                    .focused(focused_field.binding(), Field::Password),
+            ))
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let root = Root::new(SignUpView::default());
+        let is_focused = |i: usize| match root.render().descendant(&IdPathBuf::from(Id::index(0)).child(i)) {
+            Some(Node::Modified { modifier: ModifierNode::Focused { is_focused }, .. }) => *is_focused,
+            node => panic!("Expected focused modifier, got {node:?}"),
+        };
+        // The path to the text field within the focused modifier
+        let field_path = |i: usize| IdPathBuf::from(Id::index(0)).child(i).child(0);
+
+        assert!(!is_focused(0) && !is_focused(1));
+
+        root.fire_event(&field_path(1), &Event::UpdateFocus { is_focused: true }).unwrap();
+        assert!(!is_focused(0) && is_focused(1));
+
+        // Submitting with an empty username moves the focus to the username field
+        root.fire_event(&field_path(1), &Event::Submit {}).unwrap();
+        assert!(is_focused(0) && !is_focused(1));
+
+        // Focus leaving a field that is no longer focused doesn't reset the focus
+        root.fire_event(&field_path(1), &Event::UpdateFocus { is_focused: false }).unwrap();
+        assert!(is_focused(0));
+
+        root.fire_event(&field_path(0), &Event::UpdateFocus { is_focused: false }).unwrap();
+        assert!(!is_focused(0) && !is_focused(1));
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use serde::{Deserialize, Serialize};
 
 use crate::IdPathBuf;
@@ -17,3 +19,16 @@ impl StateKey {
         Self { id_path: id_path.into(), index: index.into() }
     }
 }
+
+/// Identifies a state or memo along with its clones, independently of the key
+/// it is linked to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct StateId(u64);
+
+impl StateId {
+    /// Allocates a new, unique id.
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
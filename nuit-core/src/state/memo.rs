@@ -0,0 +1,254 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{Access, Animation, State, Storage};
+
+use super::{StateId, StateKey};
+
+/// A value derived from other state. The value is computed lazily and cached
+/// until any of the state read during the computation changes.
+///
+/// Only reads of [`State`]s and other memos are tracked. A memo whose
+/// computation depends on anything else (e.g. captured cells or environment
+/// values) is not recomputed when that changes and thus goes stale until one
+/// of its state dependencies changes.
+///
+/// The state to derive from is passed as (clones of) its dependencies, which
+/// are resolved through the storage to the keys the originals are linked to,
+/// e.g.
+///
+/// ```
+/// # use nuit_core::{Access, Memo, State};
+/// let items = State::<Vec<i32>>::new(vec![1, 2, 3]);
+/// let total = Memo::new(items.clone(), |items| items.get().iter().sum::<i32>());
+/// ```
+#[derive(Clone)]
+pub struct Memo<T> {
+    #[allow(clippy::type_complexity)]
+    compute: Rc<dyn Fn(&Rc<Storage>) -> T>,
+    id: StateId,
+    storage: RefCell<Option<Rc<Storage>>>,
+    key: RefCell<Option<StateKey>>,
+}
+
+/// The state a [`Memo`] is derived from, i.e. a [`State`], another [`Memo`] or
+/// a tuple of them.
+pub trait Dependencies {
+    /// Creates clones of the dependencies linked to the keys they were last
+    /// linked to in the given storage.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if any of the dependencies has not been linked to the
+    /// storage.
+    #[must_use]
+    fn resolve(&self, storage: &Rc<Storage>) -> Self;
+}
+
+impl<T> Memo<T> where T: 'static + Clone {
+    /// Creates a new (unlinked) memo computing its value from the given
+    /// dependencies with the given function.
+    pub fn new<D>(dependencies: D, compute: impl Fn(&D) -> T + 'static) -> Self where D: Dependencies + 'static {
+        Self {
+            compute: Rc::new(move |storage| compute(&dependencies.resolve(storage))),
+            id: StateId::next(),
+            storage: RefCell::new(None),
+            key: RefCell::new(None),
+        }
+    }
+
+    /// Checks whether an underlying storage has been linked. May be useful for
+    /// debugging.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.storage.borrow().is_some() && self.key.borrow().is_some()
+    }
+
+    /// Links an underlying storage to this memo. This is done automatically by
+    /// the derived [`Bind::bind`] implementation, therefore this method should
+    /// usually not be called manually.
+    pub fn link(&self, storage: &Rc<Storage>, key: StateKey) {
+        *self.storage.borrow_mut() = Some(storage.clone());
+        *self.key.borrow_mut() = Some(key.clone());
+
+        storage.register_link(self.id, key);
+    }
+}
+
+impl<T> Access for Memo<T> where T: 'static + Clone {
+    type Value = T;
+
+    /// Fetches the (possibly cached) value.
+    /// 
+    /// # Panics
+    /// 
+    /// This will panic if the storage has not been linked first. Linking is
+    /// performed by (the usually derived) [`Bind::bind`] implementation before
+    /// [`View::render`] is called, so calling this method in the render
+    /// implementation is generally safe (and encouraged).
+    fn get(&self) -> T {
+        let storage = self.storage.borrow().clone().expect("Storage not linked before calling Memo::get");
+        let key = self.key.borrow().clone().unwrap();
+        storage.get_memoized(&key, || (self.compute)(&storage))
+    }
+
+    /// Memos are derived from other state and therefore read-only, changes are
+    /// ignored.
+    fn change(&self, _value: impl Into<T>, _animation: Option<Animation>) {
+        eprintln!("Warning: Ignoring change to read-only memo");
+    }
+}
+
+impl<T> Dependencies for State<T> where T: 'static + Clone {
+    fn resolve(&self, storage: &Rc<Storage>) -> Self {
+        self.resolved(storage).expect("Memo dependency not linked before calling Memo::get")
+    }
+}
+
+impl<T> Dependencies for Memo<T> {
+    fn resolve(&self, storage: &Rc<Storage>) -> Self {
+        let key = storage.linked_key(self.id).expect("Memo dependency not linked before calling Memo::get");
+        Self {
+            compute: self.compute.clone(),
+            id: self.id,
+            storage: RefCell::new(Some(storage.clone())),
+            key: RefCell::new(Some(key)),
+        }
+    }
+}
+
+macro_rules! impl_tuple_dependencies {
+    ($($tvs:ident),*) => {
+        impl<$($tvs),*> Dependencies for ($($tvs,)*) where $($tvs: Dependencies),* {
+            #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+            fn resolve(&self, storage: &Rc<Storage>) -> Self {
+                let ($($tvs,)*) = self;
+                ($($tvs.resolve(storage),)*)
+            }
+        }
+    };
+}
+
+impl_tuple_dependencies!();
+impl_tuple_dependencies!(T1);
+impl_tuple_dependencies!(T1, T2);
+impl_tuple_dependencies!(T1, T2, T3);
+impl_tuple_dependencies!(T1, T2, T3, T4);
+impl_tuple_dependencies!(T1, T2, T3, T4, T5);
+impl_tuple_dependencies!(T1, T2, T3, T4, T5, T6);
+impl_tuple_dependencies!(T1, T2, T3, T4, T5, T6, T7);
+impl_tuple_dependencies!(T1, T2, T3, T4, T5, T6, T7, T8);
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use nuit_derive::Bind;
+
+    use crate::{clone, Access, Button, Event, IdPathBuf, Memo, Node, Root, State, StateKey, Storage, Text, View, VStack};
+
+    #[test]
+    fn untracked_inputs() {
+        let storage = Rc::new(Storage::new());
+        let factor = Rc::new(Cell::new(1));
+        let items = State::<Vec<i32>>::new(vec![1, 2]);
+        let sum = Memo::new(items.clone(), clone!(factor => move |items| factor.get() * items.get().iter().sum::<i32>()));
+        items.link(&storage, StateKey::new(IdPathBuf::root(), 0usize));
+        sum.link(&storage, StateKey::new(IdPathBuf::root(), 1usize));
+        assert_eq!(sum.get(), 3);
+
+        // Changes to inputs that aren't state don't invalidate the memo...
+        factor.set(2);
+        assert_eq!(sum.get(), 3);
+
+        // ...but changes to its state dependencies do
+        items.set(vec![1, 2, 3]);
+        storage.apply_changes();
+        assert_eq!(sum.get(), 12);
+    }
+
+    #[test]
+    fn memo_dependencies() {
+        let storage = Rc::new(Storage::new());
+        let items = State::<Vec<i32>>::new(vec![1, 2]);
+        let factor = State::<i32>::new(2);
+        let sum = Memo::new(items.clone(), |items| items.get().iter().sum::<i32>());
+        let product = Memo::new((sum.clone(), factor.clone()), |(sum, factor)| sum.get() * factor.get());
+        items.link(&storage, StateKey::new(IdPathBuf::root(), 0usize));
+        factor.link(&storage, StateKey::new(IdPathBuf::root(), 1usize));
+        sum.link(&storage, StateKey::new(IdPathBuf::root(), 2usize));
+        product.link(&storage, StateKey::new(IdPathBuf::root(), 3usize));
+        assert_eq!(product.get(), 6);
+
+        items.set(vec![1, 2, 3]);
+        storage.apply_changes();
+        assert_eq!(product.get(), 12);
+
+        factor.set(3);
+        storage.apply_changes();
+        assert_eq!(product.get(), 18);
+    }
+
+    #[derive(Bind)]
+    struct SumView {
+        items: State<Vec<i32>>,
+        other: State<i32>,
+        sum: Memo<i32>,
+    }
+
+    impl SumView {
+        fn new(computations: Rc<Cell<usize>>) -> Self {
+            let items = State::<Vec<i32>>::new(vec![1, 2]);
+            Self {
+                sum: Memo::new(items.clone(), move |items| {
+                    computations.set(computations.get() + 1);
+                    items.get().iter().sum()
+                }),
+                items,
+                other: State::new(0),
+            }
+        }
+    }
+
+    impl View for SumView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let items = self.items.clone();
+            let other = self.other.clone();
+            VStack::from((
+                Text::new(format!("Sum: {}", self.sum.get())),
+                Button::with_text("Add", move || {
+                    let mut value = items.get();
+                    value.push(3);
+                    items.set(value);
+                }),
+                Button::with_text("Other", move || other.set(other.get() + 1)),
+            ))
+        }
+    }
+
+    #[test]
+    fn view() {
+        let computations = Rc::new(Cell::new(0));
+        let root = Root::new(SumView::new(computations.clone()));
+        let node = root.render();
+        assert!(node.descendants().iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Sum: 3")));
+        assert_eq!(computations.get(), 1);
+
+        let buttons: Vec<IdPathBuf> = node.descendants()
+            .into_iter()
+            .filter(|(_, node)| matches!(node, Node::Button { .. }))
+            .map(|(id_path, _)| id_path)
+            .collect();
+        let (add, other) = (&buttons[0], &buttons[1]);
+
+        root.fire_event(other, &Event::ButtonTap {}).unwrap();
+        root.render();
+        assert_eq!(computations.get(), 1);
+
+        root.fire_event(add, &Event::ButtonTap {}).unwrap();
+        let node = root.render();
+        assert!(node.descendants().iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Sum: 6")));
+        assert_eq!(computations.get(), 2);
+    }
+}
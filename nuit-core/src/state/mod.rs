@@ -1,7 +1,13 @@
+mod focus_state;
 mod key;
+mod memo;
+mod persistence;
 mod state;
 mod storage;
 
+pub use focus_state::*;
 pub use key::*;
+pub use memo::*;
+pub use persistence::*;
 pub use state::*;
 pub use storage::*;
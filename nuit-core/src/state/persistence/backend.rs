@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use serde_json::Value;
+
+/// A facility that stores persisted state across app launches.
+///
+/// Values are identified by the keys passed to [`State::persisted`](crate::State::persisted)
+/// and represented as JSON.
+pub trait PersistenceBackend {
+    /// Loads the value stored under the given key, if any.
+    fn load(&self, key: &str) -> Option<Value>;
+
+    /// Stores the given value under the given key.
+    fn save(&self, key: &str, value: Value);
+
+    /// Stores the values that have been saved but not written yet, if the
+    /// backend defers writes. Called by the backends when the app terminates.
+    fn flush(&self) {}
+}
+
+impl<B> PersistenceBackend for Box<B> where B: PersistenceBackend + ?Sized {
+    fn load(&self, key: &str) -> Option<Value> {
+        (**self).load(key)
+    }
+
+    fn save(&self, key: &str, value: Value) {
+        (**self).save(key, value);
+    }
+
+    fn flush(&self) {
+        (**self).flush();
+    }
+}
+
+impl<B> PersistenceBackend for Rc<B> where B: PersistenceBackend + ?Sized {
+    fn load(&self, key: &str) -> Option<Value> {
+        (**self).load(key)
+    }
+
+    fn save(&self, key: &str, value: Value) {
+        (**self).save(key, value);
+    }
+
+    fn flush(&self) {
+        (**self).flush();
+    }
+}
@@ -0,0 +1,250 @@
+use std::{env, ffi::OsString, fs, path::{Path, PathBuf}, sync::{Arc, Mutex, MutexGuard, PoisonError}, thread, time::{Duration, Instant}};
+
+use serde_json::{Map, Value};
+
+use super::PersistenceBackend;
+
+/// The minimum time between two writes of the file.
+const WRITE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A persistence backend that stores all values in a single JSON file.
+///
+/// To avoid rewriting the file on every keystroke in a bound text field,
+/// the file is written at most once per second. Changes made in the meantime
+/// are written by a background thread once the second has passed, or earlier
+/// by [`PersistenceBackend::flush`] (which backends call when the app
+/// terminates) or when the backend is dropped.
+pub struct JsonFileBackend {
+    file: Arc<JsonFile>,
+}
+
+/// The JSON file, shared with the threads performing deferred writes.
+struct JsonFile {
+    path: PathBuf,
+    state: Mutex<JsonFileState>,
+}
+
+struct JsonFileState {
+    values: Map<String, Value>,
+    last_write: Option<Instant>,
+    has_unsaved_changes: bool,
+    /// Whether a thread is waiting to write the unsaved changes.
+    is_write_scheduled: bool,
+}
+
+impl JsonFileBackend {
+    /// Creates a backend storing its values in the file at the given path,
+    /// reading the values already stored there (if any).
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let values = fs::read_to_string(&path).ok()
+            .and_then(|contents| match serde_json::from_str(&contents) {
+                Ok(values) => Some(values),
+                Err(error) => {
+                    eprintln!("Warning: Ignoring invalid persisted state at {}: {error}", path.display());
+                    None
+                },
+            })
+            .unwrap_or_default();
+        let state = JsonFileState { values, last_write: None, has_unsaved_changes: false, is_write_scheduled: false };
+        Self { file: Arc::new(JsonFile { path, state: Mutex::new(state) }) }
+    }
+
+    /// Creates a backend storing its values in `<app_id>/state.json` under the
+    /// XDG config directory, i.e. `$XDG_CONFIG_HOME` or `~/.config`. Returns
+    /// `None` if neither can be determined.
+    pub fn for_app(app_id: &str) -> Option<Self> {
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(Self::new(config_dir.join(app_id).join("state.json")))
+    }
+
+    /// The path of the JSON file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.file.path
+    }
+}
+
+impl JsonFile {
+    fn lock(&self) -> MutexGuard<'_, JsonFileState> {
+        // The state stays consistent even if a write panicked
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Writes the changes that haven't been written yet to the file.
+    fn flush(&self, state: &mut JsonFileState) {
+        if !state.has_unsaved_changes {
+            return;
+        }
+        state.has_unsaved_changes = false;
+        state.last_write = Some(Instant::now());
+        if let Err(error) = self.write(&state.values) {
+            eprintln!("Warning: Could not persist state to {}: {error}", self.path.display());
+        }
+    }
+
+    /// Writes all values to the file. The values are written to a temporary
+    /// file first, which then replaces the file, so a crash during the write
+    /// cannot leave a truncated file behind.
+    fn write(&self, values: &Map<String, Value>) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let contents = serde_json::to_string_pretty(values).map_err(|e| e.to_string())?;
+        let mut temp_path = OsString::from(&self.path);
+        temp_path.push(".tmp");
+        fs::write(&temp_path, contents).map_err(|e| e.to_string())?;
+        fs::rename(&temp_path, &self.path).map_err(|e| e.to_string())
+    }
+}
+
+impl PersistenceBackend for JsonFileBackend {
+    fn load(&self, key: &str) -> Option<Value> {
+        self.file.lock().values.get(key).cloned()
+    }
+
+    fn save(&self, key: &str, value: Value) {
+        let mut state = self.file.lock();
+        state.values.insert(key.to_owned(), value);
+        state.has_unsaved_changes = true;
+
+        let delay = state.last_write.map_or(Duration::ZERO, |last_write| WRITE_INTERVAL.saturating_sub(last_write.elapsed()));
+        if delay.is_zero() {
+            self.file.flush(&mut state);
+        } else if !state.is_write_scheduled {
+            // Write the deferred changes once the interval has passed, so
+            // they aren't lost if nothing else is saved until the app exits
+            state.is_write_scheduled = true;
+            let file = self.file.clone();
+            thread::spawn(move || {
+                thread::sleep(delay);
+                let mut state = file.lock();
+                state.is_write_scheduled = false;
+                file.flush(&mut state);
+            });
+        }
+    }
+
+    fn flush(&self) {
+        self.file.flush(&mut self.file.lock());
+    }
+}
+
+impl Drop for JsonFileBackend {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, thread, time::{Duration, Instant}};
+
+    use nuit_derive::Bind;
+    use serde_json::json;
+
+    use crate::{Access, Event, JsonFileBackend, Node, PersistenceBackend, Root, State, Text, TextField, View, VStack};
+
+    use super::WRITE_INTERVAL;
+
+    #[test]
+    fn throttled_writes() {
+        let dir = env::temp_dir().join(format!("nuit-core-{}-throttled-writes", std::process::id()));
+        let path = dir.join("state.json");
+        let read = || serde_json::from_str::<serde_json::Value>(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        let backend = JsonFileBackend::new(&path);
+        backend.save("query", json!("N"));
+        assert_eq!(read(), json!({ "query": "N" }));
+
+        // Changes right after a write are deferred...
+        backend.save("query", json!("Nuit"));
+        assert_eq!(read(), json!({ "query": "N" }));
+
+        // ...until the backend is flushed...
+        backend.flush();
+        assert_eq!(read(), json!({ "query": "Nuit" }));
+
+        // ...or dropped
+        backend.save("query", json!("Nuit UI"));
+        assert_eq!(read(), json!({ "query": "Nuit" }));
+        drop(backend);
+        assert_eq!(read(), json!({ "query": "Nuit UI" }));
+        assert_eq!(JsonFileBackend::new(&path).load("query"), Some(json!("Nuit UI")));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn trailing_write() {
+        let dir = env::temp_dir().join(format!("nuit-core-{}-trailing-write", std::process::id()));
+        let path = dir.join("state.json");
+        let read = || serde_json::from_str::<serde_json::Value>(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        let backend = JsonFileBackend::new(&path);
+        backend.save("query", json!("N"));
+        backend.save("query", json!("Nu"));
+        backend.save("query", json!("Nuit"));
+        assert_eq!(read(), json!({ "query": "N" }));
+
+        // The last change is written once the interval has passed, even though
+        // nothing is saved afterwards and the backend is still alive
+        let deadline = Instant::now() + WRITE_INTERVAL * 5;
+        while read() != json!({ "query": "Nuit" }) {
+            assert!(Instant::now() < deadline, "The deferred change was not written");
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        drop(backend);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[derive(Bind)]
+    struct QueryView {
+        query: State<String>,
+    }
+
+    impl View for QueryView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            VStack::from((
+                TextField::new(self.query.binding()),
+                Text::new(format!("Query: {}", self.query.get())),
+            ))
+        }
+    }
+
+    fn launch(path: &Path) -> Root<QueryView> {
+        let root = Root::new(QueryView { query: State::persisted("query", "") });
+        root.set_persistence_backend(JsonFileBackend::new(path));
+        root
+    }
+
+    #[test]
+    fn persisted_state() {
+        let dir = env::temp_dir().join(format!("nuit-core-{}-persisted-state", std::process::id()));
+        let path = dir.join("state.json");
+
+        let root = launch(&path);
+        let node = root.render();
+        assert!(node.descendants().iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Query: ")));
+        let (text_field_path, _) = node.descendants()
+            .into_iter()
+            .find(|(_, node)| matches!(node, Node::TextField { .. }))
+            .unwrap();
+        root.fire_event(&text_field_path, &Event::UpdateText { content: "Nuit".to_owned() }).unwrap();
+        root.render();
+
+        let relaunched_root = launch(&path);
+        let node = relaunched_root.render();
+        assert!(node.descendants().iter().any(|(_, node)| matches!(node, Node::Text { content } if content == "Query: Nuit")));
+
+        drop((root, relaunched_root));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
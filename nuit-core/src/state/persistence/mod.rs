@@ -0,0 +1,5 @@
+mod backend;
+mod json_file_backend;
+
+pub use backend::*;
+pub use json_file_backend::*;
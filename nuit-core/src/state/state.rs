@@ -1,15 +1,30 @@
 use std::{cell::RefCell, rc::Rc};
 
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
 use crate::{Access, Animation, Binding, Storage};
 
-use super::StateKey;
+use super::{StateId, StateKey};
 
 /// A wrapper around a value managed by Nuit.
 #[derive(Clone)]
 pub struct State<T> {
     initial_value: T,
+    /// Identifies the state and its clones, so [`Memo`](super::Memo)s can
+    /// resolve the key of a captured clone through the storage.
+    id: StateId,
     storage: RefCell<Option<Rc<Storage>>>,
     key: RefCell<Option<StateKey>>,
+    persistence: Option<Persistence<T>>,
+}
+
+/// How a persisted state's value is stored in the persistence backend.
+#[derive(Clone)]
+struct Persistence<T> {
+    key: String,
+    serialize: fn(&T) -> serde_json::Result<Value>,
+    deserialize: fn(Value) -> serde_json::Result<T>,
 }
 
 impl<T> State<T> where T: 'static + Clone {
@@ -17,8 +32,10 @@ impl<T> State<T> where T: 'static + Clone {
     pub fn new(initial_value: impl Into<T>) -> Self {
         Self {
             initial_value: initial_value.into(),
+            id: StateId::next(),
             storage: RefCell::new(None),
             key: RefCell::new(None),
+            persistence: None,
         }
     }
 
@@ -35,7 +52,48 @@ impl<T> State<T> where T: 'static + Clone {
         *self.storage.borrow_mut() = Some(storage.clone());
         *self.key.borrow_mut() = Some(key.clone());
 
-        storage.initialize_if_needed(key, || self.initial_value.clone());
+        storage.register_link(self.id, key.clone());
+        storage.initialize_if_needed(key, || self.restore(storage).unwrap_or_else(|| self.initial_value.clone()));
+    }
+
+    /// Creates a clone linked to the key this state (or a clone of it) was
+    /// last linked to in the given storage.
+    pub(crate) fn resolved(&self, storage: &Rc<Storage>) -> Option<Self> {
+        let key = storage.linked_key(self.id)?;
+        let resolved = self.clone();
+        *resolved.storage.borrow_mut() = Some(storage.clone());
+        *resolved.key.borrow_mut() = Some(key);
+        Some(resolved)
+    }
+
+    /// Fetches the value stored in the storage's persistence backend, if this
+    /// state is persisted.
+    fn restore(&self, storage: &Storage) -> Option<T> {
+        let persistence = self.persistence.as_ref()?;
+        let value = storage.load_persisted(&persistence.key)?;
+        (persistence.deserialize)(value)
+            .map_err(|error| eprintln!("Warning: Ignoring invalid persisted state for '{}': {error}", persistence.key))
+            .ok()
+    }
+}
+
+impl<T> State<T> where T: 'static + Clone + Serialize + DeserializeOwned {
+    /// Creates a new (unlinked) state that is persisted across app launches
+    /// under the given key. When linked, the state is restored from the
+    /// storage's [`PersistenceBackend`](super::PersistenceBackend), falling
+    /// back to the initial value, and every change is saved to it.
+    ///
+    /// Keys should be unique within the app, since states with the same key
+    /// share the persisted value.
+    pub fn persisted(key: impl Into<String>, initial_value: impl Into<T>) -> Self {
+        Self {
+            persistence: Some(Persistence {
+                key: key.into(),
+                serialize: |value| serde_json::to_value(value),
+                deserialize: serde_json::from_value,
+            }),
+            ..Self::new(initial_value)
+        }
     }
 }
 
@@ -67,7 +125,14 @@ impl<T> Access for State<T> where T: 'static + Clone {
     fn change(&self, value: impl Into<T>, animation: Option<Animation>) {
         let storage = self.storage.borrow();
         let storage = storage.as_ref().expect("Storage not linked before calling State::change");
-        storage.add_change(self.key.borrow().clone().unwrap(), value.into(), animation);
+        let value = value.into();
+        if let Some(persistence) = &self.persistence {
+            match (persistence.serialize)(&value) {
+                Ok(json) => storage.save_persisted(&persistence.key, json),
+                Err(error) => eprintln!("Warning: Could not persist state for '{}': {error}", persistence.key),
+            }
+        }
+        storage.add_change(self.key.borrow().clone().unwrap(), value, animation);
     }
 
     /// Obtains a [`Binding`] to the underlying value.
@@ -89,3 +154,46 @@ impl<T> Default for State<T> where T: 'static + Default + Clone {
         Self::new(T::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{Access, IdPathBuf, State, StateKey, Storage};
+
+    #[test]
+    fn independent_clones() {
+        let storage = Rc::new(Storage::new());
+        let state = State::<i32>::new(1);
+        let clone = state.clone();
+
+        state.link(&storage, StateKey::new(IdPathBuf::root(), 0usize));
+        assert!(!clone.is_linked());
+
+        // Linking a clone to another key leaves the original linked to its own
+        clone.link(&storage, StateKey::new(IdPathBuf::root(), 1usize));
+        clone.set(2);
+        storage.apply_changes();
+        assert_eq!(state.get(), 1);
+        assert_eq!(clone.get(), 2);
+
+        // Clones of linked states are linked to the same key
+        assert_eq!(state.clone().get(), 1);
+    }
+
+    #[test]
+    fn resolved() {
+        let storage = Rc::new(Storage::new());
+        let state = State::<i32>::new(1);
+        let clone = state.clone();
+        assert!(clone.resolved(&storage).is_none());
+
+        state.link(&storage, StateKey::new(IdPathBuf::root(), 0usize));
+        assert_eq!(clone.resolved(&storage).unwrap().get(), 1);
+
+        // States replacing the original under its key (e.g. after rerendering)
+        // take over the key
+        State::<i32>::new(2).link(&storage, StateKey::new(IdPathBuf::root(), 0usize));
+        assert!(clone.resolved(&storage).is_none());
+    }
+}
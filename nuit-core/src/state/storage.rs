@@ -1,14 +1,42 @@
-use std::{collections::HashMap, any::Any, cell::{RefCell, Cell}};
+use std::{collections::{HashMap, HashSet}, any::Any, cell::{RefCell, Cell}, future::Future, mem, pin::Pin, rc::Rc};
 
-use crate::{Animation, StateKey, Update};
+use serde_json::Value;
+
+use crate::{Animation, Executor, IdPath, IdPathBuf, PersistenceBackend, StateId, StateKey, TaskHandle, Update};
+
+/// A memoized value along with the state keys it was computed from.
+struct MemoEntry {
+    value: Box<dyn Any>,
+    dependencies: HashSet<StateKey>,
+    /// Whether the value was computed from changes that are not applied yet.
+    is_preapplied: bool,
+}
 
 /// A facility that manages view state internally.
 pub struct Storage {
     state: RefCell<HashMap<StateKey, Box<dyn Any>>>,
     changes: RefCell<HashMap<StateKey, Box<dyn Any>>>,
+    memos: RefCell<HashMap<StateKey, MemoEntry>>,
+    /// The keys read by the memos currently being computed (innermost last).
+    tracked_reads: RefCell<Vec<HashSet<StateKey>>>,
+    /// The keys that states and memos were last linked to, used to resolve the
+    /// dependencies of memos.
+    linked_keys: RefCell<HashMap<StateId, StateKey>>,
+    /// The inverse of `linked_keys`, used to drop the ids of states that were
+    /// replaced by newer ones (e.g. after rerendering their views).
+    linked_ids: RefCell<HashMap<StateKey, StateId>>,
     preapply: Cell<bool>,
     #[allow(clippy::type_complexity)]
     update_callback: RefCell<Option<Box<dyn Fn(&Update)>>>,
+    persistence_backend: RefCell<Option<Rc<dyn PersistenceBackend>>>,
+    executor: RefCell<Option<Rc<dyn Executor>>>,
+    /// The tasks started by views that are still shown (including finished
+    /// ones), keyed by the id paths of the views.
+    tasks: RefCell<HashMap<IdPathBuf, Vec<TaskHandle>>>,
+    /// The tasks registered by rendered views, which are started once the
+    /// views are known to be shown.
+    #[allow(clippy::type_complexity)]
+    pending_tasks: RefCell<Vec<(IdPathBuf, Pin<Box<dyn Future<Output = ()>>>)>>,
 }
 
 impl Storage {
@@ -17,8 +45,16 @@ impl Storage {
         Self {
             state: RefCell::new(HashMap::new()),
             changes: RefCell::new(HashMap::new()),
+            memos: RefCell::new(HashMap::new()),
+            tracked_reads: RefCell::new(Vec::new()),
+            linked_keys: RefCell::new(HashMap::new()),
+            linked_ids: RefCell::new(HashMap::new()),
             preapply: Cell::new(false),
             update_callback: RefCell::new(None),
+            persistence_backend: RefCell::new(None),
+            executor: RefCell::new(None),
+            tasks: RefCell::new(HashMap::new()),
+            pending_tasks: RefCell::new(Vec::new()),
         }
     }
 
@@ -28,12 +64,27 @@ impl Storage {
         }
     }
 
+    pub(crate) fn register_link(&self, id: StateId, key: StateKey) {
+        let mut linked_keys = self.linked_keys.borrow_mut();
+        if let Some(previous) = self.linked_ids.borrow_mut().insert(key.clone(), id)
+            && previous != id
+            && linked_keys.get(&previous) == Some(&key) {
+            linked_keys.remove(&previous);
+        }
+        linked_keys.insert(id, key);
+    }
+
+    pub(crate) fn linked_key(&self, id: StateId) -> Option<StateKey> {
+        self.linked_keys.borrow().get(&id).cloned()
+    }
+
     pub(crate) fn add_change<V>(&self, key: StateKey, value: V, animation: Option<Animation>) where V: 'static {
         self.changes.borrow_mut().insert(key, Box::new(value));
         self.fire_update_callback(&Update::new(animation));
     }
 
     pub(crate) fn get<T>(&self, key: &StateKey) -> T where T: Clone + 'static {
+        self.track_read(key);
         if self.preapply.get() && let Some(changed) = self.changes.borrow().get(key) {
             changed.downcast_ref::<T>().cloned()
         } else {
@@ -42,6 +93,44 @@ impl Storage {
         .expect("State has invalid type")
     }
 
+    /// Fetches the memoized value for the given key, (re)computing it if any
+    /// of the state it was computed from has changed.
+    pub(crate) fn get_memoized<T>(&self, key: &StateKey, compute: impl FnOnce() -> T) -> T where T: Clone + 'static {
+        self.track_read(key);
+
+        let cached = self.memos.borrow().get(key)
+            .filter(|memo| !self.preapply.get() || memo.is_preapplied || !self.has_changes_affecting(memo))
+            .and_then(|memo| memo.value.downcast_ref::<T>().cloned());
+        if let Some(value) = cached {
+            return value;
+        }
+
+        self.tracked_reads.borrow_mut().push(HashSet::new());
+        let value = compute();
+        let dependencies = self.tracked_reads.borrow_mut().pop().unwrap_or_default();
+
+        let memo = MemoEntry {
+            value: Box::new(value.clone()),
+            dependencies,
+            is_preapplied: false,
+        };
+        let is_preapplied = self.preapply.get() && self.has_changes_affecting(&memo);
+        self.memos.borrow_mut().insert(key.clone(), MemoEntry { is_preapplied, ..memo });
+        self.invalidate_memos(HashSet::from([key.clone()]));
+        value
+    }
+
+    fn track_read(&self, key: &StateKey) {
+        if let Some(reads) = self.tracked_reads.borrow_mut().last_mut() {
+            reads.insert(key.clone());
+        }
+    }
+
+    fn has_changes_affecting(&self, memo: &MemoEntry) -> bool {
+        let changes = self.changes.borrow();
+        memo.dependencies.iter().any(|key| changes.contains_key(key))
+    }
+
     pub(crate) fn with_preapplied_changes<T>(&self, action: impl FnOnce() -> T) -> T {
         self.preapply.set(true);
         let result = action();
@@ -51,8 +140,36 @@ impl Storage {
 
     pub(crate) fn apply_changes(&self) {
         let mut state = self.state.borrow_mut();
+        let mut changed = HashSet::new();
         for (key, value) in self.changes.borrow_mut().drain() {
-            state.insert(key, value);
+            state.insert(key.clone(), value);
+            changed.insert(key);
+        }
+        self.invalidate_memos(changed);
+
+        for memo in self.memos.borrow_mut().values_mut() {
+            memo.is_preapplied = false;
+        }
+    }
+
+    /// Removes the memoized values depending on the given keys, including
+    /// those that depend on them indirectly (through other memos). Values
+    /// computed from not yet applied changes are kept, since they are
+    /// already up to date.
+    fn invalidate_memos(&self, mut changed: HashSet<StateKey>) {
+        let mut memos = self.memos.borrow_mut();
+        loop {
+            let invalidated: Vec<StateKey> = memos.iter()
+                .filter(|(_, memo)| !memo.is_preapplied && !memo.dependencies.is_disjoint(&changed))
+                .map(|(key, _)| key.clone())
+                .collect();
+            if invalidated.is_empty() {
+                break;
+            }
+            for key in invalidated {
+                memos.remove(&key);
+                changed.insert(key);
+            }
         }
     }
 
@@ -65,6 +182,84 @@ impl Storage {
     pub fn set_update_callback(&self, update_callback: impl Fn(&Update) + 'static) {
         *self.update_callback.borrow_mut() = Some(Box::new(update_callback));
     }
+
+    /// Sets the backend that persisted state is restored from and saved to.
+    /// Without a backend, persisted state behaves like regular state. Since
+    /// values are restored when state is linked, this should be set before
+    /// the first render.
+    pub fn set_persistence_backend(&self, persistence_backend: impl PersistenceBackend + 'static) {
+        *self.persistence_backend.borrow_mut() = Some(Rc::new(persistence_backend));
+    }
+
+    pub(crate) fn load_persisted(&self, key: &str) -> Option<Value> {
+        self.persistence_backend.borrow().as_ref()?.load(key)
+    }
+
+    pub(crate) fn save_persisted(&self, key: &str, value: Value) {
+        if let Some(persistence_backend) = self.persistence_backend.borrow().as_ref() {
+            persistence_backend.save(key, value);
+        }
+    }
+
+    /// Sets the executor that runs the tasks started by views.
+    pub fn set_executor(&self, executor: impl Executor + 'static) {
+        *self.executor.borrow_mut() = Some(Rc::new(executor));
+    }
+
+    /// Registers the task of the view at the given id path, which is started
+    /// by [`Storage::start_pending_tasks`] unless the view has already started
+    /// one.
+    pub(crate) fn register_task(&self, id_path: &IdPath, future: impl Future<Output = ()> + 'static) {
+        self.pending_tasks.borrow_mut().push((id_path.to_owned(), Box::pin(future)));
+    }
+
+    /// Starts the registered tasks of the views at or below the given id path
+    /// that haven't started one yet.
+    pub(crate) fn start_pending_tasks(&self, id_path: &IdPath) {
+        let (pending, remaining) = mem::take(&mut *self.pending_tasks.borrow_mut())
+            .into_iter()
+            .partition::<Vec<_>, _>(|(path, _)| path.starts_with(id_path));
+        self.pending_tasks.borrow_mut().extend(remaining);
+        for (path, future) in pending {
+            if !self.tasks.borrow().contains_key(&path) {
+                self.spawn_task(&path, future);
+            }
+        }
+    }
+
+    /// Discards the registered tasks that haven't been started, e.g. since
+    /// their views were rendered through events and never shown.
+    pub(crate) fn discard_pending_tasks(&self) {
+        let discarded = mem::take(&mut *self.pending_tasks.borrow_mut());
+        drop(discarded);
+    }
+
+    /// Starts a task on behalf of the view at the given id path.
+    fn spawn_task(&self, id_path: &IdPath, future: impl Future<Output = ()> + 'static) {
+        let Some(executor) = self.executor.borrow().clone() else {
+            eprintln!("Warning: Ignoring task since no executor is set");
+            return;
+        };
+        let (handle, future) = TaskHandle::new(future);
+        // Finished tasks are kept until their views disappear, since they
+        // would otherwise be restarted with the next render
+        self.tasks.borrow_mut().entry(id_path.to_owned()).or_default().push(handle);
+        executor.spawn(Box::pin(future));
+    }
+
+    /// Cancels the tasks started by the view at the given id path or by any of
+    /// its descendants.
+    pub(crate) fn cancel_tasks(&self, id_path: &IdPath) {
+        let cancelled: Vec<TaskHandle> = {
+            let mut tasks = self.tasks.borrow_mut();
+            let paths: Vec<IdPathBuf> = tasks.keys().filter(|path| path.starts_with(id_path)).cloned().collect();
+            paths.into_iter().flat_map(|path| tasks.remove(&path).unwrap_or_default()).collect()
+        };
+        // Cancelling drops the futures, which may access the storage again
+        for handle in cancelled {
+            handle.cancel();
+        }
+    }
 }
 
 impl Default for Storage {
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The level of a heading, which assistive technologies use to navigate
+/// between sections.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessibilityHeadingLevel {
+    #[default]
+    Unspecified,
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+}
+
+impl AccessibilityHeadingLevel {
+    /// The numeric level, if specified.
+    #[must_use]
+    pub const fn number(self) -> Option<u8> {
+        match self {
+            Self::Unspecified => None,
+            Self::H1 => Some(1),
+            Self::H2 => Some(2),
+            Self::H3 => Some(3),
+            Self::H4 => Some(4),
+            Self::H5 => Some(5),
+            Self::H6 => Some(6),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod heading_level;
+mod role;
+
+pub use heading_level::*;
+pub use role::*;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of element that assistive technologies present a view as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessibilityRole {
+    Button,
+    Link,
+    Heading,
+    Image,
+    StaticText,
+    TextField,
+    SearchField,
+    Toggle,
+    Slider,
+    Stepper,
+    ProgressIndicator,
+    Picker,
+    List,
+    Group,
+}
@@ -1,14 +1,37 @@
-use std::{fmt, time::Duration};
+use std::{f64::consts::PI, fmt, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
-/// An animation to use for smooth view transitions.
+use crate::Vec2;
+
+/// An animation to use for smooth view transitions, i.e. a curve or a spring
+/// that may be repeated and delayed.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "AnimationRepr", try_from = "AnimationRepr")]
+pub struct Animation {
+    kind: AnimationKind,
+    repetition: Option<Repetition>,
+    delay_seconds: f64,
+}
+
+/// The curve or spring of an animation.
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
-pub enum Animation {
+pub enum AnimationKind {
     Default {},
     Curve { curve: AnimationCurve, duration_seconds: Option<f64> },
-    // TODO: Springs
+    TimingCurve { control_point1: Vec2<f64>, control_point2: Vec2<f64>, duration_seconds: Option<f64> },
+    Spring { response: f64, damping_fraction: f64 },
+    InterpolatingSpring { mass: f64, stiffness: f64, damping: f64, initial_velocity: f64 },
+}
+
+/// How often an animation is repeated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Repetition {
+    /// The number of repetitions, or `None` to repeat indefinitely.
+    pub count: Option<u32>,
+    /// Whether every other repetition is played backwards.
+    pub autoreverses: bool,
 }
 
 /// The curve of a simple animation.
@@ -21,16 +44,33 @@ pub enum AnimationCurve {
     EaseInOut,
 }
 
+/// The duration of curve animations without an explicit duration, in seconds.
+pub const DEFAULT_ANIMATION_DURATION_SECONDS: f64 = 0.35;
+
+/// The displacement below which a spring is considered settled.
+const SPRING_SETTLING_THRESHOLD: f64 = 0.001;
+
+/// The tolerance within which a spring's damping ratio is considered critical.
+const CRITICAL_DAMPING_TOLERANCE: f64 = 1e-6;
+
 impl Animation {
-    pub const LINEAR: Self = Self::Curve { curve: AnimationCurve::Linear, duration_seconds: None };
-    pub const EASE_IN: Self = Self::Curve { curve: AnimationCurve::EaseIn, duration_seconds: None };
-    pub const EASE_OUT: Self = Self::Curve { curve: AnimationCurve::EaseOut, duration_seconds: None };
-    pub const EASE_IN_OUT: Self = Self::Curve { curve: AnimationCurve::EaseInOut, duration_seconds: None };
+    pub const LINEAR: Self = Self::new(AnimationKind::Curve { curve: AnimationCurve::Linear, duration_seconds: None });
+    pub const EASE_IN: Self = Self::new(AnimationKind::Curve { curve: AnimationCurve::EaseIn, duration_seconds: None });
+    pub const EASE_OUT: Self = Self::new(AnimationKind::Curve { curve: AnimationCurve::EaseOut, duration_seconds: None });
+    pub const EASE_IN_OUT: Self = Self::new(AnimationKind::Curve { curve: AnimationCurve::EaseInOut, duration_seconds: None });
+    pub const SPRING: Self = Self::new(AnimationKind::Spring { response: 0.55, damping_fraction: 0.825 });
+
+    /// Creates an animation of the given kind that is played once without
+    /// delay.
+    #[must_use]
+    pub const fn new(kind: AnimationKind) -> Self {
+        Self { kind, repetition: None, delay_seconds: 0.0 }
+    }
 
     /// Creates an animation with the given curve and the given duration (or default if none).
     #[must_use]
     pub fn curve(curve: AnimationCurve, duration: Option<Duration>) -> Self {
-        Self::Curve { curve, duration_seconds: duration.map(|d| d.as_secs_f64()) }
+        Self::new(AnimationKind::Curve { curve, duration_seconds: duration.map(|d| d.as_secs_f64()) })
     }
 
     /// Creates an animation with a linear curve and the given duration (or default if none).
@@ -56,15 +96,362 @@ impl Animation {
     pub fn ease_in_out(duration: Duration) -> Self {
         Self::curve(AnimationCurve::EaseInOut, Some(duration))
     }
+
+    /// Creates an animation with a cubic bézier timing curve, specified by its
+    /// two inner control points (the outer ones being (0, 0) and (1, 1)), and
+    /// the given duration.
+    #[must_use]
+    pub const fn timing_curve(control_point1: Vec2<f64>, control_point2: Vec2<f64>, duration: Duration) -> Self {
+        Self::new(AnimationKind::TimingCurve { control_point1, control_point2, duration_seconds: Some(duration.as_secs_f64()) })
+    }
+
+    /// Creates a spring animation with the given response (i.e. the duration
+    /// of a single oscillation in seconds) and damping fraction (with 1 being
+    /// critically damped and values below that overshooting).
+    #[must_use]
+    pub const fn spring(response: f64, damping_fraction: f64) -> Self {
+        Self::new(AnimationKind::Spring { response, damping_fraction })
+    }
+
+    /// Creates a spring animation modeled after a physical spring with the
+    /// given mass, stiffness and damping.
+    #[must_use]
+    pub const fn interpolating_spring(mass: f64, stiffness: f64, damping: f64) -> Self {
+        Self::new(AnimationKind::InterpolatingSpring { mass, stiffness, damping, initial_velocity: 0.0 })
+    }
+
+    /// Repeats this animation the given number of times, optionally playing
+    /// every other repetition backwards. This replaces any previous
+    /// repetition, delays are not repeated.
+    #[must_use]
+    pub const fn repeat_count(self, count: u32, autoreverses: bool) -> Self {
+        Self { repetition: Some(Repetition { count: Some(count), autoreverses }), ..self }
+    }
+
+    /// Repeats this animation indefinitely, optionally playing every other
+    /// repetition backwards. This replaces any previous repetition, delays are
+    /// not repeated.
+    #[must_use]
+    pub const fn repeat_forever(self, autoreverses: bool) -> Self {
+        Self { repetition: Some(Repetition { count: None, autoreverses }), ..self }
+    }
+
+    /// Delays this animation by the given duration (in addition to any
+    /// previous delay).
+    #[must_use]
+    pub fn delay(self, delay: Duration) -> Self {
+        Self { delay_seconds: self.delay_seconds + delay.as_secs_f64(), ..self }
+    }
+
+    /// The curve or spring of this animation.
+    #[must_use]
+    pub const fn kind(&self) -> AnimationKind {
+        self.kind
+    }
+
+    /// How often this animation is repeated, if at all.
+    #[must_use]
+    pub const fn repetition(&self) -> Option<Repetition> {
+        self.repetition
+    }
+
+    /// The delay before the animation starts, in seconds.
+    #[must_use]
+    pub const fn delay_seconds(&self) -> f64 {
+        self.delay_seconds
+    }
+
+    /// The total duration of the animation in seconds, or `None` if it repeats
+    /// indefinitely. Springs are considered finished once they have settled.
+    #[must_use]
+    pub fn duration_seconds(&self) -> Option<f64> {
+        let duration = self.kind.duration_seconds();
+        let duration = match self.repetition {
+            Some(Repetition { count, .. }) => duration * f64::from(count?),
+            None => duration,
+        };
+        Some(duration + self.delay_seconds)
+    }
+
+    /// Evaluates the animation at the given time (in seconds since its start),
+    /// yielding the progress from the start (0) to the end value (1). Springs
+    /// may overshoot, yielding values outside of that range.
+    ///
+    /// The default animation is evaluated as an ease-in/out curve, since the
+    /// actual default depends on the backend.
+    #[must_use]
+    pub fn value_at(&self, t: f64) -> f64 {
+        let t = t - self.delay_seconds;
+        let Some(Repetition { count, autoreverses }) = self.repetition else {
+            return self.kind.value_at(t);
+        };
+        let duration = self.kind.duration_seconds();
+        if t <= 0.0 || duration <= 0.0 || duration.is_infinite() {
+            return self.kind.value_at(t);
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let iteration = (t / duration).floor() as u64;
+        if let Some(count) = count && iteration >= u64::from(count) {
+            // Finished, the last iteration determines the end value
+            let is_reversed = autoreverses && count % 2 == 0;
+            return self.kind.value_at(if is_reversed { 0.0 } else { duration });
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let local_t = t - iteration as f64 * duration;
+        if autoreverses && iteration % 2 == 1 {
+            self.kind.value_at(duration - local_t)
+        } else {
+            self.kind.value_at(local_t)
+        }
+    }
+}
+
+impl AnimationKind {
+    /// The duration of a single play of the animation in seconds. Springs are
+    /// considered finished once they have settled.
+    #[must_use]
+    pub fn duration_seconds(&self) -> f64 {
+        match *self {
+            Self::Default {} => DEFAULT_ANIMATION_DURATION_SECONDS,
+            Self::Curve { duration_seconds, .. }
+            | Self::TimingCurve { duration_seconds, .. } => duration_seconds.unwrap_or(DEFAULT_ANIMATION_DURATION_SECONDS),
+            Self::Spring { .. }
+            | Self::InterpolatingSpring { .. } => {
+                let (omega, zeta, velocity) = self.spring_parameters().unwrap_or_default();
+                spring_settling_time(omega, zeta, velocity)
+            },
+        }
+    }
+
+    /// Evaluates a single play of the animation at the given time, see
+    /// [`Animation::value_at`].
+    #[must_use]
+    pub fn value_at(&self, t: f64) -> f64 {
+        match *self {
+            Self::Default {} => Animation::EASE_IN_OUT.kind.value_at(t),
+            Self::Curve { curve, duration_seconds } => {
+                let (control_point1, control_point2) = curve.control_points();
+                cubic_bezier(control_point1, control_point2, progress(t, duration_seconds))
+            },
+            Self::TimingCurve { control_point1, control_point2, duration_seconds } => {
+                cubic_bezier(control_point1, control_point2, progress(t, duration_seconds))
+            },
+            Self::Spring { .. }
+            | Self::InterpolatingSpring { .. } => {
+                let Some((omega, zeta, velocity)) = self.spring_parameters() else { return 1.0 };
+                1.0 - spring_displacement(omega, zeta, velocity, t.max(0.0))
+            },
+        }
+    }
+
+    /// The undamped angular frequency, the damping ratio and the initial
+    /// velocity of a spring animation.
+    fn spring_parameters(&self) -> Option<(f64, f64, f64)> {
+        match *self {
+            Self::Spring { response, damping_fraction } => {
+                let omega = 2.0 * PI / response;
+                Some((omega, damping_fraction, 0.0))
+            },
+            Self::InterpolatingSpring { mass, stiffness, damping, initial_velocity } => {
+                let omega = (stiffness / mass).sqrt();
+                let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+                Some((omega, zeta, initial_velocity))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<AnimationKind> for Animation {
+    fn from(kind: AnimationKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl AnimationCurve {
+    /// The inner control points of the corresponding cubic bézier curve, using
+    /// the same values as CSS.
+    #[must_use]
+    pub const fn control_points(self) -> (Vec2<f64>, Vec2<f64>) {
+        match self {
+            Self::Linear => (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)),
+            Self::EaseIn => (Vec2::new(0.42, 0.0), Vec2::new(1.0, 1.0)),
+            Self::EaseOut => (Vec2::new(0.0, 0.0), Vec2::new(0.58, 1.0)),
+            Self::EaseInOut => (Vec2::new(0.42, 0.0), Vec2::new(0.58, 1.0)),
+        }
+    }
+}
+
+/// The linear progress at the given time for the given (or default) duration.
+fn progress(t: f64, duration_seconds: Option<f64>) -> f64 {
+    let duration = duration_seconds.unwrap_or(DEFAULT_ANIMATION_DURATION_SECONDS);
+    if duration <= 0.0 {
+        return if t < 0.0 { 0.0 } else { 1.0 };
+    }
+    (t / duration).clamp(0.0, 1.0)
+}
+
+/// Evaluates the y-coordinate of the cubic bézier curve from (0, 0) to (1, 1)
+/// with the given inner control points at the given x-coordinate.
+fn cubic_bezier(p1: Vec2<f64>, p2: Vec2<f64>, x: f64) -> f64 {
+    let bezier = |a: f64, b: f64, s: f64| 3.0 * a * s * (1.0 - s).powi(2) + 3.0 * b * s * s * (1.0 - s) + s.powi(3);
+    let bezier_derivative = |a: f64, b: f64, s: f64| 3.0 * a * (1.0 - s).powi(2) + 6.0 * (b - a) * s * (1.0 - s) + 3.0 * (1.0 - b) * s * s;
+
+    // Solve x(s) = x using Newton's method, falling back to bisection
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier(p1.x, p2.x, s) - x;
+        let slope = bezier_derivative(p1.x, p2.x, s);
+        if error.abs() < 1e-9 || slope.abs() < 1e-9 {
+            break;
+        }
+        s = (s - error / slope).clamp(0.0, 1.0);
+    }
+    if (bezier(p1.x, p2.x, s) - x).abs() >= 1e-6 {
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..64 {
+            s = f64::midpoint(low, high);
+            if bezier(p1.x, p2.x, s) < x { low = s; } else { high = s; }
+        }
+    }
+
+    bezier(p1.y, p2.y, s)
+}
+
+/// The remaining displacement (relative to the distance to travel) of a
+/// damped spring at the given time.
+fn spring_displacement(omega: f64, zeta: f64, velocity: f64, t: f64) -> f64 {
+    if zeta < 1.0 && !is_critically_damped(zeta) {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        (-zeta * omega * t).exp() * ((omega_d * t).cos() + (zeta * omega - velocity) / omega_d * (omega_d * t).sin())
+    } else if is_critically_damped(zeta) {
+        (-omega * t).exp() * (1.0 + (omega - velocity) * t)
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+        let a = (-velocity - r2) / (r1 - r2);
+        a * (r1 * t).exp() + (1.0 - a) * (r2 * t).exp()
+    }
+}
+
+/// Whether a spring with the given damping ratio is (close enough to)
+/// critically damped, where the under- and overdamped solutions become
+/// numerically unstable.
+fn is_critically_damped(zeta: f64) -> bool {
+    (zeta - 1.0).abs() < CRITICAL_DAMPING_TOLERANCE
+}
+
+/// The time after which the displacement of a damped spring stays below the
+/// settling threshold, estimated using an upper bound of its envelope.
+fn spring_settling_time(omega: f64, zeta: f64, velocity: f64) -> f64 {
+    let threshold = SPRING_SETTLING_THRESHOLD;
+    if zeta <= 0.0 {
+        f64::INFINITY
+    } else if zeta < 1.0 && !is_critically_damped(zeta) {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let amplitude = 1.0_f64.hypot((zeta * omega - velocity) / omega_d);
+        (amplitude / threshold).ln() / (zeta * omega)
+    } else if is_critically_damped(zeta) {
+        // Solve e^(-omega t) (1 + |omega - velocity| t) = threshold iteratively
+        let mut t = (1.0 / threshold).ln() / omega;
+        for _ in 0..16 {
+            t = ((1.0 + (omega - velocity).abs() * t) / threshold).ln() / omega;
+        }
+        t
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+        let a = (-velocity - r2) / (r1 - r2);
+        ((a.abs() + (1.0 - a).abs()) / threshold).ln() / -r1
+    }
 }
 
 impl Default for Animation {
     fn default() -> Self {
-        Self::Default {}
+        Self::new(AnimationKind::Default {})
+    }
+}
+
+/// The serialized form of an animation, which wraps the animation in
+/// repetitions and delays like the backends' animation modifiers do.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum AnimationRepr {
+    Repeat { repeat: RepeatRepr },
+    Delay { delay: DelayRepr },
+    Kind(AnimationKind),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepeatRepr {
+    wrapped: Box<AnimationRepr>,
+    count: Option<u32>,
+    autoreverses: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DelayRepr {
+    wrapped: Box<AnimationRepr>,
+    delay_seconds: f64,
+}
+
+impl From<Animation> for AnimationRepr {
+    fn from(animation: Animation) -> Self {
+        let mut repr = Self::Kind(animation.kind);
+        if let Some(Repetition { count, autoreverses }) = animation.repetition {
+            repr = Self::Repeat { repeat: RepeatRepr { wrapped: Box::new(repr), count, autoreverses } };
+        }
+        if animation.delay_seconds > 0.0 {
+            repr = Self::Delay { delay: DelayRepr { wrapped: Box::new(repr), delay_seconds: animation.delay_seconds } };
+        }
+        repr
+    }
+}
+
+impl TryFrom<AnimationRepr> for Animation {
+    type Error = String;
+
+    fn try_from(repr: AnimationRepr) -> Result<Self, String> {
+        match repr {
+            AnimationRepr::Kind(kind) => Ok(Self::new(kind)),
+            AnimationRepr::Repeat { repeat: RepeatRepr { wrapped, count, autoreverses } } => {
+                let wrapped = Self::try_from(*wrapped)?;
+                if wrapped.repetition.is_some() || wrapped.delay_seconds > 0.0 {
+                    return Err("Only curves and springs can be repeated".to_owned());
+                }
+                Ok(Self { repetition: Some(Repetition { count, autoreverses }), ..wrapped })
+            },
+            AnimationRepr::Delay { delay: DelayRepr { wrapped, delay_seconds } } => {
+                let wrapped = Self::try_from(*wrapped)?;
+                Ok(Self { delay_seconds: wrapped.delay_seconds + delay_seconds, ..wrapped })
+            },
+        }
     }
 }
 
 impl fmt::Display for Animation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(Repetition { count, autoreverses }) = self.repetition {
+            write!(f, ", repeated ")?;
+            match count {
+                Some(count) => write!(f, "{count}x")?,
+                None => write!(f, "forever")?,
+            }
+            if autoreverses {
+                write!(f, ", autoreversing")?;
+            }
+        }
+        if self.delay_seconds > 0.0 {
+            write!(f, ", delayed by {}s", self.delay_seconds)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AnimationKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Default {} => write!(f, "Default")?,
@@ -74,6 +461,14 @@ impl fmt::Display for Animation {
                     write!(f, " ({duration_seconds}s)")?;
                 }
             },
+            Self::TimingCurve { control_point1, control_point2, duration_seconds } => {
+                write!(f, "Timing curve ({}, {}, {}, {})", control_point1.x, control_point1.y, control_point2.x, control_point2.y)?;
+                if let Some(duration_seconds) = duration_seconds {
+                    write!(f, " ({duration_seconds}s)")?;
+                }
+            },
+            Self::Spring { response, damping_fraction } => write!(f, "Spring ({response}s, {damping_fraction})")?,
+            Self::InterpolatingSpring { mass, stiffness, damping, .. } => write!(f, "Spring ({mass}, {stiffness}, {damping})")?,
         }
         Ok(())
     }
@@ -89,3 +484,94 @@ impl fmt::Display for AnimationCurve {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::{assert_approx_eq, Animation, Vec2};
+
+    #[test]
+    fn curves() {
+        let linear = Animation::linear(Duration::from_secs(2));
+        assert_approx_eq!(linear.value_at(-1.0), 0.0);
+        assert_approx_eq!(linear.value_at(0.5), 0.25);
+        assert_approx_eq!(linear.value_at(3.0), 1.0);
+
+        let ease_in_out = Animation::ease_in_out(Duration::from_secs(1));
+        assert_approx_eq!(ease_in_out.value_at(0.5), 0.5, 1e-6);
+        assert!(ease_in_out.value_at(0.1) < 0.1);
+        assert!(ease_in_out.value_at(0.9) > 0.9);
+
+        let timing_curve = Animation::timing_curve(Vec2::new(0.0, 1.0), Vec2::new(0.0, 1.0), Duration::from_secs(1));
+        assert!(timing_curve.value_at(0.2) > 0.5);
+        assert_approx_eq!(timing_curve.value_at(1.0), 1.0);
+    }
+
+    #[test]
+    fn springs() {
+        let bouncy = Animation::spring(0.5, 0.3);
+        assert_approx_eq!(bouncy.value_at(0.0), 0.0);
+        assert!((0..100).map(|i| bouncy.value_at(f64::from(i) / 100.0)).any(|v| v > 1.0));
+        assert_approx_eq!(bouncy.value_at(bouncy.duration_seconds().unwrap()), 1.0, 0.002);
+
+        for spring in [Animation::spring(0.5, 1.0), Animation::interpolating_spring(1.0, 100.0, 40.0)] {
+            assert!((0..100).map(|i| spring.value_at(f64::from(i) / 100.0)).all(|v| (0.0..=1.0).contains(&v)));
+            assert_approx_eq!(spring.value_at(spring.duration_seconds().unwrap()), 1.0, 0.002);
+        }
+    }
+
+    #[test]
+    fn repetitions() {
+        let delayed = Animation::linear(Duration::from_secs(1)).delay(Duration::from_secs(1));
+        assert_approx_eq!(delayed.value_at(0.5), 0.0);
+        assert_approx_eq!(delayed.value_at(1.5), 0.5);
+        assert_eq!(delayed.duration_seconds(), Some(2.0));
+
+        let repeated = Animation::linear(Duration::from_secs(1)).repeat_count(2, true);
+        assert_approx_eq!(repeated.value_at(0.25), 0.25);
+        assert_approx_eq!(repeated.value_at(1.25), 0.75);
+        assert_approx_eq!(repeated.value_at(3.0), 0.0);
+        assert_eq!(repeated.duration_seconds(), Some(2.0));
+
+        let forever = Animation::linear(Duration::from_secs(1)).repeat_forever(false);
+        assert_approx_eq!(forever.value_at(10.25), 0.25);
+        assert_eq!(forever.duration_seconds(), None);
+    }
+
+    #[test]
+    fn nearly_critical_springs() {
+        for damping_fraction in [1.0 - 1e-9, 1.0, 1.0 + 1e-9] {
+            let spring = Animation::spring(0.5, damping_fraction);
+            let duration = spring.duration_seconds().unwrap();
+            assert!(duration.is_finite());
+            assert!((0..100).map(|i| spring.value_at(f64::from(i) / 100.0)).all(|v| v.is_finite() && (0.0..=1.0).contains(&v)));
+            assert_approx_eq!(spring.value_at(duration), 1.0, 0.002);
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        let animation = Animation::linear(Duration::from_secs(1)).repeat_count(2, true).delay(Duration::from_secs(3));
+        let json = json!({
+            "delay": {
+                "wrapped": {
+                    "repeat": {
+                        "wrapped": { "curve": { "curve": "linear", "durationSeconds": 1.0 } },
+                        "count": 2,
+                        "autoreverses": true,
+                    },
+                },
+                "delaySeconds": 3.0,
+            },
+        });
+        assert_eq!(serde_json::to_value(animation).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Animation>(json).unwrap(), animation);
+        assert_eq!(serde_json::to_value(Animation::default()).unwrap(), json!({ "default": {} }));
+
+        let nested = json!({ "repeat": { "wrapped": { "delay": { "wrapped": { "default": {} }, "delaySeconds": 1.0 } }, "count": null, "autoreverses": false } });
+        assert!(serde_json::from_value::<Animation>(nested).is_err());
+    }
+}
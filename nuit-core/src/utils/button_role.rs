@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// The purpose of a button, which backends may use to style it, e.g. when
+/// presenting it as an action of an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ButtonRole {
+    /// A button cancelling an operation.
+    Cancel,
+    /// A button performing a destructive operation, e.g. deleting data.
+    Destructive,
+}
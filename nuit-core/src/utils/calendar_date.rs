@@ -0,0 +1,112 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A day in the (proleptic) Gregorian calendar, without a time of day or time
+/// zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", try_from = "CalendarDateRepr")]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// The unvalidated fields of a deserialized date.
+#[derive(Deserialize)]
+struct CalendarDateRepr {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl TryFrom<CalendarDateRepr> for CalendarDate {
+    type Error = String;
+
+    fn try_from(repr: CalendarDateRepr) -> Result<Self, String> {
+        let CalendarDateRepr { year, month, day } = repr;
+        Self::new(year, month, day).ok_or_else(|| format!("Invalid date {year}-{month}-{day}"))
+    }
+}
+
+impl CalendarDate {
+    /// Creates a date from the given year, month (from 1 to 12) and day of the
+    /// month (starting at 1), returning `None` if there is no such day.
+    #[must_use]
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 to 12.
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting at 1.
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Whether the given year is a leap year.
+    #[must_use]
+    pub const fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// The number of days in the given month (from 1 to 12) of the given year.
+    #[must_use]
+    pub const fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    /// Formats the date in ISO 8601 format, e.g. 2024-02-29.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::CalendarDate;
+
+    #[test]
+    fn validation() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(2000, 2, 29).is_some());
+        assert!(CalendarDate::new(1900, 2, 29).is_none());
+        assert!(CalendarDate::new(2024, 4, 31).is_none());
+        assert!(CalendarDate::new(2024, 13, 1).is_none());
+        assert!(CalendarDate::new(2024, 1, 0).is_none());
+        assert_eq!(CalendarDate::new(987, 6, 5).unwrap().to_string(), "0987-06-05");
+    }
+
+    #[test]
+    fn deserialization() {
+        let date: CalendarDate = serde_json::from_value(json!({ "year": 2024, "month": 2, "day": 29 })).unwrap();
+        assert_eq!(date, CalendarDate::new(2024, 2, 29).unwrap());
+        assert_eq!(serde_json::to_value(date).unwrap(), json!({ "year": 2024, "month": 2, "day": 29 }));
+
+        assert!(serde_json::from_value::<CalendarDate>(json!({ "year": 2024, "month": 13, "day": 40 })).is_err());
+        assert!(serde_json::from_value::<CalendarDate>(json!({ "year": 2023, "month": 2, "day": 29 })).is_err());
+    }
+}
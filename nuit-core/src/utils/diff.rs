@@ -32,6 +32,22 @@ impl<T> Difference<T> {
         }
     }
 
+    /// Whether there are no differences.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.changed.is_empty() && self.added.is_empty()
+    }
+
+    /// Whether any of the differences affects the subtree at the given id
+    /// path, i.e. lies at, below or above it.
+    #[must_use]
+    pub fn affects(&self, id_path: &IdPath) -> bool {
+        self.removed.iter().map(|(p, _)| p)
+            .chain(self.changed.iter().map(|(p, _, _)| p))
+            .chain(self.added.iter().map(|(p, _)| p))
+            .any(|p| p.starts_with(id_path) || id_path.starts_with(p))
+    }
+
     pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Difference<U> {
         Difference {
             removed: self.removed.into_iter().map(|(p, x)| (p, f(x))).collect(),
@@ -10,6 +10,11 @@ pub struct Geometry {
 }
 
 impl Geometry {
+    #[must_use]
+    pub const fn new(size: Vec2<f64>) -> Self {
+        Self { size }
+    }
+
     #[must_use]
     pub const fn size(self) -> Vec2<f64> {
         self.size
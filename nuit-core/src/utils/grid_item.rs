@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// The size of a column (or row) of a lazy grid.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum GridItemSize {
+    /// A single item with the given size.
+    Fixed { size: f64 },
+    /// A single item sharing the available space with the other flexible
+    /// items, within the given bounds.
+    Flexible { minimum: f64, maximum: Option<f64> },
+    /// As many items of at least the minimum size as fit into the available
+    /// space.
+    Adaptive { minimum: f64, maximum: Option<f64> },
+}
+
+/// A description of a column (or row) of a lazy grid.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridItem {
+    pub size: GridItemSize,
+    /// The spacing to the next column (or row), if it differs from the grid's.
+    pub spacing: Option<f64>,
+}
+
+impl GridItem {
+    /// Creates an item with the given size.
+    #[must_use]
+    pub const fn new(size: GridItemSize) -> Self {
+        Self { size, spacing: None }
+    }
+
+    /// Creates a single column (or row) with the given size.
+    #[must_use]
+    pub fn fixed(size: impl Into<f64>) -> Self {
+        Self::new(GridItemSize::Fixed { size: size.into() })
+    }
+
+    /// Creates a single column (or row) that takes up an equal share of the
+    /// available space.
+    #[must_use]
+    pub const fn flexible() -> Self {
+        Self::new(GridItemSize::Flexible { minimum: 10.0, maximum: None })
+    }
+
+    /// Creates a single column (or row) that takes up an equal share of the
+    /// available space, within the given bounds.
+    #[must_use]
+    pub fn flexible_within(minimum: impl Into<f64>, maximum: impl Into<f64>) -> Self {
+        Self::new(GridItemSize::Flexible { minimum: minimum.into(), maximum: Some(maximum.into()) })
+    }
+
+    /// Creates as many columns (or rows) of at least the given size as fit
+    /// into the available space.
+    #[must_use]
+    pub fn adaptive(minimum: impl Into<f64>) -> Self {
+        Self::new(GridItemSize::Adaptive { minimum: minimum.into(), maximum: None })
+    }
+
+    /// Sets the spacing to the next column (or row).
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: impl Into<f64>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+}
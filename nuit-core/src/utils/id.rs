@@ -7,7 +7,7 @@ use serde::{Serialize, Deserialize};
 // require a lifetime parameter now.
 
 /// An identifier for a view.
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Id {
     Index(i64),
@@ -30,11 +30,21 @@ impl IdPath {
         self.0.first().cloned()
     }
 
+    #[must_use]
+    pub fn last(&self) -> Option<Id> {
+        self.0.last().cloned()
+    }
+
     #[must_use]
     pub fn tail(&self) -> &Self {
         Self::ref_cast(&self.0[1..])
     }
 
+    #[must_use]
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
     #[must_use]
     pub fn child(&self, id: impl Into<Id>) -> IdPathBuf {
         self.to_owned().child(id)
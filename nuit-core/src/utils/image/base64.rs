@@ -0,0 +1,84 @@
+//! (De)serialization of bytes as standard, padded base64 strings, which are
+//! much more compact in JSON than arrays of numbers and are decoded natively
+//! by Swift's `JSONDecoder` (into `Data`) and by browsers (via `atob`).
+
+use std::sync::Arc;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn serialize<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+    serializer.serialize_str(&encode(data))
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Arc<[u8]>, D::Error> where D: Deserializer<'de> {
+    let string = String::deserialize(deserializer)?;
+    decode(&string)
+        .map(Arc::from)
+        .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&string), &"a base64 string"))
+}
+
+fn encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &byte)| bits | u32::from(byte) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn decode(string: &str) -> Option<Vec<u8>> {
+    let bytes = string.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(bytes.len() / 4 * 3);
+    for (index, chunk) in bytes.chunks(4).enumerate() {
+        let is_last = index == bytes.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return None;
+        }
+        let mut bits = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let value = ALPHABET.iter().position(|&a| a == c)?;
+            bits |= u32::try_from(value).ok()? << (18 - 6 * i);
+        }
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..4 - padding]);
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    #[test]
+    fn round_trip() {
+        for (data, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (&[0x89, b'P', b'N', b'G', 0xFF], "iVBOR/8="),
+        ] {
+            assert_eq!(encode(data), encoded);
+            assert_eq!(decode(encoded).as_deref(), Some(data));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for string in ["Zg=", "Zg=a", "Z===", "Zg==Zm9v", "Zm9*"] {
+            assert_eq!(decode(string), None, "{string}");
+        }
+    }
+}
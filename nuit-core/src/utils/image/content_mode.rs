@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// How content is scaled to the available space when preserving its aspect
+/// ratio.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContentMode {
+    /// Scales the content to fit within the available space, possibly leaving
+    /// some of it empty.
+    #[default]
+    Fit,
+    /// Scales the content to fill the available space, possibly cropping it.
+    Fill,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The quality of the interpolation used when scaling images.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Interpolation {
+    /// No interpolation, i.e. nearest-neighbor scaling. Useful for pixel art.
+    None,
+    Low,
+    Medium,
+    /// The highest quality, chosen by default.
+    #[default]
+    High,
+}
@@ -0,0 +1,8 @@
+mod base64;
+mod content_mode;
+mod interpolation;
+mod source;
+
+pub use content_mode::*;
+pub use interpolation::*;
+pub use source::*;
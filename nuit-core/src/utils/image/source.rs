@@ -0,0 +1,20 @@
+use std::{path::PathBuf, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+/// Where an image is loaded from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ImageSource {
+    /// An image file (e.g. PNG or JPEG) at the given path.
+    File { path: PathBuf },
+    /// An encoded image (e.g. PNG or JPEG) in memory. The data is shared
+    /// between clones and serialized as a base64 string.
+    Bytes {
+        #[serde(with = "super::base64")]
+        data: Arc<[u8]>,
+    },
+    /// A symbol provided by the platform, i.e. an SF Symbol on Apple platforms
+    /// and an icon from the icon theme (by its freedesktop name) on Linux.
+    Symbol { name: String },
+}
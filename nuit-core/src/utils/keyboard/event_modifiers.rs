@@ -0,0 +1,90 @@
+use std::ops::BitOr;
+
+use serde::{Deserialize, Serialize};
+
+/// A set of modifier keys that are held down, e.g. as part of a keyboard
+/// shortcut.
+///
+/// Since only Apple platforms have a command key, backends for other platforms
+/// map [`EventModifiers::COMMAND`] to the control key. This makes it the
+/// modifier of choice for cross-platform shortcuts like command/control + S.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventModifiers {
+    raw_value: u8,
+}
+
+impl EventModifiers {
+    pub const EMPTY: Self = Self::new();
+
+    pub const COMMAND: Self = Self::from_raw_value(1 << 0);
+    pub const SHIFT: Self = Self::from_raw_value(1 << 1);
+    pub const OPTION: Self = Self::from_raw_value(1 << 2);
+    pub const CONTROL: Self = Self::from_raw_value(1 << 3);
+
+    /// Creates a set containing no modifiers.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { raw_value: 0 }
+    }
+
+    /// Creates a set from the given raw value.
+    #[must_use]
+    pub const fn from_raw_value(raw_value: u8) -> Self {
+        Self { raw_value }
+    }
+
+    /// The raw value of this set.
+    #[must_use]
+    pub const fn raw_value(self) -> u8 {
+        self.raw_value
+    }
+
+    /// The union with the given set.
+    #[must_use]
+    pub const fn union(self, rhs: Self) -> Self {
+        Self { raw_value: self.raw_value | rhs.raw_value }
+    }
+
+    /// Whether the set contains all of the given modifiers.
+    #[must_use]
+    pub const fn contains(self, modifiers: Self) -> bool {
+        (self.raw_value & modifiers.raw_value) == modifiers.raw_value
+    }
+
+    /// Whether the set contains no modifiers.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.raw_value == 0
+    }
+}
+
+impl Default for EventModifiers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitOr for EventModifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EventModifiers;
+
+    #[test]
+    fn union() {
+        let modifiers = EventModifiers::COMMAND | EventModifiers::SHIFT;
+        assert!(modifiers.contains(EventModifiers::COMMAND));
+        assert!(modifiers.contains(EventModifiers::SHIFT));
+        assert!(modifiers.contains(EventModifiers::EMPTY));
+        assert!(!modifiers.contains(EventModifiers::OPTION));
+        assert!(!modifiers.contains(EventModifiers::COMMAND | EventModifiers::CONTROL));
+        assert!(EventModifiers::new().is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// A key on the keyboard, as used in keyboard shortcuts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum Key {
+    /// The key producing the given character. Letters should be lowercase,
+    /// use the shift modifier for uppercase ones.
+    Character { character: char },
+    Return {},
+    Escape {},
+    Delete {},
+    DeleteForward {},
+    Tab {},
+    Space {},
+    UpArrow {},
+    DownArrow {},
+    LeftArrow {},
+    RightArrow {},
+    Home {},
+    End {},
+    PageUp {},
+    PageDown {},
+}
+
+impl Key {
+    #[must_use]
+    pub const fn character(character: char) -> Self {
+        Self::Character { character }
+    }
+}
+
+impl From<char> for Key {
+    fn from(character: char) -> Self {
+        Self::character(character)
+    }
+}
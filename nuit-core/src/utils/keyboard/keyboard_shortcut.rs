@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use super::{EventModifiers, Key};
+
+/// A key combined with the modifiers that have to be held down with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyboardShortcut {
+    key: Key,
+    modifiers: EventModifiers,
+}
+
+impl KeyboardShortcut {
+    #[must_use]
+    pub const fn new(key: Key, modifiers: EventModifiers) -> Self {
+        Self { key, modifiers }
+    }
+
+    #[must_use]
+    pub const fn key(self) -> Key {
+        self.key
+    }
+
+    #[must_use]
+    pub const fn modifiers(self) -> EventModifiers {
+        self.modifiers
+    }
+}
+
+impl From<Key> for KeyboardShortcut {
+    /// A shortcut for the given key with the command modifier, the most common
+    /// kind of shortcut.
+    fn from(key: Key) -> Self {
+        Self::new(key, EventModifiers::COMMAND)
+    }
+}
@@ -0,0 +1,7 @@
+mod event_modifiers;
+mod key;
+mod keyboard_shortcut;
+
+pub use event_modifiers::*;
+pub use key::*;
+pub use keyboard_shortcut::*;
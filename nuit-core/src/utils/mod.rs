@@ -1,7 +1,10 @@
 mod access;
+mod accessibility;
 mod animation;
 mod alignment;
 mod angle;
+mod button_role;
+mod calendar_date;
 mod approx_eq;
 mod clone;
 mod color;
@@ -10,21 +13,30 @@ mod edge;
 mod font;
 mod frame;
 mod geometry;
+mod grid_item;
 mod has_id;
 mod id_path;
 mod id;
 mod identified;
+mod image;
 mod insets;
+mod keyboard;
 mod navigation;
+mod selection_mode;
 mod style;
+mod text;
+mod toolbar;
 mod unit_point;
 mod vec2;
 mod zero;
 
 pub use access::*;
+pub use accessibility::*;
 pub use animation::*;
 pub use alignment::*;
 pub use angle::*;
+pub use button_role::*;
+pub use calendar_date::*;
 pub use approx_eq::*;
 pub use color::*;
 pub use diff::*;
@@ -32,13 +44,19 @@ pub use edge::*;
 pub use font::*;
 pub use frame::*;
 pub use geometry::*;
+pub use grid_item::*;
 pub use has_id::*;
 pub use id_path::*;
 pub use id::*;
 pub use identified::*;
+pub use image::*;
 pub use insets::*;
+pub use keyboard::*;
 pub use navigation::*;
+pub use selection_mode::*;
 pub use style::*;
+pub use text::*;
+pub use toolbar::*;
 pub use unit_point::*;
 pub use vec2::*;
 pub use zero::*;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// How many rows of a list can be selected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    #[default]
+    None,
+    /// At most one row can be selected.
+    Single,
+    /// Any number of rows can be selected.
+    Multiple,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// A hint for the kind of input a text field expects, which e.g. lets
+/// on-screen keyboards show a suitable layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyboardType {
+    #[default]
+    Default,
+    EmailAddress,
+    Url,
+    Number,
+    Decimal,
+    PhoneNumber,
+}
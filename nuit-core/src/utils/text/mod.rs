@@ -0,0 +1,5 @@
+mod keyboard_type;
+mod text_field_kind;
+
+pub use keyboard_type::*;
+pub use text_field_kind::*;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of text a text field is used to enter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextFieldKind {
+    /// A single line of plain text.
+    #[default]
+    Plain,
+    /// A single line of sensitive text, e.g. a password, which is obscured.
+    Secure,
+    /// Multiple lines of text, where Enter inserts a line break.
+    Multiline,
+}
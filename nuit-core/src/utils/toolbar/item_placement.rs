@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Where an item is placed in a toolbar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ToolbarItemPlacement {
+    /// A placement chosen by the platform, usually the trailing end.
+    #[default]
+    Automatic,
+    /// The leading end, where navigation controls are usually located.
+    Navigation,
+    /// The center, usually replacing the title.
+    Principal,
+    /// The trailing end, for the most important actions.
+    PrimaryAction,
+}
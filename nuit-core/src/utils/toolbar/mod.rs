@@ -0,0 +1,3 @@
+mod item_placement;
+
+pub use item_placement::*;
@@ -48,6 +48,12 @@ impl UnitPoint {
         };
         Self::with_xy(x, y)
     }
+
+    /// The normalized coordinates of this point.
+    #[must_use]
+    pub const fn value(self) -> Vec2<f64> {
+        self.value
+    }
 }
 
 impl From<Alignment> for UnitPoint {
@@ -10,24 +10,26 @@ pub fn derive(input: TokenStream) -> TokenStream {
     let const_idents: Vec<_> = input.generics.const_params().map(|p| p.ident.clone()).collect();
     let type_params: Vec<_> = input.generics.type_params().collect();
 
-    let state_fields: Vec<Ident> = match input.data {
+    let fields: Vec<Field> = match input.data {
         Data::Struct(s) => match s.fields {
             Fields::Unit => Vec::new(),
-            Fields::Named(fs) => fs.named.into_iter()
-                .filter(|Field { ty, .. }| is_state_type(ty))
-                .map(|f| f.ident.expect("#[derive(Bind)] requires all state fields to be named"))
-                .collect(),
+            Fields::Named(fs) => fs.named.into_iter().collect(),
             Fields::Unnamed(_) => panic!("#[derive(Bind)] requires named fields!"),
         },
         _ => panic!("#[derive(Bind)] only works on structs!")
     };
 
+    // Memos and focus state are stored alongside state and therefore share its keys
+    let state_fields: Vec<Ident> = fields_of_type(&fields, &["State", "Memo", "FocusState"]);
+    let environment_fields: Vec<Ident> = fields_of_type(&fields, &["Environment"]);
+
     let indices = 0..state_fields.len();
 
     let impl_block = quote! {
         impl <#(#const_params,)* #(#type_params,)*> ::nuit::Bind for #name <#(#const_idents,)* #(#type_params,)*> {
             fn bind(&self, context: &::nuit::Context) {
                 #(self.#state_fields.link(context.storage(), ::nuit::StateKey::new(context.id_path(), #indices));)*
+                #(self.#environment_fields.link(context.environment());)*
             }
         }
     };
@@ -35,13 +37,20 @@ pub fn derive(input: TokenStream) -> TokenStream {
     impl_block.into()
 }
 
-fn is_state_type(ty: &Type) -> bool {
-    // TODO: Support qualified/parenthesized/... type annotations that resolve to `nuit::State` too
+fn fields_of_type(fields: &[Field], type_names: &[&str]) -> Vec<Ident> {
+    fields.iter()
+        .filter(|Field { ty, .. }| type_names.iter().any(|type_name| is_type(ty, type_name)))
+        .map(|f| f.ident.clone().expect("#[derive(Bind)] requires all bound fields to be named"))
+        .collect()
+}
+
+fn is_type(ty: &Type, type_name: &str) -> bool {
+    // TODO: Support qualified/parenthesized/... type annotations that resolve to e.g. `nuit::State` too
     match ty {
         Type::Path(ty_path) => {
             let segments = &ty_path.path.segments;
             if let Some(segment) = segments.first() {
-                segment.ident == "State"
+                segment.ident == type_name
             } else {
                 false
             }
@@ -0,0 +1,50 @@
+//! A headless test harness for Nuit views.
+//!
+//! The harness drives a [`nuit_core::Root`] directly, i.e. without any
+//! backend, which makes it possible to unit-test views in CI. Rendered node
+//! trees can also be compared against snapshots stored in the crate's
+//! `snapshots` directory, which are created or updated by running the tests
+//! with `NUIT_BLESS=1`:
+//!
+//! ```no_run
+//! # #![feature(impl_trait_in_assoc_type)]
+//! # extern crate nuit_core as nuit;
+//! # use nuit_core::{Access, Button, State, Text, View, VStack};
+//! # use nuit_derive::Bind;
+//! # use nuit_test::{assert_node_snapshot, TestRoot};
+//! #[derive(Bind, Default)]
+//! struct CounterView {
+//!     count: State<i32>,
+//! }
+//!
+//! impl View for CounterView {
+//!     type Body = impl View;
+//!
+//!     fn body(&self) -> Self::Body {
+//!         let count = self.count.clone();
+//!         VStack::from((
+//!             Text::new(format!("Count: {}", count.get())),
+//!             Button::with_text("Increment", move || count.set(count.get() + 1)),
+//!         ))
+//!     }
+//! }
+//!
+//! let root = TestRoot::new(CounterView::default());
+//! root.tap_button("Increment");
+//! assert!(root.find_text("Count: 1").is_some());
+//!
+//! assert_node_snapshot!(CounterView::default());
+//! ```
+
+#![cfg_attr(test, feature(impl_trait_in_assoc_type))]
+
+mod snapshot;
+mod test_root;
+
+pub use snapshot::*;
+pub use test_root::*;
+
+// Make nuit-derive's derive macros (which refer to `::nuit`) usable in our own
+// tests, analogous to the alias in nuit-core.
+#[cfg(test)]
+extern crate nuit_core as nuit;
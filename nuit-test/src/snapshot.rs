@@ -0,0 +1,180 @@
+use std::{env, fmt::Write, fs, path::{Path, PathBuf}};
+
+use nuit_core::{Diff, IdPathBuf, Node};
+use serde_json::Value;
+
+/// The environment variable that, if set to `1` or `true`, makes snapshot
+/// assertions write the rendered node trees to the snapshot files instead of
+/// comparing them.
+pub const BLESS_VARIABLE: &str = "NUIT_BLESS";
+
+/// Asserts that the node tree rendered by the given view (via a
+/// [`TestRoot`](crate::TestRoot)) matches the snapshot stored in the calling
+/// crate's `snapshots` directory. The snapshot is named after the enclosing
+/// function unless a name is given explicitly.
+///
+/// Running the tests with `NUIT_BLESS=1` creates or updates the snapshots.
+#[macro_export]
+macro_rules! assert_node_snapshot {
+    ($view:expr $(,)?) => {
+        $crate::assert_node_snapshot!($crate::function_name!(), $view)
+    };
+    ($name:expr, $view:expr $(,)?) => {
+        $crate::Snapshot::new(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots"),
+            format!("{}__{}", module_path!().replace("::", "__"), $name),
+        )
+        .assert_matches(&$crate::TestRoot::new($view).node())
+    };
+}
+
+/// The name of the enclosing function (excluding its path).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! function_name {
+    () => {{
+        fn f() {}
+        fn type_name_of<T>(_: T) -> &'static str {
+            ::std::any::type_name::<T>()
+        }
+        type_name_of(f)
+            .trim_end_matches("::f")
+            .trim_end_matches("::{{closure}}")
+            .rsplit("::")
+            .next()
+            .unwrap_or_default()
+    }};
+}
+
+/// A stored rendering of a node tree, which is serialized as pretty-printed
+/// JSON to keep it stable and human-readable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    path: PathBuf,
+}
+
+impl Snapshot {
+    /// Creates a snapshot with the given name in the given directory.
+    pub fn new(directory: impl AsRef<Path>, name: impl AsRef<str>) -> Self {
+        Self {
+            path: directory.as_ref().join(format!("{}.json", name.as_ref())),
+        }
+    }
+
+    /// The path of the snapshot file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the stored node tree, if the snapshot exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot cannot be read or deserialized.
+    #[must_use]
+    pub fn load(&self) -> Option<Node> {
+        let json = fs::read_to_string(&self.path).ok()?;
+        let node = serde_json::from_str(&json).unwrap_or_else(|error| {
+            panic!("Could not deserialize snapshot {} (rerun with {BLESS_VARIABLE}=1 to update it): {error}", self.path.display())
+        });
+        Some(node)
+    }
+
+    /// Writes the given node tree to the snapshot file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot cannot be written.
+    pub fn store(&self, node: &Node) {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).expect("Could not create snapshot directory");
+        }
+        let json = serde_json::to_string_pretty(node).expect("Could not serialize node");
+        fs::write(&self.path, json + "\n").expect("Could not write snapshot");
+    }
+
+    /// Asserts that the given node tree matches the stored one or, if the
+    /// bless environment variable is set, stores it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot is missing or doesn't match, showing the
+    /// differences in the latter case.
+    #[track_caller]
+    pub fn assert_matches(&self, node: &Node) {
+        if is_blessing() {
+            self.store(node);
+            return;
+        }
+
+        let Some(stored) = self.load() else {
+            panic!("No snapshot at {} (rerun with {BLESS_VARIABLE}=1 to create it)", self.path.display());
+        };
+        assert!(
+            stored == *node,
+            "Snapshot {} does not match (rerun with {BLESS_VARIABLE}=1 to update it):\n{}",
+            self.path.display(),
+            describe_difference(&stored, node),
+        );
+    }
+}
+
+/// Whether the snapshots should be blessed, i.e. [`BLESS_VARIABLE`] is set to
+/// `1` or `true`. Other values (e.g. `0`) are ignored.
+fn is_blessing() -> bool {
+    env::var(BLESS_VARIABLE).is_ok_and(|value| matches!(value.as_str(), "1" | "true"))
+}
+
+/// Describes the differences between the given old and new node trees, one
+/// line per changed, removed or added node, ordered by id path.
+#[must_use]
+pub fn describe_difference(old: &Node, new: &Node) -> String {
+    let difference = new.diff(old);
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for (path, node) in difference.removed {
+        entries.push((format_path(&path), format!("- {}", summarize(node))));
+    }
+    for (path, new_node, old_node) in difference.changed {
+        entries.push((format_path(&path), format!("- {}\n    + {}", summarize(old_node), summarize(new_node))));
+    }
+    for (path, node) in difference.added {
+        entries.push((format_path(&path), format!("+ {}", summarize(node))));
+    }
+    // Differences are recorded in no particular order
+    entries.sort();
+
+    let mut description = String::new();
+    for (path, entry) in entries {
+        let _ = writeln!(description, "  at {path}:\n    {entry}");
+    }
+    description
+}
+
+fn format_path(path: &IdPathBuf) -> String {
+    serde_json::to_string(path).unwrap_or_default()
+}
+
+/// A one-line rendering of the given node without its children, e.g.
+/// `text {"content":"Hello"}`.
+fn summarize(node: &Node) -> String {
+    let Ok(Value::Object(variant)) = serde_json::to_value(node) else { return format!("{node:?}") };
+    let Some((name, Value::Object(fields))) = variant.into_iter().next() else { return format!("{node:?}") };
+    let fields: serde_json::Map<String, Value> = fields.into_iter()
+        .filter(|(_, value)| !is_child(value))
+        .collect();
+    if fields.is_empty() {
+        name
+    } else {
+        format!("{name} {}", Value::Object(fields))
+    }
+}
+
+/// Whether the given serialized field holds child nodes.
+fn is_child(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object.len() == 2 && object.contains_key("id") && object.contains_key("value"),
+        Value::Array(values) => !values.is_empty() && values.iter().all(is_child),
+        _ => false,
+    }
+}
@@ -0,0 +1,594 @@
+use std::{cell::{Cell, RefCell}, ops::Range, rc::Rc};
+
+use nuit_core::{clone, AccessibilityNode, CalendarDate, DragEvent, Event, EventModifiers, EventResponse, FireError, Geometry, GestureEvent, Id, IdPath, IdPathBuf, Identified, Key, KeyboardShortcut, ModifierNode, Node, Root, View};
+use serde::Serialize;
+
+/// A headless wrapper around a [`Root`] that mimics a backend: It renders the
+/// view eagerly, lets tests query the rendered node tree, fires events at it
+/// and re-renders whenever the storage reports an update.
+pub struct TestRoot<T> {
+    root: Root<T>,
+    node: RefCell<Node>,
+    needs_render: Rc<Cell<bool>>,
+}
+
+impl<T> TestRoot<T> where T: View {
+    /// Creates a test root from the given view and renders it once.
+    pub fn new(view: T) -> Self {
+        Self::from_root(Root::new(view))
+    }
+
+    /// Creates a test root wrapping the given root and renders it once. This
+    /// is useful to configure the root before the first render, e.g. with a
+    /// persistence backend.
+    pub fn from_root(root: Root<T>) -> Self {
+        let needs_render = Rc::new(Cell::new(false));
+
+        root.set_update_callback(clone!(needs_render => move |_update| {
+            needs_render.set(true);
+        }));
+
+        let test_root = Self {
+            root,
+            node: RefCell::new(Node::default()),
+            needs_render,
+        };
+        test_root.render();
+        test_root.render_if_needed();
+        test_root
+    }
+
+    /// The underlying root.
+    pub const fn root(&self) -> &Root<T> {
+        &self.root
+    }
+
+    /// The most recently rendered node tree.
+    pub fn node(&self) -> Node {
+        self.node.borrow().clone()
+    }
+
+    /// Renders the view, regardless of whether its state has changed.
+    pub fn render(&self) -> Node {
+        self.needs_render.set(false);
+        let node = self.root.render();
+        *self.node.borrow_mut() = node.clone();
+        node
+    }
+
+    /// Runs the tasks that are ready and renders the view if its state has
+    /// changed since the last render. Like a backend, this keeps going until
+    /// the state settles, e.g. if an appearance handler or a task changes
+    /// state.
+    pub fn render_if_needed(&self) {
+        loop {
+            self.root.run_tasks();
+            if !self.needs_render.get() {
+                break;
+            }
+            self.render();
+        }
+    }
+
+    /// The rendered node at the given id path, if any.
+    pub fn node_at(&self, id_path: &IdPath) -> Option<Node> {
+        self.node.borrow().descendant(id_path).cloned()
+    }
+
+    /// All rendered nodes matching the given predicate, paired with their id
+    /// paths, in pre-order.
+    pub fn find_all(&self, predicate: impl Fn(&Node) -> bool) -> Vec<(IdPathBuf, Node)> {
+        self.node.borrow()
+            .descendants()
+            .into_iter()
+            .filter(|(_, node)| predicate(node))
+            .map(|(id_path, node)| (id_path, node.clone()))
+            .collect()
+    }
+
+    /// The first rendered node matching the given predicate, paired with its
+    /// id path.
+    pub fn find(&self, predicate: impl Fn(&Node) -> bool) -> Option<(IdPathBuf, Node)> {
+        self.find_all(predicate).into_iter().next()
+    }
+
+    /// The id path of the first text with the given content.
+    pub fn find_text(&self, content: &str) -> Option<IdPathBuf> {
+        self.find(|node| is_text(node, content)).map(|(id_path, _)| id_path)
+    }
+
+    /// The id path of the first button whose label contains a text with the
+    /// given content.
+    pub fn find_button(&self, label: &str) -> Option<IdPathBuf> {
+        self.find(|node| matches!(node, Node::Button { label: l, .. } if contains_text(l.value(), label)))
+            .map(|(id_path, _)| id_path)
+    }
+
+    /// The contents of all rendered texts, in pre-order.
+    pub fn texts(&self) -> Vec<String> {
+        self.find_all(|node| matches!(node, Node::Text { .. }))
+            .into_iter()
+            .filter_map(|(_, node)| match node {
+                Node::Text { content } => Some(content),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The accessibility tree of the rendered node tree, i.e. the elements an
+    /// assistive technology would see.
+    pub fn accessibility_tree(&self) -> Vec<AccessibilityNode> {
+        self.node.borrow().accessibility_tree()
+    }
+
+    /// Fires the given event at the given id path and re-renders if the view's
+    /// state changed as a result.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the event cannot be dispatched, e.g. since the id path is
+    /// stale.
+    pub fn try_fire(&self, id_path: &IdPath, event: &Event) -> Result<EventResponse, FireError> {
+        let response = self.root.fire_event(id_path, event);
+        self.render_if_needed();
+        response
+    }
+
+    /// Fires the given event at the given id path and re-renders if the view's
+    /// state changed as a result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event cannot be dispatched.
+    #[track_caller]
+    pub fn fire(&self, id_path: &IdPath, event: &Event) -> EventResponse {
+        self.try_fire(id_path, event).unwrap_or_else(|error| {
+            panic!("Could not fire {event:?} at {id_path:?}: {error}");
+        })
+    }
+
+    /// Taps the button at the given id path.
+    pub fn tap(&self, id_path: &IdPath) {
+        self.fire(id_path, &Event::ButtonTap {});
+    }
+
+    /// Taps the first button whose label contains a text with the given
+    /// content.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such button.
+    #[track_caller]
+    pub fn tap_button(&self, label: &str) {
+        let Some(id_path) = self.find_button(label) else {
+            panic!("No button labeled {label:?} in {:#?}", self.node());
+        };
+        self.tap(&id_path);
+    }
+
+    /// Presses the given keyboard shortcut, tapping the first button it is
+    /// assigned to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no button has the given shortcut.
+    #[track_caller]
+    pub fn press_shortcut(&self, key: impl Into<Key>, modifiers: EventModifiers) {
+        let shortcut = KeyboardShortcut::new(key.into(), modifiers);
+        let button_path = self.find(|node| matches!(
+            node,
+            Node::Modified { modifier: ModifierNode::KeyboardShortcut { shortcut: s }, .. } if *s == shortcut
+        )).and_then(|(id_path, node)| {
+            node.descendants_from(&id_path)
+                .into_iter()
+                .find_map(|(path, node)| matches!(node, Node::Button { .. }).then_some(path))
+        });
+        let Some(button_path) = button_path else {
+            panic!("No button with shortcut {shortcut:?} in {:#?}", self.node());
+        };
+        self.tap(&button_path);
+    }
+
+    /// Dismisses the sheet, popover, alert or confirmation dialog at the given
+    /// id path, as if the user had closed it.
+    pub fn dismiss(&self, id_path: &IdPath) {
+        self.fire(id_path, &Event::Dismiss {});
+    }
+
+    /// Replaces the content of the text field at the given id path.
+    pub fn enter_text(&self, id_path: &IdPath, content: impl Into<String>) {
+        self.fire(id_path, &Event::UpdateText { content: content.into() });
+    }
+
+    /// Moves the slider at the given id path to the given value.
+    pub fn set_slider_value(&self, id_path: &IdPath, value: f64) {
+        self.fire(id_path, &Event::UpdateSliderValue { value });
+    }
+
+    /// Submits the text field at the given id path, as if the user had pressed
+    /// Enter.
+    pub fn submit(&self, id_path: &IdPath) {
+        self.fire(id_path, &Event::Submit {});
+    }
+
+    /// Moves focus to or away from the text field at the given id path.
+    pub fn set_focus(&self, id_path: &IdPath, is_focused: bool) {
+        self.fire(id_path, &Event::UpdateFocus { is_focused });
+    }
+
+    /// Switches the toggle at the given id path on or off.
+    pub fn set_toggle(&self, id_path: &IdPath, is_on: bool) {
+        self.fire(id_path, &Event::UpdateToggle { is_on });
+    }
+
+    /// Sets the stepper at the given id path to the given value.
+    pub fn set_stepper_value(&self, id_path: &IdPath, value: i64) {
+        self.fire(id_path, &Event::UpdateStepperValue { value });
+    }
+
+    /// Chooses the given date in the date picker at the given id path.
+    pub fn set_date(&self, id_path: &IdPath, date: CalendarDate) {
+        self.fire(id_path, &Event::UpdateDate { date });
+    }
+
+    /// Selects the item with the given id in the picker at the given id path.
+    pub fn select(&self, id_path: &IdPath, id: impl Into<Id>) {
+        self.fire(id_path, &Event::UpdatePickerSelection { id: id.into() });
+    }
+
+    /// Selects the rows with the given ids in the list at the given id path,
+    /// replacing the previous selection.
+    pub fn select_rows<I>(&self, id_path: &IdPath, ids: impl IntoIterator<Item = I>) where I: Into<Id> {
+        self.fire(id_path, &Event::UpdateSelection { selection: ids.into_iter().map(Into::into).collect() });
+    }
+
+    /// Deletes the rows at the given indices from the editable node at the
+    /// given id path.
+    pub fn delete_rows(&self, id_path: &IdPath, indices: impl Into<Vec<usize>>) {
+        self.fire(id_path, &Event::DeleteRows { indices: indices.into() });
+    }
+
+    /// Moves the rows at the given indices of the editable node at the given
+    /// id path before the row at the given destination.
+    pub fn move_rows(&self, id_path: &IdPath, indices: impl Into<Vec<usize>>, destination: usize) {
+        self.fire(id_path, &Event::MoveRows { indices: indices.into(), destination });
+    }
+
+    /// Performs the custom accessibility action with the given name on the
+    /// element at the given id path.
+    pub fn perform_accessibility_action(&self, id_path: &IdPath, name: impl Into<String>) {
+        self.fire(id_path, &Event::PerformAccessibilityAction { name: name.into() });
+    }
+
+    /// Fires the given gesture event at the gesture of the `Gestured` node at
+    /// the given id path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no gestured node at the given id path.
+    #[track_caller]
+    pub fn fire_gesture(&self, id_path: &IdPath, gesture: GestureEvent) {
+        let Some(Node::Gestured { gesture: gesture_node, .. }) = self.node_at(id_path) else {
+            panic!("No gestured node at {id_path:?}");
+        };
+        self.fire(&id_path.child(gesture_node.id().clone()), &Event::Gesture { gesture });
+    }
+
+    /// Taps the gestured node at the given id path.
+    #[track_caller]
+    pub fn tap_gesture(&self, id_path: &IdPath) {
+        self.fire_gesture(id_path, GestureEvent::Tap {});
+    }
+
+    /// Drags the gestured node at the given id path.
+    #[track_caller]
+    pub fn drag(&self, id_path: &IdPath, drag: DragEvent) {
+        self.fire_gesture(id_path, GestureEvent::Drag { drag });
+    }
+
+    /// Replaces the path of the navigation stack at the given id path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path cannot be serialized.
+    pub fn set_navigation_path<I>(&self, id_path: &IdPath, path: impl IntoIterator<Item = I>) where I: Serialize {
+        let path = path.into_iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Could not serialize navigation path");
+        self.fire(id_path, &Event::UpdateNavigationPath { path });
+    }
+
+    /// Resolves the destination for the given value at the navigation
+    /// destination node at the given id path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be serialized.
+    pub fn navigation_destination(&self, id_path: &IdPath, value: impl Serialize) -> Option<Identified<Node>> {
+        let value = serde_json::to_value(value).expect("Could not serialize navigation value");
+        match self.fire(id_path, &Event::GetNavigationDestination { value }) {
+            EventResponse::Node { node } => Some(node),
+            EventResponse::Empty {} | EventResponse::Nodes { .. } | EventResponse::Error { .. } => None,
+        }
+    }
+
+    /// Follows the navigation link at the given id path, like a user would by
+    /// clicking it: The link's value is pushed onto the path of the enclosing
+    /// navigation stack (if it has a path binding) and the destination is
+    /// resolved using the nearest enclosing navigation destination.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no navigation link at the given id path.
+    #[track_caller]
+    pub fn follow_link(&self, id_path: &IdPath) -> Option<Identified<Node>> {
+        let Some(Node::NavigationLink { value, .. }) = self.node_at(id_path) else {
+            panic!("No navigation link at {id_path:?}");
+        };
+
+        let stack = self.find_all(|node| matches!(node, Node::NavigationStack { .. }))
+            .into_iter()
+            .rev()
+            .find(|(stack_path, _)| id_path.starts_with(stack_path));
+        if let Some((stack_path, Node::NavigationStack { path: Some(mut path), .. })) = stack {
+            path.push(value.clone());
+            self.fire(&stack_path, &Event::UpdateNavigationPath { path });
+        }
+
+        let destination_path = self.find_all(|node| matches!(node, Node::NavigationDestination { .. }))
+            .into_iter()
+            .map(|(destination_path, _)| destination_path)
+            .rev()
+            .find(|destination_path| id_path.starts_with(destination_path))?;
+        self.navigation_destination(&destination_path, value)
+    }
+
+    /// Resolves the view of the geometry reader at the given id path for the
+    /// given geometry.
+    pub fn geometry_reader_view(&self, id_path: &IdPath, geometry: Geometry) -> Option<Identified<Node>> {
+        match self.fire(id_path, &Event::GetGeometryReaderView { geometry }) {
+            EventResponse::Node { node } => Some(node),
+            EventResponse::Empty {} | EventResponse::Nodes { .. } | EventResponse::Error { .. } => None,
+        }
+    }
+
+    /// Renders the rows of the lazy stack or list at the given id path within
+    /// the given index range, as a backend would for the visible rows.
+    pub fn lazy_rows(&self, id_path: &IdPath, range: Range<usize>) -> Vec<Identified<Node>> {
+        match self.fire(id_path, &Event::GetRows { start: range.start, end: range.end }) {
+            EventResponse::Nodes { nodes } => nodes,
+            EventResponse::Empty {} | EventResponse::Node { .. } | EventResponse::Error { .. } => Vec::new(),
+        }
+    }
+}
+
+fn is_text(node: &Node, content: &str) -> bool {
+    matches!(node, Node::Text { content: c } if c == content)
+}
+
+fn contains_text(node: &Node, content: &str) -> bool {
+    node.descendants().into_iter().any(|(_, node)| is_text(node, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use nuit_core::{clone, Access, AccessibilityHeadingLevel, AccessibilityRole, Button, Event, FireError, Geometry, GeometryReader, Id, IdPath, IdPathBuf, If, Image, NavigationLink, NavigationStack, Node, State, Text, TextField, Vec2, View, ViewExt, VStack};
+    use nuit_derive::Bind;
+
+    use crate::{assert_node_snapshot, describe_difference};
+
+    use super::TestRoot;
+
+    #[derive(Bind, Default)]
+    struct CounterView {
+        count: State<i32>,
+    }
+
+    impl View for CounterView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let count = self.count.clone();
+            VStack::from((
+                Text::new(format!("{}", count.get())),
+                Button::with_text("Increment", move || {
+                    count.set(count.get() + 1);
+                }),
+            ))
+        }
+    }
+
+    #[derive(Bind, Default)]
+    struct NameView {
+        name: State<String>,
+    }
+
+    impl View for NameView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            VStack::from((
+                TextField::new(self.name.binding()),
+                Text::new(format!("Hi {}!", self.name.get())),
+            ))
+        }
+    }
+
+    #[derive(Bind, Default)]
+    struct PageView {
+        path: State<Vec<i32>>,
+    }
+
+    impl View for PageView {
+        type Body = impl View;
+
+        fn body(&self) -> Self::Body {
+            let path = self.path.clone();
+            NavigationStack::with_path(
+                path.binding(),
+                VStack::from((
+                    Text::new(format!("Depth {}", path.get().len())),
+                    NavigationLink::with_text("Next", 1),
+                ))
+                .navigation_destination(|i: i32| Text::new(format!("Page {i}")))
+            )
+        }
+    }
+
+    #[test]
+    fn counter() {
+        let root = TestRoot::new(CounterView::default());
+        assert_eq!(root.texts(), vec!["0", "Increment"]);
+
+        root.tap_button("Increment");
+        root.tap_button("Increment");
+        assert!(root.find_text("2").is_some());
+        assert!(root.find_text("0").is_none());
+    }
+
+    #[test]
+    fn text_field() {
+        let root = TestRoot::new(NameView::default());
+        let (id_path, _) = root.find(|node| matches!(node, Node::TextField { .. })).unwrap();
+
+        root.enter_text(&id_path, "Nuit");
+        assert!(matches!(root.node_at(&id_path), Some(Node::TextField { content, .. }) if content == "Nuit"));
+        assert!(root.find_text("Hi Nuit!").is_some());
+    }
+
+    #[test]
+    fn navigation() {
+        let root = TestRoot::new(PageView::default());
+        let (link_path, _) = root.find(|node| matches!(node, Node::NavigationLink { .. })).unwrap();
+
+        let destination = root.follow_link(&link_path).unwrap();
+        assert_eq!(destination.value(), &Node::Text { content: "Page 1".to_owned() });
+        assert!(root.find_text("Depth 1").is_some());
+    }
+
+    #[test]
+    fn geometry_reader() {
+        let root = TestRoot::new(GeometryReader::new(|geometry| {
+            Text::new(format!("{}x{}", geometry.width(), geometry.height()))
+        }));
+
+        let node = root.geometry_reader_view(IdPath::root(), Geometry::new(Vec2::new(20.0, 10.0))).unwrap();
+        assert_eq!(node.value(), &Node::Text { content: "20x10".to_owned() });
+    }
+
+    #[test]
+    fn gestures() {
+        #[derive(Bind, Default)]
+        struct TapView {
+            taps: State<usize>,
+        }
+
+        impl View for TapView {
+            type Body = impl View;
+
+            fn body(&self) -> Self::Body {
+                let taps = self.taps.clone();
+                Text::new(format!("Taps: {}", taps.get()))
+                    .on_tap(clone!(taps => move || taps.set(taps.get() + 1)))
+            }
+        }
+
+        let root = TestRoot::new(TapView::default());
+        root.tap_gesture(IdPath::root());
+        assert!(root.find_text("Taps: 1").is_some());
+    }
+
+    #[test]
+    fn stale_events() {
+        #[derive(Bind, Default)]
+        struct ToggleView {
+            is_shown: State<bool>,
+            name: State<String>,
+        }
+
+        impl View for ToggleView {
+            type Body = impl View;
+
+            fn body(&self) -> Self::Body {
+                let is_shown = self.is_shown.clone();
+                VStack::from((
+                    If::new(is_shown.get(), || TextField::new(self.name.binding())),
+                    Button::with_text("Toggle", clone!(is_shown => move || is_shown.set(!is_shown.get()))),
+                ))
+            }
+        }
+
+        let root = TestRoot::new(ToggleView::default());
+        root.tap_button("Toggle");
+        let (text_field_path, _) = root.find(|node| matches!(node, Node::TextField { .. })).unwrap();
+        root.tap_button("Toggle");
+
+        let update = Event::UpdateText { content: "Stale".to_owned() };
+        assert!(matches!(root.try_fire(&text_field_path, &update), Err(FireError::StalePath { .. })));
+        assert_eq!(
+            root.try_fire(&IdPathBuf::from(Id::index(0)).child(7), &update),
+            Err(FireError::UnknownChild { id: Id::index(7), view: "tuple" }),
+        );
+    }
+
+    #[test]
+    fn accessibility() {
+        #[derive(Bind, Default)]
+        struct PlayerView {
+            volume: State<i64>,
+        }
+
+        impl View for PlayerView {
+            type Body = impl View;
+
+            fn body(&self) -> Self::Body {
+                let volume = self.volume.clone();
+                VStack::from((
+                    Text::new("Now Playing").accessibility_heading(AccessibilityHeadingLevel::H1),
+                    Image::symbol("music.note").accessibility_label("Album art"),
+                    Button::new(Image::symbol("play.fill"), || {})
+                        .accessibility_label("Play")
+                        .accessibility_hint("Starts playback"),
+                    Text::new("Decoration").accessibility_hidden(true),
+                    Text::new(format!("Volume {}", volume.get()))
+                        .accessibility_role(AccessibilityRole::Slider)
+                        .accessibility_action("Louder", clone!(volume => move || volume.set(volume.get() + 1))),
+                    VStack::from((Text::new("3:12"), Text::new("of 4:05"))).accessibility_label("Elapsed time"),
+                ))
+            }
+        }
+
+        let root = TestRoot::new(PlayerView::default());
+        let tree = root.accessibility_tree();
+        let summary: Vec<_> = tree.iter().map(|element| (element.role, element.label.as_deref())).collect();
+        assert_eq!(summary, [
+            (AccessibilityRole::Heading, Some("Now Playing")),
+            (AccessibilityRole::Image, Some("Album art")),
+            (AccessibilityRole::Button, Some("Play")),
+            (AccessibilityRole::Slider, Some("Volume 0")),
+            (AccessibilityRole::Group, Some("Elapsed time")),
+        ]);
+        assert_eq!(tree[0].heading_level, AccessibilityHeadingLevel::H1);
+        assert_eq!(tree[2].hint.as_deref(), Some("Starts playback"));
+        assert_eq!(tree[4].children.len(), 2);
+
+        let slider = &tree[3];
+        assert_eq!(slider.actions, ["Louder"]);
+        root.perform_accessibility_action(&slider.id_path, "Louder");
+        assert_eq!(root.accessibility_tree()[3].label.as_deref(), Some("Volume 1"));
+    }
+
+    #[test]
+    fn snapshot() {
+        assert_node_snapshot!(CounterView::default());
+
+        let old = TestRoot::new(CounterView::default()).node();
+        let root = TestRoot::new(CounterView::default());
+        root.tap_button("Increment");
+        assert_eq!(
+            describe_difference(&old, &root.node()),
+            "  at [0,0]:\n    - text {\"content\":\"0\"}\n    + text {\"content\":\"1\"}\n",
+        );
+    }
+}
@@ -5,6 +5,8 @@ use std::str::FromStr;
 pub enum Backend {
     Adwaita,
     SwiftUI,
+    Tui,
+    Web,
 }
 
 impl FromStr for Backend {
@@ -14,6 +16,8 @@ impl FromStr for Backend {
         match s.to_lowercase().as_str() {
             "adwaita" => Ok(Self::Adwaita),
             "swiftui" => Ok(Self::SwiftUI),
+            "tui" => Ok(Self::Tui),
+            "web" => Ok(Self::Web),
             _ => Err(format!("Unsupported backend: {s}")),
         }
     }
@@ -1,13 +1,16 @@
 use std::env;
 
-use nuit_core::View;
+use nuit_core::{App, JsonFileBackend, PersistenceBackend};
 
 use crate::Backend;
 
-/// A configuration encapsulating an app with its preferred backend.
+/// A configuration encapsulating an app (or a single view) with its preferred
+/// backend.
 pub struct Config<T> {
     view: T,
     preferred_backend: Option<Backend>,
+    app_id: Option<String>,
+    persistence_backend: Option<Box<dyn PersistenceBackend>>,
 }
 
 impl<T> Config<T> {
@@ -22,12 +25,27 @@ impl<T> Config<T> {
     pub fn into_view(self) -> T {
         self.view
     }
+
+    /// Converts the config into the app to run, applying the configured
+    /// application id and persistence backend.
+    pub fn into_app(self) -> App where T: Into<App> {
+        let mut app: App = self.view.into();
+        if let Some(app_id) = self.app_id {
+            app = app.with_id(app_id);
+        }
+        if let Some(persistence_backend) = self.persistence_backend {
+            app = app.persistence_backend(persistence_backend);
+        }
+        app
+    }
 }
 
 /// A convenience for building a `Config`.
 pub struct ConfigBuilder<T> {
     view: T,
     preferred_backend: Option<Backend>,
+    app_id: Option<String>,
+    persistence_backend: Option<Box<dyn PersistenceBackend>>,
 }
 
 impl<T> ConfigBuilder<T> {
@@ -36,6 +54,32 @@ impl<T> ConfigBuilder<T> {
         self.preferred_backend = Some(backend);
         self
     }
+
+    /// Sets the application id, which should be in reverse-DNS notation.
+    #[must_use]
+    pub fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Sets the backend that persisted state is restored from and saved to.
+    #[must_use]
+    pub fn persistence_backend(mut self, backend: impl PersistenceBackend + 'static) -> Self {
+        self.persistence_backend = Some(Box::new(backend));
+        self
+    }
+
+    /// Persists state in a JSON file under the XDG config directory, i.e. in
+    /// `$XDG_CONFIG_HOME/<app_id>/state.json`.
+    #[must_use]
+    pub fn persisted_state(self, app_id: &str) -> Self {
+        if let Some(backend) = JsonFileBackend::for_app(app_id) {
+            self.persistence_backend(backend)
+        } else {
+            eprintln!("Warning: Not persisting state since the config directory could not be determined");
+            self
+        }
+    }
 }
 
 impl<T> From<T> for ConfigBuilder<T> {
@@ -43,6 +87,8 @@ impl<T> From<T> for ConfigBuilder<T> {
         Self {
             view,
             preferred_backend: env::var("NUIT_BACKEND").ok().map(|v| v.parse().unwrap()),
+            app_id: None,
+            persistence_backend: None,
         }
     }
 }
@@ -52,11 +98,13 @@ impl<T> From<ConfigBuilder<T>> for Config<T> {
         Self {
             view: builder.view,
             preferred_backend: builder.preferred_backend,
+            app_id: builder.app_id,
+            persistence_backend: builder.persistence_backend,
         }
     }
 }
 
-impl<T> From<T> for Config<T> where T: View {
+impl<T> From<T> for Config<T> where T: Into<App> {
     fn from(view: T) -> Self {
         Self::builder(view).into()
     }
@@ -32,37 +32,48 @@ impl Default for Backend {
         return Self::SwiftUI;
         #[cfg(feature = "adwaita")]
         return Self::Adwaita;
+        #[cfg(feature = "tui")]
+        return Self::Tui;
+        #[cfg(feature = "web")]
+        return Self::Web;
         panic!("A backend must be enabled via Nuit's crate features!");
     }
 }
 
-/// Blocks and presents the given view to the user. This is the main entry point
-/// for apps using Nuit.
+/// Blocks and presents the given app (or view) to the user. This is the main
+/// entry point for apps using Nuit.
 /// 
 /// # Panics
 /// 
 /// This may panic in a number of cases, e.g. if the backend is unsupported or
 /// if JSON serialization fails. Clients should assume this may panic at any
 /// time if something goes wrong.
-pub fn run_app<T>(config: impl Into<Config<T>>) where T: View + 'static {
+pub fn run_app<T>(config: impl Into<Config<T>>) where T: Into<App> {
     let config: Config<T> = config.into();
     let backend = config.preferred_backend().unwrap_or_default();
-    let view = config.into_view();
-    let root = Root::new(view);
+    let app = config.into_app();
 
     match backend {
         #[cfg(feature = "swiftui")]
         Backend::SwiftUI => {
             #[cfg(target_vendor = "apple")]
-            CRoot::scope_from(&mut Box::new(root), |c_root| {
-                unsafe { nuit_bridge_swiftui::run_app(c_root); }
+            CApp::scope_from(&mut Box::new(app), |c_app| {
+                unsafe { nuit_bridge_swiftui::run_app(c_app); }
             });
             #[cfg(not(target_vendor = "apple"))]
             panic!("SwiftUI is not supported outside of Apple platforms!")
         }
         #[cfg(feature = "adwaita")]
         Backend::Adwaita => {
-            nuit_bridge_adwaita::run_app(root);
+            nuit_bridge_adwaita::run_app(app);
+        }
+        #[cfg(feature = "tui")]
+        Backend::Tui => {
+            nuit_bridge_tui::run_app(app);
+        }
+        #[cfg(feature = "web")]
+        Backend::Web => {
+            nuit_bridge_web::run_app(app);
         }
         #[allow(unreachable_patterns)]
         _ => panic!("The backend {backend:?} must be enabled via Nuit's crate features!"),